@@ -2,9 +2,15 @@ use crate::Error::InsufficientTargetSpace;
 use crate::base_64::Base64Encoder;
 use crate::base_64::decode::decode_block::decode_block;
 use crate::base_64::decode::decode_block_last::decode_block_last;
+use crate::base_64::decode::decode_forgiving::decode_forgiving;
 use crate::base_64::decode::decoded_len::decoded_len;
+use crate::base_64::decode::decoded_len_last_block::decoded_len_last_block;
 use crate::base_64::decode::decoding_table::DecodingTable;
+use crate::base_64::decode::forgiving_decoded_len::forgiving_decoded_len;
 use crate::base_64::decode::split_last_block::split_last_block;
+use crate::base_64::decode::split_last_block_skip_whitespace::{
+    is_skipped_whitespace, split_last_block_skip_whitespace,
+};
 use crate::{Decoder, Error};
 
 /// Responsible for decoding base-64 encoded data.
@@ -13,10 +19,27 @@ use crate::{Decoder, Error};
 /// This decoder implementation does nothing to validate the encoded data. If invalid input data is
 /// given, the output bytes are undefined. The decoded length calculation will still be accurate,
 /// and decoding data will not cause a panic.
+///
+/// # Whitespace
+/// A decoder created `with_skip_whitespace` skips CR, LF, space and tab bytes anywhere in the
+/// encoded data. This is useful for line-wrapped data such as MIME bodies.
+///
+/// # Forgiving
+/// A decoder created `with_forgiving` implements the forgiving-base64 decode algorithm of the
+/// WHATWG Infra standard, as used by `atob` and data URLs. ASCII whitespace is skipped, padding is
+/// optional, and the encoded data is validated while decoding. Invalid data results in an
+/// `InvalidEncodedData` error with the offset of the invalid byte.
+///
+/// # Mixed Alphabets
+/// The `mixed_decoder` accepts both the standard (`+/`) and the URL-safe (`-_`) alphabets, even when
+/// mixed within the same data, with or without padding. Use the `Base64Validator::mixed_validator`
+/// to validate the encoded data and enforce a `PaddingMode`.
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
 pub struct Base64Decoder {
     table: DecodingTable,
     padding: Option<u8>,
+    skip_whitespace: bool,
+    forgiving: bool,
 }
 
 impl Base64Decoder {
@@ -32,11 +55,29 @@ impl Base64Decoder {
     /// Creates a new base-64 decoder.
     ///
     /// Returns `None` if the decoding config is invalid.
-    pub fn new(v63: u8, v64: u8, padding: Option<u8>) -> Option<Self> {
+    pub const fn new(v63: u8, v64: u8, padding: Option<u8>) -> Option<Self> {
         if Base64Encoder::is_valid_config(v63, v64, padding) {
             Some(Self {
                 table: DecodingTable::get_decoding_table(v63, v64),
                 padding,
+                skip_whitespace: false,
+                forgiving: false,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new base-64 decoder with the full custom `alphabet`.
+    ///
+    /// Returns `None` if the alphabet config is invalid.
+    pub const fn from_alphabet(alphabet: &[u8; 64], padding: Option<u8>) -> Option<Self> {
+        if Base64Encoder::is_valid_alphabet(alphabet, padding) {
+            Some(Self {
+                table: DecodingTable::from_alphabet(alphabet),
+                padding,
+                skip_whitespace: false,
+                forgiving: false,
             })
         } else {
             None
@@ -44,11 +85,37 @@ impl Base64Decoder {
     }
 }
 
+impl Base64Decoder {
+    //! Configuration
+
+    /// Sets whether CR, LF, space and tab bytes are skipped when decoding.
+    pub const fn with_skip_whitespace(mut self, skip_whitespace: bool) -> Self {
+        self.skip_whitespace = skip_whitespace;
+        self
+    }
+
+    /// Checks if CR, LF, space and tab bytes are skipped when decoding.
+    pub const fn skips_whitespace(&self) -> bool {
+        self.skip_whitespace
+    }
+
+    /// Sets whether the forgiving-base64 decode algorithm is used. (WHATWG Infra)
+    pub const fn with_forgiving(mut self, forgiving: bool) -> Self {
+        self.forgiving = forgiving;
+        self
+    }
+
+    /// Checks if the forgiving-base64 decode algorithm is used.
+    pub const fn is_forgiving(&self) -> bool {
+        self.forgiving
+    }
+}
+
 impl Base64Decoder {
     //! Special Decoders
 
     /// Gets the URL-safe decoder.
-    pub fn url_safe_decoder() -> Self {
+    pub const fn url_safe_decoder() -> Self {
         Self::new(
             Base64Encoder::URL_SAFE_V63,
             Base64Encoder::URL_SAFE_V64,
@@ -56,6 +123,21 @@ impl Base64Decoder {
         )
         .unwrap()
     }
+
+    /// Gets the bcrypt decoder.
+    pub const fn bcrypt_decoder() -> Self {
+        Self::from_alphabet(Base64Encoder::BCRYPT_ALPHABET, None).unwrap()
+    }
+
+    /// Gets the mixed decoder. (accepts both the standard and URL-safe alphabets)
+    pub const fn mixed_decoder() -> Self {
+        Self {
+            table: DecodingTable::mixed_decoding_table(),
+            padding: Base64Encoder::DEFAULT_PADDING,
+            skip_whitespace: false,
+            forgiving: false,
+        }
+    }
 }
 
 impl Default for Base64Decoder {
@@ -63,21 +145,148 @@ impl Default for Base64Decoder {
         Self {
             table: DecodingTable::default(),
             padding: Base64Encoder::DEFAULT_PADDING,
+            skip_whitespace: false,
+            forgiving: false,
         }
     }
 }
 
+impl Base64Decoder {
+    //! Decoding
+
+    /// Decodes the `data` into the `target` slice while skipping whitespace.
+    ///
+    /// The `target` length must be exactly the decoded length.
+    fn decode_to_slice_skip_whitespace(&self, data: &[u8], target: &mut [u8]) -> usize {
+        let table: &[u8; 256] = self.table.decoding_table();
+        let (full_blocks_len, last_block, last_block_len) = split_last_block_skip_whitespace(data);
+        let mut block: [u8; Self::BLOCK_SIZE] = [0u8; Self::BLOCK_SIZE];
+        let mut b: usize = 0;
+        let mut t: usize = 0;
+        let full_blocks = data.iter().filter(|c| !is_skipped_whitespace(**c));
+        for c in full_blocks.take(full_blocks_len) {
+            block[b] = *c;
+            b += 1;
+            if b == Self::BLOCK_SIZE {
+                t += unsafe { decode_block(table, &block, &mut target[t..]) };
+                b = 0;
+            }
+        }
+        let last_block: &[u8] = &last_block[..last_block_len];
+        t += unsafe { decode_block_last(table, self.padding, last_block, &mut target[t..]) };
+        debug_assert_eq!(target.len(), t);
+        t
+    }
+}
+
+impl Base64Decoder {
+    //! Const Decoding
+
+    /// Gets the length of the `data` without the trailing padding in a `const` context.
+    ///
+    /// # Panics
+    /// Panics if the padding is invalid.
+    const fn unpadded_len_const(&self, data: &[u8]) -> usize {
+        let mut len: usize = data.len();
+        if let Some(padding) = self.padding {
+            while len > 0 && data[len - 1] == padding {
+                len -= 1;
+            }
+            if len != data.len() && (data.len() - len > 2 || !data.len().is_multiple_of(4)) {
+                panic!("invalid base-64 data: invalid padding");
+            }
+        }
+        len
+    }
+
+    /// Gets the length of the decoded `data` in a `const` context.
+    ///
+    /// Unlike the `Decoder` implementation, the `data` is validated and whitespace is never
+    /// skipped.
+    ///
+    /// # Panics
+    /// Panics if the `data` length or padding is invalid.
+    pub const fn decoded_len_const(&self, data: &[u8]) -> usize {
+        let len: usize = self.unpadded_len_const(data);
+        if len % 4 == 1 {
+            panic!("invalid base-64 data: invalid length");
+        }
+        (len / 4) * 3 + (len % 4) * 3 / 4
+    }
+
+    /// Decodes the `data` into an array in a `const` context.
+    ///
+    /// Unlike the `Decoder` implementation, the `data` is validated and whitespace is never
+    /// skipped.
+    ///
+    /// # Panics
+    /// Panics if the `data` is invalid or `N` is not the decoded length.
+    pub const fn decode_array<const N: usize>(&self, data: &[u8]) -> [u8; N] {
+        if self.decoded_len_const(data) != N {
+            panic!("invalid base-64 data: decoded length mismatch");
+        }
+        let len: usize = self.unpadded_len_const(data);
+        let table: &[u8; 256] = self.table.decoding_table();
+        let mut target: [u8; N] = [0u8; N];
+        let mut bits: u32 = 0;
+        let mut bit_count: u32 = 0;
+        let mut d: usize = 0;
+        let mut t: usize = 0;
+        while d < len {
+            let value: u8 = table[data[d] as usize];
+            if value == 0xFF {
+                panic!("invalid base-64 data: invalid byte");
+            }
+            bits = (bits << 6) | value as u32;
+            bit_count += 6;
+            if bit_count >= 8 {
+                bit_count -= 8;
+                target[t] = (bits >> bit_count) as u8;
+                bits &= (1 << bit_count) - 1;
+                t += 1;
+            }
+            d += 1;
+        }
+        if bits != 0 {
+            panic!("invalid base-64 data: non-zero trailing bits");
+        }
+        target
+    }
+}
+
 impl Decoder for Base64Decoder {
     fn decoded_len(&self, data: &[u8]) -> Result<usize, Error> {
-        Ok(decoded_len(data, self.padding))
+        if self.forgiving {
+            let table: &[u8; 256] = self.table.decoding_table();
+            forgiving_decoded_len(table, self.padding, data).map(|(_, decoded_len)| decoded_len)
+        } else if self.skip_whitespace {
+            let (full_blocks_len, last_block, last_block_len) =
+                split_last_block_skip_whitespace(data);
+            Ok((full_blocks_len / Self::BLOCK_SIZE) * 3
+                + decoded_len_last_block(&last_block[..last_block_len], self.padding))
+        } else {
+            Ok(decoded_len(data, self.padding))
+        }
     }
 
     fn decode_to_slice(&self, data: &[u8], target: &mut [u8]) -> Result<usize, Error> {
+        if self.forgiving {
+            let table: &[u8; 256] = self.table.decoding_table();
+            let (encoded_len, decoded_len) = forgiving_decoded_len(table, self.padding, data)?;
+            return if decoded_len > target.len() {
+                Err(InsufficientTargetSpace)
+            } else {
+                Ok(decode_forgiving(table, data, encoded_len, target))
+            };
+        }
         let decoded_len: usize = self.decoded_len(data)?;
         if decoded_len > target.len() {
             Err(InsufficientTargetSpace)
         } else {
             let target: &mut [u8] = &mut target[..decoded_len];
+            if self.skip_whitespace {
+                return Ok(self.decode_to_slice_skip_whitespace(data, target));
+            }
             let table: &[u8; 256] = self.table.decoding_table();
             let (full_blocks, last_block) = split_last_block(data);
             let mut d: usize = 0;
@@ -96,8 +305,9 @@ impl Decoder for Base64Decoder {
 #[cfg(test)]
 #[cfg(feature = "dev")]
 mod tests {
-    use crate::base_64::Base64Decoder;
-    use crate::test::test_decoder;
+    use crate::base_64::{Base64Decoder, Base64Encoder};
+    use crate::test::{test_decoder, test_forgiving_base_64};
+    use crate::{Decoder, Error};
 
     #[test]
     fn decode() {
@@ -127,4 +337,133 @@ mod tests {
         let decoder: Base64Decoder = Base64Decoder::default();
         test_decoder(&decoder, test_cases);
     }
+
+    #[test]
+    fn decode_mixed() {
+        let test_cases: &[(&str, &[u8])] = &[
+            ("", b""),
+            ("89+/", b"\xF3\xDF\xBF"),
+            ("89-_", b"\xF3\xDF\xBF"),
+            ("+/-_", b"\xFB\xFF\xBF"),
+            ("//8=", b"\xFF\xFF"),
+            ("__8", b"\xFF\xFF"),
+            ("_w==", b"\xFF"),
+            ("/w", b"\xFF"),
+        ];
+        let decoder: Base64Decoder = Base64Decoder::mixed_decoder();
+        test_decoder(&decoder, test_cases);
+    }
+
+    #[test]
+    fn decode_array() {
+        let decoder: Base64Decoder = Base64Decoder::default();
+        assert_eq!(decoder.decode_array::<0>(b""), []);
+        assert_eq!(decoder.decode_array::<2>(b"//8="), [0xFF, 0xFF]);
+        assert_eq!(decoder.decode_array::<2>(b"//8"), [0xFF, 0xFF]);
+        assert_eq!(decoder.decode_array::<3>(b"////"), [0xFF, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid byte")]
+    fn decode_array_invalid_byte() {
+        Base64Decoder::default().decode_array::<3>(b"SGk!");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid padding")]
+    fn decode_array_invalid_padding() {
+        Base64Decoder::default().decode_array::<1>(b"S===");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid length")]
+    fn decode_array_invalid_len() {
+        Base64Decoder::default().decode_array::<3>(b"SGk=S");
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero trailing bits")]
+    fn decode_array_trailing_bits() {
+        Base64Decoder::default().decode_array::<1>(b"QR==");
+    }
+
+    #[test]
+    #[should_panic(expected = "decoded length mismatch")]
+    fn decode_array_wrong_len() {
+        Base64Decoder::default().decode_array::<3>(b"SGk=");
+    }
+
+    #[test]
+    fn decode_alphabet() {
+        let bcrypt: &[(&str, &[u8])] = &[
+            ("", b""),
+            ("....", b"\x00\x00\x00"),
+            ("9999", b"\xFF\xFF\xFF"),
+            ("./AB", b"\x00\x10\x83"),
+            ("9u", b"\xFF"),
+            ("996", b"\xFF\xFF"),
+        ];
+        test_decoder(&Base64Decoder::bcrypt_decoder(), bcrypt);
+
+        let crypt: &[(&str, &[u8])] = &[
+            ("", b""),
+            ("zzzz", b"\xFF\xFF\xFF"),
+            ("./01", b"\x00\x10\x83"),
+            ("zk", b"\xFF"),
+        ];
+        let decoder: Base64Decoder =
+            Base64Decoder::from_alphabet(Base64Encoder::CRYPT_ALPHABET, None).unwrap();
+        test_decoder(&decoder, crypt);
+
+        let duplicate: &[u8; 64] =
+            b"AACDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        assert!(Base64Decoder::from_alphabet(duplicate, None).is_none());
+    }
+
+    #[test]
+    fn decode_skip_whitespace() {
+        let test_cases: &[(&str, &[u8])] = &[
+            ("", b""),
+            (" \r\n\t", b""),
+            ("AAAA", b"\x00\x00\x00"),
+            ("AA\r\nAA", b"\x00\x00\x00"),
+            (" //// ////\n", b"\xFF\xFF\xFF\xFF\xFF\xFF"),
+            ("//\r\n//\r\n/w=\r\n=\r\n", b"\xFF\xFF\xFF\xFF"),
+            ("//\t//\t/\t/\t8", b"\xFF\xFF\xFF\xFF\xFF"),
+        ];
+        let decoder: Base64Decoder = Base64Decoder::default().with_skip_whitespace(true);
+        test_decoder(&decoder, test_cases);
+    }
+
+    #[test]
+    fn decode_forgiving() {
+        let decoder: Base64Decoder = Base64Decoder::default().with_forgiving(true);
+        assert!(decoder.is_forgiving());
+        test_forgiving_base_64(|data| decoder.decode_as_vec(data).ok());
+    }
+
+    #[test]
+    fn decode_forgiving_invalid() {
+        let test_cases: &[(&str, usize)] = &[
+            ("a", 1),
+            ("ab=c", 2),
+            (" ab\x00", 3),
+            ("abcd a", 6),
+            ("ab-_", 2),
+        ];
+        let decoder: Base64Decoder = Base64Decoder::default().with_forgiving(true);
+
+        for (data, offset) in test_cases {
+            let error: Error = decoder.decode_as_vec(data.as_bytes()).unwrap_err();
+            assert_eq!(error.offset(), Some(*offset), "data={data:?}");
+        }
+    }
+
+    #[test]
+    fn decode_forgiving_url_safe() {
+        let test_cases: &[(&str, &[u8])] = &[("-_", b"\xFB"), (" - _ ", b"\xFB")];
+        let decoder: Base64Decoder = Base64Decoder::url_safe_decoder().with_forgiving(true);
+        test_decoder(&decoder, test_cases);
+        assert!(decoder.decode_as_vec(b"+/").is_err());
+    }
 }
@@ -23,6 +23,28 @@ impl Base64Encoder {
                 true
             })
     }
+
+    /// Checks if the full `alphabet` & `padding` config is valid.
+    ///
+    /// The alphabet bytes must be unique, graphic ASCII characters. The padding, if present, must
+    /// be a graphic ASCII character not in the alphabet.
+    pub const fn is_valid_alphabet(alphabet: &[u8; 64], padding: Option<u8>) -> bool {
+        let mut seen: [bool; 128] = [false; 128];
+        let mut i: usize = 0;
+        while i < 64 {
+            let c: u8 = alphabet[i];
+            if !c.is_ascii_graphic() || seen[c as usize] {
+                return false;
+            }
+            seen[c as usize] = true;
+            i += 1;
+        }
+        if let Some(padding) = padding {
+            padding.is_ascii_graphic() && !seen[padding as usize]
+        } else {
+            true
+        }
+    }
 }
 
 impl Base64Encoder {
@@ -31,7 +53,7 @@ impl Base64Encoder {
     /// Creates a new base-64 encoder.
     ///
     /// Returns `None` if the encoding config is invalid.
-    pub fn new(v63: u8, v64: u8, padding: Option<u8>) -> Option<Self> {
+    pub const fn new(v63: u8, v64: u8, padding: Option<u8>) -> Option<Self> {
         if Self::is_valid_config(v63, v64, padding) {
             Some(Self {
                 table: EncodingTable::get_encoding_table(v63, v64),
@@ -41,6 +63,20 @@ impl Base64Encoder {
             None
         }
     }
+
+    /// Creates a new base-64 encoder with the full custom `alphabet`.
+    ///
+    /// Returns `None` if the alphabet config is invalid.
+    pub const fn from_alphabet(alphabet: &[u8; 64], padding: Option<u8>) -> Option<Self> {
+        if Self::is_valid_alphabet(alphabet, padding) {
+            Some(Self {
+                table: EncodingTable::from_alphabet(alphabet),
+                padding,
+            })
+        } else {
+            None
+        }
+    }
 }
 
 impl Default for Base64Encoder {
@@ -56,7 +92,7 @@ impl Base64Encoder {
     //! Special Encoders
 
     /// Gets the URL-safe encoder.
-    pub fn url_safe_encoder() -> Self {
+    pub const fn url_safe_encoder() -> Self {
         Self::new(
             Self::URL_SAFE_V63,
             Self::URL_SAFE_V64,
@@ -64,6 +100,11 @@ impl Base64Encoder {
         )
         .unwrap()
     }
+
+    /// Gets the bcrypt encoder.
+    pub const fn bcrypt_encoder() -> Self {
+        Self::from_alphabet(Self::BCRYPT_ALPHABET, None).unwrap()
+    }
 }
 
 impl Encoder for Base64Encoder {
@@ -111,6 +152,10 @@ impl Encoder for Base64Encoder {
             Ok(encoded_len)
         }
     }
+
+    fn block_size(&self) -> Option<usize> {
+        Some(3)
+    }
 }
 
 impl StringEncoder for Base64Encoder {
@@ -184,4 +229,69 @@ mod tests {
         let encoder: Base64Encoder = Base64Encoder::default();
         test_string_encoder(&encoder, test_cases);
     }
+
+    #[test]
+    fn is_valid_alphabet() {
+        let standard: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        assert!(Base64Encoder::is_valid_alphabet(standard, Some(b'=')));
+        assert!(Base64Encoder::is_valid_alphabet(standard, None));
+        assert!(!Base64Encoder::is_valid_alphabet(standard, Some(b'+')));
+        assert!(!Base64Encoder::is_valid_alphabet(standard, Some(b' ')));
+        assert!(Base64Encoder::is_valid_alphabet(
+            Base64Encoder::BCRYPT_ALPHABET,
+            None
+        ));
+        assert!(Base64Encoder::is_valid_alphabet(
+            Base64Encoder::CRYPT_ALPHABET,
+            None
+        ));
+
+        let mut duplicate: [u8; 64] = *standard;
+        duplicate[63] = b'+';
+        assert!(!Base64Encoder::is_valid_alphabet(&duplicate, None));
+
+        let mut whitespace: [u8; 64] = *standard;
+        whitespace[63] = b' ';
+        assert!(!Base64Encoder::is_valid_alphabet(&whitespace, None));
+
+        let mut non_ascii: [u8; 64] = *standard;
+        non_ascii[63] = 0xFF;
+        assert!(!Base64Encoder::is_valid_alphabet(&non_ascii, None));
+    }
+
+    #[test]
+    fn encode_bcrypt() {
+        let test_cases: &[(&[u8], &str)] = &[
+            (b"", ""),
+            (b"\x00\x00\x00", "...."),
+            (b"\xFF\xFF\xFF", "9999"),
+            (b"\x00\x10\x83", "./AB"),
+            (b"\xFF", "9u"),
+            (b"\xFF\xFF", "996"),
+        ];
+        let encoder: Base64Encoder = Base64Encoder::bcrypt_encoder();
+        test_string_encoder(&encoder, test_cases);
+    }
+
+    #[test]
+    fn encode_crypt() {
+        let test_cases: &[(&[u8], &str)] = &[
+            (b"", ""),
+            (b"\x00\x00\x00", "...."),
+            (b"\xFF\xFF\xFF", "zzzz"),
+            (b"\x00\x10\x83", "./01"),
+            (b"\xFF", "zk"),
+        ];
+        let encoder: Base64Encoder =
+            Base64Encoder::from_alphabet(Base64Encoder::CRYPT_ALPHABET, None).unwrap();
+        test_string_encoder(&encoder, test_cases);
+    }
+
+    #[test]
+    fn encode_imap() {
+        let test_cases: &[(&[u8], &str)] = &[(b"\xF3\xDF\xBF", "89+,"), (b"\xFF", ",w")];
+        let encoder: Base64Encoder = Base64Encoder::new(b'+', b',', None).unwrap();
+        test_string_encoder(&encoder, test_cases);
+    }
 }
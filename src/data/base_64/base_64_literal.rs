@@ -0,0 +1,69 @@
+/// Decodes a base-64 string literal into a `[u8; N]` array at compile time.
+///
+/// The standard decoder is used unless a `const` decoder expression is given first. Padding is
+/// optional. Compilation fails if the literal is not valid for the decoder.
+///
+/// ```
+/// use enc::base_64::Base64Decoder;
+///
+/// const HELLO: [u8; 5] = enc::base64!("SGVsbG8=");
+/// assert_eq!(&HELLO, b"Hello");
+///
+/// const URL_SAFE: [u8; 2] = enc::base64!(Base64Decoder::url_safe_decoder(), "__8");
+/// assert_eq!(URL_SAFE, [0xFF, 0xFF]);
+/// ```
+///
+/// ```compile_fail,E0080
+/// const DATA: [u8; 1] = enc::base64!("S===");
+/// ```
+///
+/// ```compile_fail,E0080
+/// const DATA: [u8; 3] = enc::base64!("SGk!");
+/// ```
+#[macro_export]
+macro_rules! base64 {
+    ($data:expr) => {
+        $crate::base64!(
+            $crate::base_64::Base64Decoder::new(
+                $crate::base_64::Base64Encoder::DEFAULT_V63,
+                $crate::base_64::Base64Encoder::DEFAULT_V64,
+                $crate::base_64::Base64Encoder::DEFAULT_PADDING,
+            )
+            .unwrap(),
+            $data
+        )
+    };
+    ($decoder:expr, $data:expr) => {{
+        const DECODER: $crate::base_64::Base64Decoder = $decoder;
+        const DATA: &[u8] = $data.as_bytes();
+        const LEN: usize = DECODER.decoded_len_const(DATA);
+        const DECODED: [u8; LEN] = DECODER.decode_array::<LEN>(DATA);
+        DECODED
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base_64::Base64Decoder;
+
+    #[test]
+    fn base64() {
+        const HELLO: [u8; 13] = crate::base64!("SGVsbG8sIFdvcmxkIQ==");
+        assert_eq!(&HELLO, b"Hello, World!");
+
+        let empty: [u8; 0] = crate::base64!("");
+        assert_eq!(empty, []);
+
+        let unpadded: [u8; 2] = crate::base64!("//8");
+        assert_eq!(unpadded, [0xFF, 0xFF]);
+
+        let padded: [u8; 1] = crate::base64!("/w==");
+        assert_eq!(padded, [0xFF]);
+
+        let url_safe: [u8; 4] = crate::base64!(Base64Decoder::url_safe_decoder(), "_____w");
+        assert_eq!(url_safe, [0xFF, 0xFF, 0xFF, 0xFF]);
+
+        let bcrypt: [u8; 3] = crate::base64!(Base64Decoder::bcrypt_decoder(), "./AB");
+        assert_eq!(bcrypt, [0x00, 0x10, 0x83]);
+    }
+}
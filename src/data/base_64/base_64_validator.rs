@@ -1,5 +1,5 @@
-use crate::base_64::Base64Encoder;
 use crate::base_64::decode::decoding_table::DecodingTable;
+use crate::base_64::{Base64Encoder, PaddingMode};
 use crate::{Error, Validator};
 
 /// Responsible for validating base-64 encoded data.
@@ -7,21 +7,49 @@ use crate::{Error, Validator};
 pub struct Base64Validator {
     decoding_table: DecodingTable,
     padding: Option<u8>,
-    require_padding: bool,
+    padding_mode: PaddingMode,
 }
 
 impl Base64Validator {
     //! Construction
 
+    /// Gets the padding mode for the `require_padding` flag.
+    const fn padding_mode_for(require_padding: bool) -> PaddingMode {
+        if require_padding {
+            PaddingMode::Required
+        } else {
+            PaddingMode::Optional
+        }
+    }
+
     /// Creates a base-64 validator.
     ///
     /// Returns `None` if the encoding config is invalid.
-    pub fn new(v63: u8, v64: u8, padding: Option<u8>, require_padding: bool) -> Option<Self> {
+    pub const fn new(v63: u8, v64: u8, padding: Option<u8>, require_padding: bool) -> Option<Self> {
         if Base64Encoder::is_valid_config(v63, v64, padding) {
             Some(Self {
                 decoding_table: DecodingTable::get_decoding_table(v63, v64),
                 padding,
-                require_padding,
+                padding_mode: Self::padding_mode_for(require_padding),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a base-64 validator with the full custom `alphabet`.
+    ///
+    /// Returns `None` if the alphabet config is invalid.
+    pub const fn from_alphabet(
+        alphabet: &[u8; 64],
+        padding: Option<u8>,
+        require_padding: bool,
+    ) -> Option<Self> {
+        if Base64Encoder::is_valid_alphabet(alphabet, padding) {
+            Some(Self {
+                decoding_table: DecodingTable::from_alphabet(alphabet),
+                padding,
+                padding_mode: Self::padding_mode_for(require_padding),
             })
         } else {
             None
@@ -34,7 +62,38 @@ impl Default for Base64Validator {
         Self {
             decoding_table: DecodingTable::default(),
             padding: Base64Encoder::DEFAULT_PADDING,
-            require_padding: false,
+            padding_mode: PaddingMode::default(),
+        }
+    }
+}
+
+impl Base64Validator {
+    //! Configuration
+
+    /// Sets the padding mode.
+    pub const fn with_padding_mode(mut self, padding_mode: PaddingMode) -> Self {
+        self.padding_mode = padding_mode;
+        self
+    }
+
+    /// Gets the padding mode.
+    pub const fn padding_mode(&self) -> PaddingMode {
+        self.padding_mode
+    }
+}
+
+impl Base64Validator {
+    //! Special Validators
+
+    /// Gets the mixed validator.
+    ///
+    /// This validator accepts both the standard (`+/`) and the URL-safe (`-_`) alphabets, even
+    /// when mixed within the same data, with `=` as the padding.
+    pub const fn mixed_validator(require_padding: bool) -> Self {
+        Self {
+            decoding_table: DecodingTable::mixed_decoding_table(),
+            padding: Base64Encoder::DEFAULT_PADDING,
+            padding_mode: Self::padding_mode_for(require_padding),
         }
     }
 }
@@ -110,11 +169,12 @@ impl Base64Validator {
     unsafe fn is_valid_block_last(
         decoding_table: &[u8; 256],
         padding: Option<u8>,
-        require_padding: bool,
+        padding_mode: PaddingMode,
         data: &[u8],
     ) -> bool {
         debug_assert!(data.len() <= 4);
 
+        let require_padding: bool = padding_mode == PaddingMode::Required;
         match data.len() {
             1 => false,
             2 => !require_padding && unsafe { Self::is_valid_2_not_padded(decoding_table, data) },
@@ -126,6 +186,9 @@ impl Base64Validator {
                 if let Some(padding) = padding {
                     let d: u8 = unsafe { *data.get_unchecked(3) };
                     if d == padding {
+                        if padding_mode == PaddingMode::Forbidden {
+                            return false;
+                        }
                         let c: u8 = unsafe { *data.get_unchecked(2) };
                         if c == padding {
                             unsafe { Self::is_valid_2_not_padded(decoding_table, &data[..2]) }
@@ -158,7 +221,7 @@ impl Validator for Base64Validator {
                 Self::is_valid_block_last(
                     decoding_table,
                     self.padding,
-                    self.require_padding,
+                    self.padding_mode,
                     &data[last_chunk_index..],
                 )
             };
@@ -182,7 +245,8 @@ impl Validator for Base64Validator {
 #[cfg(test)]
 #[cfg(feature = "dev")]
 mod tests {
-    use crate::base_64::base_64_validator::Base64Validator;
+    use crate::Validator;
+    use crate::base_64::{Base64Encoder, Base64Validator, PaddingMode};
     use crate::test::test_validator;
 
     #[test]
@@ -229,4 +293,87 @@ mod tests {
         let validator: Base64Validator = Base64Validator::default();
         test_validator(&validator, test_cases);
     }
+
+    #[test]
+    fn is_valid_padding_mode() {
+        let test_cases: &[(&str, bool, bool, bool)] = &[
+            ("", true, true, true),
+            ("AAAA", true, true, true),
+            ("AA", false, true, true),
+            ("AAA", false, true, true),
+            ("AA==", true, true, false),
+            ("AAA=", true, true, false),
+            ("AAAAAA", false, true, true),
+            ("AAAAAA==", true, true, false),
+            ("AA=", false, false, false),
+        ];
+        for (data, required, optional, forbidden) in test_cases {
+            for (padding_mode, expected) in [
+                (PaddingMode::Required, required),
+                (PaddingMode::Optional, optional),
+                (PaddingMode::Forbidden, forbidden),
+            ] {
+                let validator: Base64Validator =
+                    Base64Validator::new(b'+', b'/', Some(b'='), false)
+                        .unwrap()
+                        .with_padding_mode(padding_mode);
+                assert_eq!(validator.padding_mode(), padding_mode);
+                assert_eq!(
+                    validator.is_valid(data.as_bytes()).unwrap(),
+                    *expected,
+                    "data={data}, padding_mode={padding_mode:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn require_padding() {
+        for (require_padding, padding_mode) in [
+            (true, PaddingMode::Required),
+            (false, PaddingMode::Optional),
+        ] {
+            let validator: Base64Validator =
+                Base64Validator::new(b'+', b'/', Some(b'='), require_padding).unwrap();
+            assert_eq!(validator.padding_mode(), padding_mode);
+            let validator: Base64Validator = Base64Validator::mixed_validator(require_padding);
+            assert_eq!(validator.padding_mode(), padding_mode);
+        }
+    }
+
+    #[test]
+    fn is_valid_mixed() {
+        let test_cases: &[(&str, bool)] = &[
+            ("", true),
+            ("89+/", true),
+            ("89-_", true),
+            ("89+_", true),
+            ("89-/", true),
+            ("+/-_", true),
+            ("-_8=", true),
+            ("-_8", true),
+            ("-_8==", false),
+            ("-_.8", false),
+            ("-_~8", false),
+        ];
+        let validator: Base64Validator = Base64Validator::mixed_validator(false);
+        test_validator(&validator, test_cases);
+    }
+
+    #[test]
+    fn is_valid_alphabet() {
+        let test_cases: &[(&str, bool)] = &[
+            ("", true),
+            ("./AB", true),
+            ("9999", true),
+            ("9u", true),
+            ("9u==", false),
+            ("89+/", false),
+        ];
+        let validator: Base64Validator =
+            Base64Validator::from_alphabet(Base64Encoder::BCRYPT_ALPHABET, None, false)
+                .unwrap()
+                .with_padding_mode(PaddingMode::Forbidden);
+        test_validator(&validator, test_cases);
+    }
 }
@@ -20,4 +20,15 @@ impl Base64Encoder {
 
     /// The URL-safe padding.
     pub const URL_SAFE_PADDING: Option<u8> = None;
+
+    /// The bcrypt alphabet. (used without padding)
+    pub const BCRYPT_ALPHABET: &'static [u8; 64] =
+        b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+    /// The crypt(3) alphabet. (used without padding)
+    ///
+    /// Only the alphabet matches crypt(3). Hashes such as MD5-crypt and SHA-crypt pack the bits
+    /// least-significant first, so they cannot be decoded with this alphabet alone.
+    pub const CRYPT_ALPHABET: &'static [u8; 64] =
+        b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
 }
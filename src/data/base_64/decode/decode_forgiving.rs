@@ -0,0 +1,55 @@
+/// Decodes the first `encoded_len` non-whitespace chars of the forgiving-base64 `data` into the
+/// `target` slice. Extra bits in the last char are discarded.
+///
+/// Returns the length of the decoded data.
+///
+/// # Note
+/// The `data` must be validated with `forgiving_decoded_len` and the `target` must have enough
+/// space for the decoded data.
+pub fn decode_forgiving(
+    table: &[u8; 256],
+    data: &[u8],
+    encoded_len: usize,
+    target: &mut [u8],
+) -> usize {
+    let mut bits: u32 = 0;
+    let mut bit_count: u32 = 0;
+    let mut t: usize = 0;
+    let chars = data.iter().filter(|c| !c.is_ascii_whitespace());
+    for c in chars.take(encoded_len) {
+        bits = (bits << 6) | table[*c as usize] as u32;
+        bit_count += 6;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            target[t] = (bits >> bit_count) as u8;
+            t += 1;
+        }
+    }
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base_64::decode::decode_forgiving::decode_forgiving;
+    use crate::base_64::decode::decoding_table::DecodingTable;
+
+    #[test]
+    fn fn_decode_forgiving() {
+        let test_cases: &[(&str, usize, &[u8])] = &[
+            ("", 0, b""),
+            ("YQ", 2, b"a"),
+            ("YR", 2, b"a"),
+            ("Y R = =", 2, b"a"),
+            ("//A", 3, b"\xFF\xF0"),
+            ("abcd", 4, b"\x69\xB7\x1D"),
+        ];
+        let table: DecodingTable = DecodingTable::default();
+        let table: &[u8; 256] = table.decoding_table();
+
+        for (data, encoded_len, expected) in test_cases {
+            let mut target: [u8; 3] = [0u8; 3];
+            let len: usize = decode_forgiving(table, data.as_bytes(), *encoded_len, &mut target);
+            assert_eq!(&target[..len], *expected, "data={data:?}");
+        }
+    }
+}
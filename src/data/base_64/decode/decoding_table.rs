@@ -1,77 +1,69 @@
 use crate::base_64::Base64Encoder;
-use std::cmp::Ordering;
-use std::hash::{Hash, Hasher};
-use std::sync::Arc;
 
 /// A base-64 decoding table.
 ///
-/// This implementation supports static and atomic reference counted tables. This strategy avoids
-/// all allocation for the standard decoding table configurations and also avoids allocation when
-/// cloning reference counted tables. The only allocation needed is when creating a new,
-/// non-standard decoding table.
-#[derive(Clone, Debug)]
-pub enum DecodingTable {
-    /// A static decoding table.
-    Static(&'static [u8; 256]),
-
-    /// An atomic reference counted decoding table.
-    Reference(Arc<[u8; 256]>),
+/// The table is stored inline so decoding tables can be created in `const` contexts.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct DecodingTable {
+    table: [u8; 256],
 }
 
-impl PartialEq for DecodingTable {
-    fn eq(&self, other: &Self) -> bool {
-        self.decoding_table() == other.decoding_table()
-    }
-}
-
-impl Eq for DecodingTable {}
+impl DecodingTable {
+    //! Special Tables
 
-impl Hash for DecodingTable {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.decoding_table().hash(state)
-    }
+    /// The standard decoding table.
+    const STANDARD: Self =
+        Self::get_decoding_table(Base64Encoder::DEFAULT_V63, Base64Encoder::DEFAULT_V64);
 }
 
-impl Ord for DecodingTable {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.decoding_table().cmp(other.decoding_table())
+impl DecodingTable {
+    //! Construction
+
+    /// Creates a custom decoding table.
+    pub const fn get_decoding_table(v63: u8, v64: u8) -> Self {
+        Self {
+            table: Self::create_custom_decoding_table(v63, v64),
+        }
     }
-}
 
-impl PartialOrd for DecodingTable {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    /// Creates a decoding table from the full `alphabet`.
+    pub const fn from_alphabet(alphabet: &[u8; 64]) -> Self {
+        Self {
+            table: Self::create_alphabet_decoding_table(alphabet),
+        }
     }
-}
 
-impl DecodingTable {
-    //! Special Tables
+    /// Creates a decoding table from the full `alphabet`.
+    const fn create_alphabet_decoding_table(alphabet: &[u8; 64]) -> [u8; 256] {
+        let mut t: [u8; 256] = [0xFF; 256];
 
-    /// The standard decoding table.
-    const STANDARD: Self = Self::Static(&Self::create_custom_decoding_table(
-        Base64Encoder::DEFAULT_V63,
-        Base64Encoder::DEFAULT_V64,
-    ));
-
-    /// The URL-safe decoding table.
-    const URL_SAFE: Self = Self::Static(&Self::create_custom_decoding_table(
-        Base64Encoder::URL_SAFE_V63,
-        Base64Encoder::URL_SAFE_V64,
-    ));
-}
+        let mut i: usize = 0;
+        while i < 64 {
+            t[alphabet[i] as usize] = i as u8;
+            i += 1;
+        }
 
-impl DecodingTable {
-    //! Construction
+        t
+    }
 
-    /// Creates a custom decoding table. Returns the static table if available.
-    pub fn get_decoding_table(v63: u8, v64: u8) -> Self {
-        match (v63, v64) {
-            (Base64Encoder::DEFAULT_V63, Base64Encoder::DEFAULT_V64) => Self::STANDARD,
-            (Base64Encoder::URL_SAFE_V63, Base64Encoder::URL_SAFE_V64) => Self::URL_SAFE,
-            (v63, v64) => Self::Reference(Arc::new(Self::create_custom_decoding_table(v63, v64))),
+    /// Creates the mixed decoding table. (accepts both the standard and URL-safe alphabets)
+    pub const fn mixed_decoding_table() -> Self {
+        Self {
+            table: Self::create_mixed_decoding_table(),
         }
     }
 
+    /// Creates the mixed decoding table.
+    const fn create_mixed_decoding_table() -> [u8; 256] {
+        let mut t: [u8; 256] = Self::create_custom_decoding_table(
+            Base64Encoder::DEFAULT_V63,
+            Base64Encoder::DEFAULT_V64,
+        );
+        t[Base64Encoder::URL_SAFE_V63 as usize] = 62;
+        t[Base64Encoder::URL_SAFE_V64 as usize] = 63;
+        t
+    }
+
     /// Creates a custom decoding table.
     const fn create_custom_decoding_table(v63: u8, v64: u8) -> [u8; 256] {
         let mut t: [u8; 256] = [0xFF; 256];
@@ -158,11 +150,8 @@ impl DecodingTable {
     //! Properties
 
     /// Gets the raw decoding table.
-    pub fn decoding_table(&self) -> &[u8; 256] {
-        match self {
-            Self::Static(table) => table,
-            Self::Reference(table) => table.as_ref(),
-        }
+    pub const fn decoding_table(&self) -> &[u8; 256] {
+        &self.table
     }
 }
 
@@ -173,20 +162,16 @@ mod tests {
 
     #[test]
     fn fn_get_decoding_table() {
-        let standard: DecodingTable = DecodingTable::get_decoding_table(
-            Base64Encoder::DEFAULT_V63,
-            Base64Encoder::DEFAULT_V64,
-        );
-        assert!(matches!(standard, DecodingTable::Static(_)));
-
-        let url_safe: DecodingTable = DecodingTable::get_decoding_table(
+        const URL_SAFE: DecodingTable = DecodingTable::get_decoding_table(
             Base64Encoder::URL_SAFE_V63,
             Base64Encoder::URL_SAFE_V64,
         );
-        assert!(matches!(url_safe, DecodingTable::Static(_)));
-
-        let custom: DecodingTable = DecodingTable::get_decoding_table(b'!', b'@');
-        assert!(matches!(custom, DecodingTable::Reference(_)));
+        let t: &[u8; 256] = URL_SAFE.decoding_table();
+        assert_eq!(t[b'A' as usize], 0);
+        assert_eq!(t[Base64Encoder::URL_SAFE_V63 as usize], 62);
+        assert_eq!(t[Base64Encoder::URL_SAFE_V64 as usize], 63);
+        assert_eq!(t[Base64Encoder::DEFAULT_V63 as usize], 0xFF);
+        assert_eq!(t[Base64Encoder::DEFAULT_V64 as usize], 0xFF);
     }
 
     #[test]
@@ -204,4 +189,33 @@ mod tests {
         assert_eq!(t[b'=' as usize], 0xFF);
         assert_eq!(t[b'!' as usize], 0xFF);
     }
+
+    #[test]
+    fn fn_mixed_decoding_table() {
+        let table: DecodingTable = DecodingTable::mixed_decoding_table();
+        let t: &[u8; 256] = table.decoding_table();
+        assert_eq!(t[b'A' as usize], 0);
+        assert_eq!(t[b'9' as usize], 61);
+        assert_eq!(t[Base64Encoder::DEFAULT_V63 as usize], 62);
+        assert_eq!(t[Base64Encoder::DEFAULT_V64 as usize], 63);
+        assert_eq!(t[Base64Encoder::URL_SAFE_V63 as usize], 62);
+        assert_eq!(t[Base64Encoder::URL_SAFE_V64 as usize], 63);
+        assert_eq!(t[b'=' as usize], 0xFF);
+    }
+
+    #[test]
+    fn fn_from_alphabet() {
+        let standard: DecodingTable = DecodingTable::from_alphabet(
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+        );
+        assert_eq!(standard, DecodingTable::default());
+
+        let bcrypt: DecodingTable = DecodingTable::from_alphabet(Base64Encoder::BCRYPT_ALPHABET);
+        let t: &[u8; 256] = bcrypt.decoding_table();
+        assert_eq!(t[b'.' as usize], 0);
+        assert_eq!(t[b'/' as usize], 1);
+        assert_eq!(t[b'A' as usize], 2);
+        assert_eq!(t[b'9' as usize], 63);
+        assert_eq!(t[b'+' as usize], 0xFF);
+    }
 }
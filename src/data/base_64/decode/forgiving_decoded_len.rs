@@ -0,0 +1,97 @@
+use crate::Error;
+
+/// Gets the number of encoded chars and the decoded length of the forgiving-base64 `data`.
+/// (WHATWG Infra)
+///
+/// ASCII whitespace is ignored. If the number of other chars is a multiple of 4, up to two
+/// trailing `padding` chars are ignored. The remaining chars must all be in the decoding `table`
+/// and their number must not have a remainder of 1 when divided by 4.
+///
+/// Returns an error with the offset of the invalid char, or the length of the `data` if the
+/// number of chars is invalid.
+pub fn forgiving_decoded_len(
+    table: &[u8; 256],
+    padding: Option<u8>,
+    data: &[u8],
+) -> Result<(usize, usize), Error> {
+    let len: usize = data.iter().filter(|c| !c.is_ascii_whitespace()).count();
+    let trailing_padding: usize = match padding {
+        Some(padding) => data
+            .iter()
+            .rev()
+            .filter(|c| !c.is_ascii_whitespace())
+            .take_while(|c| **c == padding)
+            .count(),
+        None => 0,
+    };
+    let encoded_len: usize = if len.is_multiple_of(4) {
+        len - trailing_padding.min(2)
+    } else {
+        len
+    };
+
+    let chars = data
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| !c.is_ascii_whitespace());
+    for (i, c) in chars.take(encoded_len) {
+        if table[*c as usize] == 0xFF {
+            return Err(Error::invalid_at(i, "invalid base-64 char"));
+        }
+    }
+    if encoded_len % 4 == 1 {
+        return Err(Error::invalid_at(data.len(), "invalid base-64 length"));
+    }
+
+    let decoded_len: usize = (encoded_len / 4) * 3 + (encoded_len % 4).saturating_sub(1);
+    Ok((encoded_len, decoded_len))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error;
+    use crate::base_64::decode::decoding_table::DecodingTable;
+    use crate::base_64::decode::forgiving_decoded_len::forgiving_decoded_len;
+
+    #[test]
+    fn fn_forgiving_decoded_len() {
+        let test_cases: &[(&str, usize, usize)] = &[
+            ("", 0, 0),
+            ("AA", 2, 1),
+            ("AAA", 3, 2),
+            ("AAAA", 4, 3),
+            ("AA==", 2, 1),
+            ("AAA=", 3, 2),
+            (" A A = = ", 2, 1),
+            ("AAAAAA", 6, 4),
+        ];
+        let table: DecodingTable = DecodingTable::default();
+        let table: &[u8; 256] = table.decoding_table();
+
+        for (data, encoded_len, decoded_len) in test_cases {
+            let result: (usize, usize) =
+                forgiving_decoded_len(table, Some(b'='), data.as_bytes()).unwrap();
+            assert_eq!(result, (*encoded_len, *decoded_len), "data={data:?}");
+        }
+    }
+
+    #[test]
+    fn fn_forgiving_decoded_len_invalid() {
+        let test_cases: &[(&str, usize)] = &[
+            ("A", 1),
+            ("AAAAA", 5),
+            ("A===", 1),
+            ("AA=", 2),
+            ("A=AA", 1),
+            ("AA\x0BA", 2),
+        ];
+        let table: DecodingTable = DecodingTable::default();
+        let table: &[u8; 256] = table.decoding_table();
+
+        for (data, offset) in test_cases {
+            let error: Error =
+                forgiving_decoded_len(table, Some(b'='), data.as_bytes()).unwrap_err();
+            assert_eq!(error.offset(), Some(*offset), "data={data:?}");
+        }
+    }
+}
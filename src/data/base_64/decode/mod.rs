@@ -3,8 +3,11 @@ pub mod decode_block_last;
 pub mod decode_block_last_1;
 pub mod decode_block_last_2;
 pub mod decode_block_last_3;
+pub mod decode_forgiving;
 pub mod decoded_len;
 pub mod decoded_len_last_block;
 pub mod decoding_table;
+pub mod forgiving_decoded_len;
 pub mod remove_padding_last_block;
 pub mod split_last_block;
+pub mod split_last_block_skip_whitespace;
@@ -0,0 +1,58 @@
+/// Checks if the byte `c` is whitespace that can be skipped. (CR, LF, space or tab)
+pub fn is_skipped_whitespace(c: u8) -> bool {
+    matches!(c, b'\r' | b'\n' | b' ' | b'\t')
+}
+
+/// Splits the `data` into full blocks and the last block while skipping whitespace.
+///
+/// Returns `(full_blocks_len, last_block, last_block_len)` where `full_blocks_len` is the number of
+/// non-whitespace bytes before the last block.
+///
+/// The last block will only be empty if `data` has no non-whitespace bytes, otherwise it will
+/// contain the last non-whitespace bytes. (1-4 bytes)
+pub fn split_last_block_skip_whitespace(data: &[u8]) -> (usize, [u8; 4], usize) {
+    let len: usize = data.iter().filter(|c| !is_skipped_whitespace(**c)).count();
+    let last_block_len: usize = if len == 0 {
+        0
+    } else {
+        let rem: usize = len % 4;
+        if rem == 0 { 4 } else { rem }
+    };
+
+    let mut last_block: [u8; 4] = [0u8; 4];
+    let last_chars = data.iter().rev().filter(|c| !is_skipped_whitespace(**c));
+    for (i, c) in last_chars.take(last_block_len).enumerate() {
+        last_block[last_block_len - 1 - i] = *c;
+    }
+    (len - last_block_len, last_block, last_block_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::base_64::decode::split_last_block_skip_whitespace::split_last_block_skip_whitespace;
+
+    #[test]
+    fn fn_split_last_block_skip_whitespace() {
+        let test_cases: &[(&str, usize, &str)] = &[
+            ("", 0, ""),
+            (" \r\n\t", 0, ""),
+            ("A", 0, "A"),
+            ("A A", 0, "AA"),
+            ("AA\r\nAA", 0, "AAAA"),
+            ("AAAA\r\nA", 4, "A"),
+            ("AA AA AA\n", 4, "AA"),
+            ("AAAA\nAAAA\nA=\n=", 8, "A=="),
+        ];
+
+        for (data, expected_1, expected_2) in test_cases {
+            let (full_blocks_len, last_block, last_block_len) =
+                split_last_block_skip_whitespace(data.as_bytes());
+            assert_eq!(full_blocks_len, *expected_1, "data={data:?}");
+            assert_eq!(
+                &last_block[..last_block_len],
+                expected_2.as_bytes(),
+                "data={data:?}"
+            );
+        }
+    }
+}
@@ -1,10 +1,13 @@
 pub use base_64_decoder::*;
 pub use base_64_encoder::*;
 pub use base_64_validator::*;
+pub use padding_mode::*;
 
 mod base_64_decoder;
 mod base_64_encoder;
+mod base_64_literal;
 mod base_64_validator;
+mod padding_mode;
 
 mod constants;
 
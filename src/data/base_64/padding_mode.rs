@@ -0,0 +1,13 @@
+/// The base-64 padding validation mode.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub enum PaddingMode {
+    /// The last block must be padded to 4 bytes.
+    Required,
+
+    /// The last block may or may not be padded.
+    #[default]
+    Optional,
+
+    /// The last block must not be padded.
+    Forbidden,
+}
@@ -0,0 +1,196 @@
+use crate::base_64::Base64Decoder;
+use crate::data_url::MimeType;
+use crate::percent::PercentDecoder;
+use crate::{Decoder, Error};
+use std::borrow::Cow;
+
+/// Responsible for decoding data URLs. (WHATWG Fetch)
+///
+/// The fragment is ignored and the data is percent decoded. If the MIME type has the `;base64`
+/// marker, the data is then decoded with the forgiving-base64 rules: ASCII whitespace is ignored
+/// and padding is optional. If the MIME type is invalid, it is `text/plain;charset=US-ASCII`.
+///
+/// # Errors
+/// Invalid URLs result in an `InvalidEncodedData` error with a `PositionedReason` holding the byte
+/// offset of the invalid text.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub struct DataUrlDecoder {}
+
+impl DataUrlDecoder {
+    //! Decoding
+
+    /// Decodes the data `url`.
+    ///
+    /// Returns the MIME type and the decoded data.
+    pub fn decode_url(&self, url: &str) -> Result<(MimeType, Vec<u8>), Error> {
+        let trimmed: &str = url.trim_start_matches(|c: char| c <= ' ');
+        let offset: usize = url.len() - trimmed.len();
+        let url: &str = trimmed.trim_end_matches(|c: char| c <= ' ');
+
+        if url.len() < 5 || !url.as_bytes()[..5].eq_ignore_ascii_case(b"data:") {
+            return Err(Error::invalid_at(offset, "expected `data:` scheme"));
+        }
+        let url: &str = &url[..url.find('#').unwrap_or(url.len())];
+        let comma: usize = url
+            .find(',')
+            .ok_or_else(|| Error::invalid_at(offset + url.len(), "expected `,`"))?;
+        let data_offset: usize = offset + comma + 1;
+        let encoded: &[u8] = &url.as_bytes()[(comma + 1)..];
+        let data: Cow<[u8]> = PercentDecoder::new().decode_cow(encoded)?;
+
+        let mut mime_type: &str = url[5..comma].trim_matches(|c: char| c.is_ascii_whitespace());
+        let data: Vec<u8> = match Self::strip_base_64_marker(mime_type) {
+            Some(stripped) => {
+                mime_type = stripped;
+                let decoder: Base64Decoder = Base64Decoder::default().with_forgiving(true);
+                decoder.decode_as_vec(&data).map_err(|e| match e.offset() {
+                    Some(decoded_offset) => {
+                        let offset: usize = PercentDecoder::encoded_offset(encoded, decoded_offset);
+                        Error::invalid_at(data_offset + offset, "invalid base-64 data")
+                    }
+                    None => e,
+                })?
+            }
+            None => data.into_owned(),
+        };
+
+        let mime_type: MimeType = if mime_type.starts_with(';') {
+            MimeType::parse(&format!("text/plain{mime_type}"))
+        } else {
+            MimeType::parse(mime_type)
+        }
+        .unwrap_or_else(MimeType::text_plain);
+        Ok((mime_type, data))
+    }
+
+    /// Strips the `;base64` marker from the end of the `mime_type`.
+    ///
+    /// Returns `None` if the `mime_type` does not have the marker.
+    fn strip_base_64_marker(mime_type: &str) -> Option<&str> {
+        let len: usize = mime_type.len();
+        if len < 6 || !mime_type.as_bytes()[(len - 6)..].eq_ignore_ascii_case(b"base64") {
+            return None;
+        }
+        mime_type[..(len - 6)]
+            .trim_end_matches(' ')
+            .strip_suffix(';')
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::StringEncoder;
+    use crate::data_url::{DataUrlDecoder, DataUrlEncoder, DataUrlEncoding, MimeType};
+    use crate::test::test_forgiving_base_64;
+
+    #[test]
+    fn decode_url() {
+        let test_cases: &[(&str, &str, &[u8])] = &[
+            ("data:,", "text/plain;charset=US-ASCII", b""),
+            (
+                "data:,Hello%2C%20World!",
+                "text/plain;charset=US-ASCII",
+                b"Hello, World!",
+            ),
+            ("  DATA:text/html,<p>hi</p>  ", "text/html", b"<p>hi</p>"),
+            ("data:image/png;base64,iVBORw==", "image/png", b"\x89PNG"),
+            ("data:image/png;BASE64,iVBORw", "image/png", b"\x89PNG"),
+            ("data:image/png ; base64,iV BO\nRw", "image/png", b"\x89PNG"),
+            ("data:;base64,SGk=", "text/plain;charset=US-ASCII", b"Hi"),
+            ("data:;charset=utf-8,a", "text/plain;charset=utf-8", b"a"),
+            (
+                "data:text/plain;charset=utf-8;base64,SGk%3D",
+                "text/plain;charset=utf-8",
+                b"Hi",
+            ),
+            ("data:base64,SGk=", "text/plain;charset=US-ASCII", b"SGk="),
+            ("data:text/plain;base64x,SGk=", "text/plain", b"SGk="),
+            ("data:invalid,a", "text/plain;charset=US-ASCII", b"a"),
+            ("data:,a#fragment", "text/plain;charset=US-ASCII", b"a"),
+            ("data:,a%ZZ", "text/plain;charset=US-ASCII", b"a%ZZ"),
+            ("data:;base64,QR==", "text/plain;charset=US-ASCII", b"A"),
+            (
+                "data:a\u{20AC}a\u{20AC},x",
+                "text/plain;charset=US-ASCII",
+                b"x",
+            ),
+            (
+                "data:\u{20AC}base64,SGk=",
+                "text/plain;charset=US-ASCII",
+                b"SGk=",
+            ),
+            (
+                "data:;base64,SGk=#\u{20AC}",
+                "text/plain;charset=US-ASCII",
+                b"Hi",
+            ),
+        ];
+        let decoder: DataUrlDecoder = DataUrlDecoder::default();
+
+        for (url, mime_type, data) in test_cases {
+            let (decoded_mime_type, decoded_data) = decoder.decode_url(url).unwrap();
+            assert_eq!(decoded_mime_type.to_string(), *mime_type, "url={url:?}");
+            assert_eq!(decoded_data, *data, "url={url:?}");
+        }
+    }
+
+    #[test]
+    fn decode_url_invalid() {
+        let test_cases: &[(&str, usize)] = &[
+            ("", 0),
+            ("http://a", 0),
+            ("  dat:,", 2),
+            ("data:text/plain", 15),
+            ("data:text/plain#,a", 15),
+            ("data:;base64,S", 14),
+            ("data:;base64,SGk!", 16),
+            ("data:;base64,S=Gk", 14),
+            ("data:;base64,SG===", 15),
+            ("data:;base64,SGk==", 16),
+            ("data:;base64,%53%47%6B%21", 22),
+            ("d\u{20AC}\u{20AC}:,x", 0),
+            ("\u{20AC}", 0),
+            ("data:;base64,\u{20AC}", 13),
+        ];
+        let decoder: DataUrlDecoder = DataUrlDecoder::default();
+
+        for (url, offset) in test_cases {
+            let error = decoder.decode_url(url).unwrap_err();
+            assert_eq!(error.offset(), Some(*offset), "url={url:?}");
+        }
+    }
+
+    #[test]
+    fn decode_url_forgiving_base_64() {
+        let decoder: DataUrlDecoder = DataUrlDecoder::default();
+        test_forgiving_base_64(|data| {
+            let data: String = data
+                .iter()
+                .map(|c| match c {
+                    b'!'..=b'~' if *c != b'%' && *c != b'#' && *c != b',' => {
+                        (*c as char).to_string()
+                    }
+                    _ => format!("%{c:02X}"),
+                })
+                .collect();
+            let url: String = format!("data:;base64,{data}");
+            decoder.decode_url(&url).ok().map(|(_, data)| data)
+        });
+    }
+
+    #[test]
+    fn encode_decode_url() {
+        let data: Vec<u8> = (0..=255u8).collect();
+        let mime_type: MimeType = MimeType::new("application", "octet-stream").unwrap();
+        for encoding in [DataUrlEncoding::Base64, DataUrlEncoding::Percent] {
+            let encoder: DataUrlEncoder = DataUrlEncoder::new(mime_type.clone(), encoding);
+            let url: String = encoder.encode_as_string(&data).unwrap();
+            let (decoded_mime_type, decoded_data) =
+                DataUrlDecoder::default().decode_url(&url).unwrap();
+            assert_eq!(decoded_mime_type, mime_type);
+            assert_eq!(decoded_data, data);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::Error::{InsufficientTargetSpace, IntegerOverflow};
+use crate::base_64::Base64Encoder;
+use crate::data_url::{DataUrlEncoding, MimeType};
+use crate::percent::PercentEncoder;
+use crate::{Encoder, Error, StringEncoder, data};
+
+/// Responsible for encoding data as a data URL. (RFC 2397)
+///
+/// The encoded URL is `data:<mime-type>[;base64],<data>`. Percent encoded data uses the WHATWG
+/// component percent-encode set.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct DataUrlEncoder {
+    mime_type: MimeType,
+    encoding: DataUrlEncoding,
+    prefix: String,
+}
+
+impl DataUrlEncoder {
+    //! Construction
+
+    /// Creates a new data URL encoder.
+    pub fn new(mime_type: MimeType, encoding: DataUrlEncoding) -> Self {
+        let prefix: String = match encoding {
+            DataUrlEncoding::Base64 => format!("data:{mime_type};base64,"),
+            DataUrlEncoding::Percent => format!("data:{mime_type},"),
+        };
+        Self {
+            mime_type,
+            encoding,
+            prefix,
+        }
+    }
+}
+
+impl DataUrlEncoder {
+    //! Properties
+
+    /// Gets the MIME type.
+    pub fn mime_type(&self) -> &MimeType {
+        &self.mime_type
+    }
+
+    /// Gets the encoding.
+    pub fn encoding(&self) -> DataUrlEncoding {
+        self.encoding
+    }
+}
+
+impl Encoder for DataUrlEncoder {
+    fn encoded_len(&self, data: &[u8]) -> Result<usize, Error> {
+        let data_len: usize = match self.encoding {
+            DataUrlEncoding::Base64 => Base64Encoder::default().encoded_len(data)?,
+            DataUrlEncoding::Percent => PercentEncoder::COMPONENT.encoded_len(data)?,
+        };
+        data_len
+            .checked_add(self.prefix.len())
+            .ok_or(IntegerOverflow)
+    }
+
+    fn encode_to_slice(&self, data: &[u8], target: &mut [u8]) -> Result<usize, Error> {
+        let encoded_len: usize = self.encoded_len(data)?;
+        if encoded_len > target.len() {
+            Err(InsufficientTargetSpace)
+        } else {
+            let prefix_len: usize = self.prefix.len();
+            target[..prefix_len].copy_from_slice(self.prefix.as_bytes());
+            let target: &mut [u8] = &mut target[prefix_len..];
+            let data_len: usize = match self.encoding {
+                DataUrlEncoding::Base64 => {
+                    Base64Encoder::default().encode_to_slice(data, target)?
+                }
+                DataUrlEncoding::Percent => {
+                    PercentEncoder::COMPONENT.encode_to_slice(data, target)?
+                }
+            };
+            debug_assert_eq!(encoded_len, prefix_len + data_len);
+            Ok(prefix_len + data_len)
+        }
+    }
+
+    fn encode_in_chunks(
+        &self,
+        data: &[u8],
+        buffer: &mut [u8],
+        f: &mut dyn FnMut(&[u8]) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        f(self.prefix.as_bytes())?;
+        match self.encoding {
+            DataUrlEncoding::Base64 => Base64Encoder::default().encode_in_chunks(data, buffer, f),
+            DataUrlEncoding::Percent => PercentEncoder::COMPONENT.encode_in_chunks(data, buffer, f),
+        }
+    }
+}
+
+impl StringEncoder for DataUrlEncoder {
+    fn append_to_string(&self, data: &[u8], target: &mut String) -> Result<usize, Error> {
+        unsafe { data::util::append_to_string_unchecked(self, data, target) }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::data_url::{DataUrlEncoder, DataUrlEncoding, MimeType};
+    use crate::test::test_string_encoder;
+
+    #[test]
+    fn encode_base_64() {
+        let test_cases: &[(&[u8], &str)] = &[
+            (b"", "data:image/png;base64,"),
+            (b"\x89PNG", "data:image/png;base64,iVBORw=="),
+        ];
+        let mime_type: MimeType = MimeType::new("image", "png").unwrap();
+        let encoder: DataUrlEncoder = DataUrlEncoder::new(mime_type, DataUrlEncoding::Base64);
+        test_string_encoder(&encoder, test_cases);
+    }
+
+    #[test]
+    fn encode_percent() {
+        let test_cases: &[(&str, &str)] = &[
+            ("", "data:text/html;charset=utf-8,"),
+            (
+                "<p>caf\u{00E9} #1</p>",
+                "data:text/html;charset=utf-8,%3Cp%3Ecaf%C3%A9%20%231%3C%2Fp%3E",
+            ),
+        ];
+        let mime_type: MimeType = MimeType::new("text", "html")
+            .unwrap()
+            .with_parameter("charset", "utf-8")
+            .unwrap();
+        let encoder: DataUrlEncoder = DataUrlEncoder::new(mime_type, DataUrlEncoding::Percent);
+        test_string_encoder(&encoder, test_cases);
+    }
+}
@@ -0,0 +1,10 @@
+/// The encoding of the data in a data URL.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub enum DataUrlEncoding {
+    /// The data is base-64 encoded and the URL has the `;base64` marker.
+    #[default]
+    Base64,
+
+    /// The data is percent encoded.
+    Percent,
+}
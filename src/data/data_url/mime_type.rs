@@ -0,0 +1,270 @@
+use std::fmt::{Display, Formatter};
+
+/// A MIME type with its parameters. (WHATWG MIME Sniffing)
+///
+/// The type, subtype and parameter names are ASCII lowercase.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct MimeType {
+    type_: String,
+    subtype: String,
+    parameters: Vec<(String, String)>,
+}
+
+impl MimeType {
+    //! Construction
+
+    /// Creates a new MIME type.
+    ///
+    /// Returns `None` if the `type_` or `subtype` is not a valid token.
+    pub fn new(type_: &str, subtype: &str) -> Option<Self> {
+        if Self::is_token(type_) && Self::is_token(subtype) {
+            Some(Self {
+                type_: type_.to_ascii_lowercase(),
+                subtype: subtype.to_ascii_lowercase(),
+                parameters: Vec::default(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Adds the parameter.
+    ///
+    /// Returns `None` if the `name` is not a valid token or the `value` contains chars that
+    /// cannot be quoted. If the parameter is already present this has no effect.
+    pub fn with_parameter(mut self, name: &str, value: &str) -> Option<Self> {
+        if !Self::is_token(name) || !value.chars().all(Self::is_quoted_string_char) {
+            None
+        } else {
+            let name: String = name.to_ascii_lowercase();
+            if self.parameter(&name).is_none() {
+                self.parameters.push((name, value.to_string()));
+            }
+            Some(self)
+        }
+    }
+
+    /// Gets the `text/plain;charset=US-ASCII` MIME type.
+    pub fn text_plain() -> Self {
+        Self::new("text", "plain")
+            .unwrap()
+            .with_parameter("charset", "US-ASCII")
+            .unwrap()
+    }
+}
+
+impl MimeType {
+    //! Properties
+
+    /// Gets the type.
+    pub fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    /// Gets the subtype.
+    pub fn subtype(&self) -> &str {
+        &self.subtype
+    }
+
+    /// Gets the essence. (`type/subtype`)
+    pub fn essence(&self) -> String {
+        format!("{}/{}", self.type_, self.subtype)
+    }
+
+    /// Gets the parameters.
+    pub fn parameters(&self) -> &[(String, String)] {
+        &self.parameters
+    }
+
+    /// Gets the value of the case-insensitive parameter `name`.
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+impl MimeType {
+    //! Validation
+
+    /// Checks if `c` is an HTTP token char.
+    fn is_token_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c)
+    }
+
+    /// Checks if `s` is a non-empty HTTP token.
+    fn is_token(s: &str) -> bool {
+        !s.is_empty() && s.chars().all(Self::is_token_char)
+    }
+
+    /// Checks if `c` is an HTTP quoted-string char.
+    fn is_quoted_string_char(c: char) -> bool {
+        c == '\t' || (' '..='~').contains(&c) || ('\u{80}'..='\u{FF}').contains(&c)
+    }
+
+    /// Checks if `c` is HTTP whitespace.
+    fn is_whitespace(c: char) -> bool {
+        matches!(c, '\t' | '\n' | '\r' | ' ')
+    }
+}
+
+impl MimeType {
+    //! Parsing
+
+    /// Parses the MIME type `text`.
+    ///
+    /// Returns `None` if the type or subtype is invalid. Invalid and duplicate parameters are
+    /// ignored.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text: &str = text.trim_matches(Self::is_whitespace);
+        let (type_, rest) = text.split_once('/')?;
+        let (subtype, mut rest) = match rest.find(';') {
+            Some(semicolon) => (&rest[..semicolon], &rest[semicolon..]),
+            None => (rest, ""),
+        };
+        let mut mime_type: Self = Self::new(type_, subtype.trim_end_matches(Self::is_whitespace))?;
+
+        while let Some(parameter) = rest.strip_prefix(';') {
+            let parameter: &str = parameter.trim_start_matches(Self::is_whitespace);
+            let name_len: usize = parameter.find([';', '=']).unwrap_or(parameter.len());
+            let name: &str = &parameter[..name_len];
+            rest = &parameter[name_len..];
+            let Some(value_text) = rest.strip_prefix('=') else {
+                continue;
+            };
+
+            let value: String = if let Some(quoted) = value_text.strip_prefix('"') {
+                let (value, quoted_len) = Self::parse_quoted(quoted);
+                let after: &str = &quoted[quoted_len..];
+                rest = &after[after.find(';').unwrap_or(after.len())..];
+                value
+            } else {
+                let value_len: usize = value_text.find(';').unwrap_or(value_text.len());
+                rest = &value_text[value_len..];
+                let value: &str = value_text[..value_len].trim_end_matches(Self::is_whitespace);
+                if value.is_empty() {
+                    continue;
+                }
+                value.to_string()
+            };
+
+            if let Some(with_parameter) = mime_type.clone().with_parameter(name, &value) {
+                mime_type = with_parameter;
+            }
+        }
+        Some(mime_type)
+    }
+
+    /// Parses the quoted-string `text` after the opening quote.
+    ///
+    /// Returns the unescaped value and the length of the text consumed including the closing
+    /// quote, if present.
+    fn parse_quoted(text: &str) -> (String, usize) {
+        let mut value: String = String::default();
+        let mut chars = text.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => return (value, i + 1),
+                '\\' => match chars.next() {
+                    Some((_, escaped)) => value.push(escaped),
+                    None => {
+                        value.push('\\');
+                        return (value, text.len());
+                    }
+                },
+                _ => value.push(c),
+            }
+        }
+        (value, text.len())
+    }
+}
+
+impl Display for MimeType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.type_, self.subtype)?;
+        for (name, value) in &self.parameters {
+            write!(f, ";{name}=")?;
+            if Self::is_token(value) {
+                write!(f, "{value}")?;
+            } else {
+                write!(f, "\"")?;
+                for c in value.chars() {
+                    if c == '"' || c == '\\' {
+                        write!(f, "\\")?;
+                    }
+                    write!(f, "{c}")?;
+                }
+                write!(f, "\"")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_url::MimeType;
+
+    #[test]
+    fn new_with_parameter() {
+        assert!(MimeType::new("text", "").is_none());
+        assert!(MimeType::new("te xt", "plain").is_none());
+        assert!(MimeType::new("text", "plain").is_some());
+
+        let mime_type: MimeType = MimeType::new("Text", "HTML")
+            .unwrap()
+            .with_parameter("Charset", "utf-8")
+            .unwrap()
+            .with_parameter("charset", "ignored")
+            .unwrap();
+        assert_eq!(mime_type.essence(), "text/html");
+        assert_eq!(mime_type.parameter("CHARSET"), Some("utf-8"));
+        assert_eq!(mime_type.to_string(), "text/html;charset=utf-8");
+        assert!(mime_type.with_parameter("a b", "c").is_none());
+    }
+
+    #[test]
+    fn parse_display() {
+        let test_cases: &[(&str, Option<&str>)] = &[
+            ("text/plain", Some("text/plain")),
+            (" Text/HTML ", Some("text/html")),
+            (
+                "image/svg+xml;charset=UTF-8",
+                Some("image/svg+xml;charset=UTF-8"),
+            ),
+            (
+                "text/plain ; Charset=utf-8 ",
+                Some("text/plain;charset=utf-8"),
+            ),
+            ("text/plain;a=1;a=2", Some("text/plain;a=1")),
+            ("text/plain;a;b=;c=3", Some("text/plain;c=3")),
+            ("text/plain;a=\"b c\"", Some("text/plain;a=\"b c\"")),
+            (
+                "text/plain;a=\"b\\\"c\";d=e",
+                Some("text/plain;a=\"b\\\"c\";d=e"),
+            ),
+            ("text/plain;a=\"b\" x;d=e", Some("text/plain;a=b;d=e")),
+            (
+                "text/plain;a=\"unterminated",
+                Some("text/plain;a=unterminated"),
+            ),
+            ("text/plain;a=\"\"", Some("text/plain;a=\"\"")),
+            ("text/plain;a b=c", Some("text/plain")),
+            ("text", None),
+            ("/plain", None),
+            ("text/", None),
+            ("text/pl ain", None),
+            ("", None),
+        ];
+
+        for (text, expected) in test_cases {
+            let mime_type: Option<MimeType> = MimeType::parse(text);
+            assert_eq!(
+                mime_type.map(|m| m.to_string()).as_deref(),
+                *expected,
+                "{text:?}"
+            );
+        }
+    }
+}
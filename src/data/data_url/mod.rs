@@ -0,0 +1,9 @@
+pub use data_url_decoder::*;
+pub use data_url_encoder::*;
+pub use data_url_encoding::*;
+pub use mime_type::*;
+
+mod data_url_decoder;
+mod data_url_encoder;
+mod data_url_encoding;
+mod mime_type;
@@ -0,0 +1,51 @@
+use crate::Error::InvalidEncodedData;
+use crate::{Decoder, Error};
+
+/// Extends `Decoder`s with decoding into fixed-size arrays.
+pub trait DecodeToArray: Decoder {
+    /// Decodes the `data` into an array.
+    ///
+    /// Returns an `InvalidEncodedData` error if the decoded length is not exactly `N`.
+    fn decode_to_array<const N: usize>(&self, data: &[u8]) -> Result<[u8; N], Error> {
+        let decoded_len: usize = self.decoded_len(data)?;
+        if decoded_len != N {
+            return Err(InvalidEncodedData {
+                reason: Some(
+                    format!("decoded length {decoded_len} does not match array length {N}").into(),
+                ),
+            });
+        }
+        let mut array: [u8; N] = [0u8; N];
+        self.decode_to_slice(data, &mut array)?;
+        Ok(array)
+    }
+}
+
+impl<D: Decoder + ?Sized> DecodeToArray for D {}
+
+#[cfg(test)]
+#[cfg(feature = "hex")]
+mod tests {
+    use crate::hex::HexDecoder;
+    use crate::{DecodeToArray, Error};
+
+    #[test]
+    fn decode_to_array() {
+        let decoder: HexDecoder = HexDecoder::default();
+
+        let array: [u8; 4] = decoder.decode_to_array(b"deadBEEF").unwrap();
+        assert_eq!(array, [0xDE, 0xAD, 0xBE, 0xEF]);
+
+        let empty: [u8; 0] = decoder.decode_to_array(b"").unwrap();
+        assert_eq!(empty, []);
+
+        let short: Result<[u8; 4], Error> = decoder.decode_to_array(b"deadbe");
+        assert!(matches!(short, Err(Error::InvalidEncodedData { .. })));
+
+        let long: Result<[u8; 2], Error> = decoder.decode_to_array(b"deadbe");
+        assert!(matches!(long, Err(Error::InvalidEncodedData { .. })));
+
+        let odd: Result<[u8; 1], Error> = decoder.decode_to_array(b"abc");
+        assert!(matches!(odd, Err(Error::InvalidEncodedData { .. })));
+    }
+}
@@ -0,0 +1,49 @@
+use crate::Error::InvalidEncodedData;
+use crate::{Error, StringEncoder};
+
+/// Extends `StringEncoder`s with encoding into fixed-size stack buffers.
+pub trait EncodeToArrayString: StringEncoder {
+    /// Encodes the `data` into the `buffer` without allocating.
+    ///
+    /// Returns the encoded string slice of the `buffer`. Returns an `InsufficientTargetSpace` error
+    /// if the encoded `data` does not fit in the `buffer`.
+    fn encode_to_array_string<'a, const N: usize>(
+        &self,
+        data: &[u8],
+        buffer: &'a mut [u8; N],
+    ) -> Result<&'a str, Error> {
+        let encoded_len: usize = self.encode_to_slice(data, buffer)?;
+        std::str::from_utf8(&buffer[..encoded_len]).map_err(|_| InvalidEncodedData {
+            reason: Some("the encoded data is not valid UTF-8".into()),
+        })
+    }
+}
+
+impl<E: StringEncoder + ?Sized> EncodeToArrayString for E {}
+
+#[cfg(test)]
+#[cfg(feature = "hex")]
+mod tests {
+    use crate::hex::HexEncoder;
+    use crate::{EncodeToArrayString, Error};
+
+    #[test]
+    fn encode_to_array_string() {
+        let mut buffer: [u8; 8] = [0u8; 8];
+        let encoded: &str = HexEncoder::LOWER
+            .encode_to_array_string(&[0xDE, 0xAD, 0xBE, 0xEF], &mut buffer)
+            .unwrap();
+        assert_eq!(encoded, "deadbeef");
+
+        let mut buffer: [u8; 16] = [0u8; 16];
+        let encoded: &str = HexEncoder::UPPER
+            .encode_to_array_string(&[0x01, 0xAB], &mut buffer)
+            .unwrap();
+        assert_eq!(encoded, "01AB");
+
+        let mut buffer: [u8; 3] = [0u8; 3];
+        let result: Result<&str, Error> =
+            HexEncoder::LOWER.encode_to_array_string(&[0x01, 0x02], &mut buffer);
+        assert!(matches!(result, Err(Error::InsufficientTargetSpace)));
+    }
+}
@@ -0,0 +1,192 @@
+use crate::Error::InvalidEncodedData;
+use crate::{Error, StringEncoder};
+use std::fmt::{Debug, Display, Formatter};
+
+/// The stack buffer length.
+const BUFFER_LEN: usize = 256;
+
+/// Creates a lazy `Display` & `Debug` adapter that encodes the `data` with the `encoder` when
+/// formatted.
+///
+/// The encoded data is streamed through a stack buffer with `Encoder::encode_in_chunks`, which does
+/// not allocate for the encoders of this crate. Other encoders without a block size that do not
+/// implement `encode_in_chunks` allocate if the encoded data does not fit in the buffer.
+pub fn display<'a, E>(encoder: &'a E, data: &'a [u8]) -> EncodedDisplay<'a, E>
+where
+    E: StringEncoder + ?Sized,
+{
+    EncodedDisplay::new(encoder, data)
+}
+
+/// Responsible for displaying encoded data without allocating.
+///
+/// # Streaming
+/// The data is encoded in chunks through a stack buffer with `Encoder::encode_in_chunks`. This does
+/// not allocate for the encoders of this crate. Other encoders without a block size that do not
+/// implement `encode_in_chunks` allocate if the encoded data does not fit in the buffer.
+///
+/// # Truncation
+/// An adapter created `with_max_data_len` encodes at most that many data bytes, rounded down to the
+/// encoder block size, followed by an ellipsis.
+#[derive(Copy, Clone)]
+pub struct EncodedDisplay<'a, E: ?Sized> {
+    encoder: &'a E,
+    data: &'a [u8],
+    max_data_len: Option<usize>,
+}
+
+impl<E: ?Sized> EncodedDisplay<'_, E> {
+    //! Constants
+
+    /// The ellipsis appended to truncated data.
+    pub const ELLIPSIS: &'static str = "...";
+}
+
+impl<'a, E> EncodedDisplay<'a, E>
+where
+    E: StringEncoder + ?Sized,
+{
+    //! Construction
+
+    /// Creates a new encoded display adapter.
+    pub fn new(encoder: &'a E, data: &'a [u8]) -> Self {
+        Self {
+            encoder,
+            data,
+            max_data_len: None,
+        }
+    }
+}
+
+impl<E> EncodedDisplay<'_, E>
+where
+    E: StringEncoder + ?Sized,
+{
+    //! Configuration
+
+    /// Sets the maximum number of data bytes to encode before truncating with an ellipsis.
+    pub fn with_max_data_len(mut self, max_data_len: usize) -> Self {
+        self.max_data_len = Some(max_data_len);
+        self
+    }
+
+    /// Gets the maximum number of data bytes to encode before truncating with an ellipsis.
+    pub fn max_data_len(&self) -> Option<usize> {
+        self.max_data_len
+    }
+}
+
+impl<E> EncodedDisplay<'_, E>
+where
+    E: StringEncoder + ?Sized,
+{
+    //! Formatting
+
+    /// Gets the data to encode and whether it was truncated.
+    fn truncated_data(&self, block_size: Option<usize>) -> (&[u8], bool) {
+        match self.max_data_len {
+            Some(max_data_len) if self.data.len() > max_data_len => {
+                let block_size: usize = block_size.unwrap_or(1).max(1);
+                let len: usize = max_data_len - (max_data_len % block_size);
+                (&self.data[..len], true)
+            }
+            _ => (self.data, false),
+        }
+    }
+}
+
+impl<E> Display for EncodedDisplay<'_, E>
+where
+    E: StringEncoder + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (data, truncated) = self.truncated_data(self.encoder.block_size());
+        let mut buffer: [u8; BUFFER_LEN] = [0u8; BUFFER_LEN];
+        self.encoder
+            .encode_in_chunks(data, &mut buffer, &mut |chunk| {
+                let chunk: &str = std::str::from_utf8(chunk).map_err(|_| InvalidEncodedData {
+                    reason: Some("the encoded data is not valid UTF-8".into()),
+                })?;
+                f.write_str(chunk)
+                    .map_err(|error| Error::Stream(std::io::Error::other(error)))
+            })
+            .map_err(|_| std::fmt::Error)?;
+        if truncated {
+            f.write_str(Self::ELLIPSIS)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E> Debug for EncodedDisplay<'_, E>
+where
+    E: StringEncoder + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hex")]
+#[cfg(feature = "base-64")]
+mod tests {
+    use crate::base_64::Base64Encoder;
+    use crate::hex::HexEncoder;
+    use crate::{EncodedDisplay, LineWrapEncoder, StringEncoder, display};
+
+    #[test]
+    fn display_hex() {
+        let test_cases: &[(&[u8], Option<usize>, &str)] = &[
+            (b"", None, ""),
+            (b"\xDE\xAD\xBE\xEF", None, "deadbeef"),
+            (b"\xDE\xAD\xBE\xEF", Some(4), "deadbeef"),
+            (b"\xDE\xAD\xBE\xEF", Some(2), "dead..."),
+            (b"\xDE\xAD\xBE\xEF", Some(0), "..."),
+        ];
+        for (data, max_data_len, expected) in test_cases {
+            let mut adapter: EncodedDisplay<HexEncoder> = display(&HexEncoder::LOWER, data);
+            if let Some(max_data_len) = max_data_len {
+                adapter = adapter.with_max_data_len(*max_data_len);
+            }
+            assert_eq!(format!("{adapter}"), *expected);
+            assert_eq!(format!("{adapter:?}"), *expected);
+        }
+    }
+
+    #[test]
+    fn display_base_64() {
+        let encoder: Base64Encoder = Base64Encoder::default();
+        let test_cases: &[(&[u8], Option<usize>, &str)] = &[
+            (b"Hello, World!", None, "SGVsbG8sIFdvcmxkIQ=="),
+            (b"Hello, World!", Some(7), "SGVsbG8s..."),
+            (b"Hello, World!", Some(2), "..."),
+        ];
+        for (data, max_data_len, expected) in test_cases {
+            let mut adapter: EncodedDisplay<Base64Encoder> = display(&encoder, data);
+            if let Some(max_data_len) = max_data_len {
+                adapter = adapter.with_max_data_len(*max_data_len);
+            }
+            assert_eq!(adapter.to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn display_chunks() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 7) as u8).collect();
+        let line_wrap: LineWrapEncoder<Base64Encoder> =
+            LineWrapEncoder::new(Base64Encoder::default(), 76, "\r\n").unwrap();
+        let line_wrap_hex: LineWrapEncoder<HexEncoder> =
+            LineWrapEncoder::new(HexEncoder::LOWER, 7, "\n").unwrap();
+        let encoders: &[&dyn StringEncoder] = &[
+            &HexEncoder::UPPER,
+            &Base64Encoder::default(),
+            &line_wrap,
+            &line_wrap_hex,
+        ];
+        for encoder in encoders {
+            let expected: String = encoder.encode_as_string(&data).unwrap();
+            assert_eq!(display(*encoder, &data).to_string(), expected);
+        }
+    }
+}
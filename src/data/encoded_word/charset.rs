@@ -0,0 +1,86 @@
+/// A charset supported when decoding encoded-words.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum Charset {
+    /// UTF-8 or its US-ASCII subset. The decoded bytes are passed through unchanged.
+    Utf8,
+
+    /// ISO-8859-1. The decoded bytes are transcoded to UTF-8.
+    Latin1,
+}
+
+impl Charset {
+    //! Construction
+
+    /// Gets the charset for the case-insensitive charset `name`.
+    ///
+    /// An RFC 2231 language suffix such as `UTF-8*en` is ignored.
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        let name: &[u8] = match name.iter().position(|c| *c == b'*') {
+            Some(star) => &name[..star],
+            None => name,
+        };
+        if name.eq_ignore_ascii_case(b"UTF-8") || name.eq_ignore_ascii_case(b"US-ASCII") {
+            Some(Self::Utf8)
+        } else if name.eq_ignore_ascii_case(b"ISO-8859-1") || name.eq_ignore_ascii_case(b"LATIN1") {
+            Some(Self::Latin1)
+        } else {
+            None
+        }
+    }
+}
+
+impl Charset {
+    //! Transcoding
+
+    /// Passes the UTF-8 encoding of the decoded byte `c` to the function `f`.
+    #[inline(always)]
+    pub fn transcode<F>(self, c: u8, f: &mut F)
+    where
+        F: FnMut(&[u8]),
+    {
+        match self {
+            Self::Latin1 if c >= 0x80 => f(&[0xC0 | (c >> 6), 0x80 | (c & 0x3F)]),
+            _ => f(&[c]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoded_word::charset::Charset;
+
+    #[test]
+    fn from_name() {
+        let test_cases: &[(&str, Option<Charset>)] = &[
+            ("UTF-8", Some(Charset::Utf8)),
+            ("utf-8", Some(Charset::Utf8)),
+            ("UTF-8*en", Some(Charset::Utf8)),
+            ("us-ascii", Some(Charset::Utf8)),
+            ("ISO-8859-1", Some(Charset::Latin1)),
+            ("iso-8859-1*fr", Some(Charset::Latin1)),
+            ("ISO-8859-2", None),
+            ("", None),
+        ];
+
+        for (name, expected) in test_cases {
+            assert_eq!(Charset::from_name(name.as_bytes()), *expected);
+        }
+    }
+
+    #[test]
+    fn transcode() {
+        let test_cases: &[(Charset, u8, &[u8])] = &[
+            (Charset::Utf8, b'a', b"a"),
+            (Charset::Utf8, 0xC3, &[0xC3]),
+            (Charset::Latin1, b'a', b"a"),
+            (Charset::Latin1, 0xE9, "\u{00E9}".as_bytes()),
+            (Charset::Latin1, 0xFF, "\u{00FF}".as_bytes()),
+        ];
+
+        for (charset, c, expected) in test_cases {
+            let mut result: Vec<u8> = Vec::default();
+            charset.transcode(*c, &mut |b: &[u8]| result.extend_from_slice(b));
+            assert_eq!(result, *expected);
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::Error::InsufficientTargetSpace;
+use crate::encoded_word::parsed_word::ParsedWord;
+use crate::{Decoder, Error};
+
+/// Responsible for decoding email header text containing RFC 2047 encoded-words.
+///
+/// # Whitespace
+/// Whitespace between two adjacent encoded-words is removed. All other whitespace and plain text
+/// passes through the decoder unchanged.
+///
+/// # Charsets
+/// Encoded-words in the `UTF-8`, `US-ASCII` and `ISO-8859-1` charsets are decoded to UTF-8.
+/// Encoded-words in other charsets, and malformed encoded-words, are left as plain text.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub struct EncodedWordDecoder {}
+
+impl EncodedWordDecoder {
+    //! Decoding
+
+    /// Checks if `c` is linear whitespace.
+    #[inline(always)]
+    fn is_whitespace(c: u8) -> bool {
+        matches!(c, b' ' | b'\t' | b'\r' | b'\n')
+    }
+
+    /// Passes each decoded token of the `data` to the function `f`.
+    fn decode_each<F>(data: &[u8], mut f: F)
+    where
+        F: FnMut(&[u8]),
+    {
+        let mut whitespace: &[u8] = &[];
+        let mut after_word: bool = false;
+        let mut i: usize = 0;
+        while i < data.len() {
+            if let Some(word) = ParsedWord::parse(&data[i..]) {
+                if !after_word {
+                    f(whitespace);
+                }
+                word.decode(&mut f);
+                whitespace = &[];
+                after_word = true;
+                i += word.len();
+            } else if Self::is_whitespace(data[i]) {
+                let len: usize = data[i..]
+                    .iter()
+                    .take_while(|c| Self::is_whitespace(**c))
+                    .count();
+                whitespace = &data[i..(i + len)];
+                i += len;
+            } else {
+                let len: usize = 1 + data[(i + 1)..]
+                    .iter()
+                    .take_while(|c| !Self::is_whitespace(**c) && **c != b'=')
+                    .count();
+                f(whitespace);
+                f(&data[i..(i + len)]);
+                whitespace = &[];
+                after_word = false;
+                i += len;
+            }
+        }
+        f(whitespace);
+    }
+}
+
+impl Decoder for EncodedWordDecoder {
+    fn decoded_len(&self, data: &[u8]) -> Result<usize, Error> {
+        let mut decoded_len: usize = 0;
+        Self::decode_each(data, |token| decoded_len += token.len());
+        Ok(decoded_len)
+    }
+
+    fn decode_to_slice(&self, data: &[u8], target: &mut [u8]) -> Result<usize, Error> {
+        let decoded_len: usize = self.decoded_len(data)?;
+        if decoded_len > target.len() {
+            Err(InsufficientTargetSpace)
+        } else {
+            let mut t: usize = 0;
+            Self::decode_each(data, |token| {
+                target[t..(t + token.len())].copy_from_slice(token);
+                t += token.len();
+            });
+            debug_assert_eq!(t, decoded_len);
+            Ok(t)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::encoded_word::{EncodedWordDecoder, EncodedWordEncoder, EncodedWordEncoding};
+    use crate::test::test_decoder;
+    use crate::{Decoder, Encoder, Error};
+
+    #[test]
+    fn decode() {
+        let test_cases: &[(&str, &str)] = &[
+            ("", ""),
+            ("plain text", "plain text"),
+            ("=?UTF-8?Q?caf=C3=A9?=", "caf\u{00E9}"),
+            ("=?UTF-8?B?5L2g5aW9?=", "\u{4F60}\u{597D}"),
+            ("=?ISO-8859-1?Q?a?=", "a"),
+            ("a =?ISO-8859-1?Q?b?= c", "a b c"),
+            ("=?ISO-8859-1?Q?a?= b", "a b"),
+            ("=?ISO-8859-1?Q?a?= =?ISO-8859-1?Q?b?=", "ab"),
+            ("=?ISO-8859-1?Q?a?=  =?ISO-8859-1?Q?b?=", "ab"),
+            ("=?ISO-8859-1?Q?a?=\r\n\t=?ISO-8859-1?Q?b?=", "ab"),
+            (
+                "=?ISO-8859-1?Q?a_b?=\r\n =?ISO-8859-2?Q?_b?=",
+                "a b\r\n =?ISO-8859-2?Q?_b?=",
+            ),
+            ("=?UTF-8?Q?a?= b =?UTF-8?Q?c?=", "a b c"),
+            ("=?UTF-8?Q?a?==?UTF-8?Q?b?=", "ab"),
+            ("x=?UTF-8?Q?a?=", "xa"),
+            ("=?UTF-8?Q?a?= ", "a "),
+            ("a = b", "a = b"),
+            ("=?UTF-8?B?!!!!?=", "=?UTF-8?B?!!!!?="),
+            ("=?UTF-8?Q?a", "=?UTF-8?Q?a"),
+        ];
+        let decoder: EncodedWordDecoder = EncodedWordDecoder::default();
+        test_decoder(&decoder, test_cases);
+    }
+
+    #[test]
+    fn decode_encoded() {
+        let text: String = "Gr\u{00FC}\u{00DF}e aus K\u{00F6}ln \u{1F600} ".repeat(10);
+        let decoder: EncodedWordDecoder = EncodedWordDecoder::default();
+        for encoding in [
+            None,
+            Some(EncodedWordEncoding::B),
+            Some(EncodedWordEncoding::Q),
+        ] {
+            let encoded: Vec<u8> = EncodedWordEncoder::new(encoding)
+                .encode_as_vec(text.as_bytes())
+                .unwrap();
+            assert_eq!(decoder.decode_as_vec(&encoded).unwrap(), text.as_bytes());
+        }
+    }
+
+    #[test]
+    fn decode_insufficient_space() {
+        let decoder: EncodedWordDecoder = EncodedWordDecoder::default();
+        let mut target: Vec<u8> = vec![];
+        assert!(matches!(
+            decoder.decode_to_slice(b"=?UTF-8?Q?a?=", &mut target),
+            Err(Error::InsufficientTargetSpace)
+        ));
+    }
+}
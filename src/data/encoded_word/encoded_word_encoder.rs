@@ -0,0 +1,249 @@
+use crate::Error::{InsufficientTargetSpace, IntegerOverflow};
+use crate::base_64::Base64Encoder;
+use crate::encoded_word::EncodedWordEncoding;
+use crate::encoded_word::q_encoding::{q_encode, q_encoded_len};
+use crate::{Encoder, Error, StringEncoder, data};
+
+/// Responsible for encoding UTF-8 text as RFC 2047 encoded-words for email headers.
+///
+/// The text is split into as many `=?UTF-8?B?...?=` or `=?UTF-8?Q?...?=` encoded-words as needed to
+/// keep each word within 75 chars. Words are separated by `CRLF SPACE` and never split a UTF-8
+/// char. If no encoding is specified, the one with the shorter encoded text is chosen.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub struct EncodedWordEncoder {
+    encoding: Option<EncodedWordEncoding>,
+}
+
+impl EncodedWordEncoder {
+    //! Constants
+
+    /// The maximum length of an encoded-word.
+    pub const MAX_WORD_LEN: usize = 75;
+
+    /// The separator between encoded-words.
+    pub const SEPARATOR: &'static str = "\r\n ";
+
+    /// The maximum length of the encoded text of a single encoded-word.
+    const MAX_TEXT_LEN: usize = Self::MAX_WORD_LEN - "=?UTF-8?B??=".len();
+
+    /// The maximum length of a single UTF-8 char.
+    const MAX_CHAR_LEN: usize = 4;
+}
+
+impl EncodedWordEncoder {
+    //! Construction
+
+    /// Creates a new encoded-word encoder.
+    ///
+    /// If the `encoding` is `None`, the encoding with the shorter encoded text is chosen.
+    pub const fn new(encoding: Option<EncodedWordEncoding>) -> Self {
+        Self { encoding }
+    }
+}
+
+impl EncodedWordEncoder {
+    //! Properties
+
+    /// Gets the encoding.
+    pub const fn encoding(self) -> Option<EncodedWordEncoding> {
+        self.encoding
+    }
+}
+
+impl EncodedWordEncoder {
+    //! Encoding
+
+    /// Gets the length of the UTF-8 char at the start of the `data`.
+    ///
+    /// Invalid UTF-8 is grouped the same way so the length is always in `1..=4`.
+    #[inline(always)]
+    fn char_len(data: &[u8]) -> usize {
+        1 + data
+            .iter()
+            .skip(1)
+            .take(Self::MAX_CHAR_LEN - 1)
+            .take_while(|c| (**c & 0xC0) == 0x80)
+            .count()
+    }
+
+    /// Gets the encoded text length of the `data` in a single encoded-word.
+    #[inline(always)]
+    fn text_len(encoding: EncodedWordEncoding, data: &[u8]) -> usize {
+        match encoding {
+            EncodedWordEncoding::B => data.len().div_ceil(3) * 4,
+            EncodedWordEncoding::Q => data.iter().map(|c| q_encoded_len(*c)).sum(),
+        }
+    }
+
+    /// Chooses the encoding for the `data`.
+    ///
+    /// The encoding with the shorter encoded text is chosen, ignoring the encoded-word overhead, by
+    /// counting the bytes that need escaping in the Q encoding. Ties are Q encoded.
+    fn choose_encoding(&self, data: &[u8]) -> EncodedWordEncoding {
+        self.encoding.unwrap_or_else(|| {
+            let escaped: usize = data.iter().filter(|c| q_encoded_len(**c) != 1).count();
+            let q_len: usize = data.len() + escaped * 2;
+            let b_len: usize = data.len().div_ceil(3) * 4;
+            if b_len < q_len {
+                EncodedWordEncoding::B
+            } else {
+                EncodedWordEncoding::Q
+            }
+        })
+    }
+
+    /// Gets the length of the `data` encoded with the `encoding`.
+    fn encoded_len_with(encoding: EncodedWordEncoding, data: &[u8]) -> Result<usize, Error> {
+        let mut encoded_len: Option<usize> = Some(0);
+        Self::encode_each(encoding, data, |token| {
+            encoded_len = encoded_len.and_then(|len| len.checked_add(token.len()));
+        });
+        encoded_len.ok_or(IntegerOverflow)
+    }
+
+    /// Passes each encoded token of the `data` to the function `f`.
+    fn encode_each<F>(encoding: EncodedWordEncoding, data: &[u8], mut f: F)
+    where
+        F: FnMut(&[u8]),
+    {
+        let prefix: &[u8] = match encoding {
+            EncodedWordEncoding::B => b"=?UTF-8?B?",
+            EncodedWordEncoding::Q => b"=?UTF-8?Q?",
+        };
+        let mut start: usize = 0;
+        while start < data.len() {
+            let mut end: usize = start;
+            while end < data.len() {
+                let char_end: usize = end + Self::char_len(&data[end..]);
+                if end > start
+                    && Self::text_len(encoding, &data[start..char_end]) > Self::MAX_TEXT_LEN
+                {
+                    break;
+                }
+                end = char_end;
+            }
+
+            if start > 0 {
+                f(Self::SEPARATOR.as_bytes());
+            }
+            f(prefix);
+            match encoding {
+                EncodedWordEncoding::B => {
+                    let mut text: [u8; Self::MAX_TEXT_LEN] = [0u8; Self::MAX_TEXT_LEN];
+                    let text_len: usize = Base64Encoder::default()
+                        .encode_to_slice(&data[start..end], &mut text)
+                        .unwrap();
+                    f(&text[..text_len]);
+                }
+                EncodedWordEncoding::Q => q_encode(&data[start..end], &mut f),
+            }
+            f(b"?=");
+            start = end;
+        }
+    }
+}
+
+impl Encoder for EncodedWordEncoder {
+    fn encoded_len(&self, data: &[u8]) -> Result<usize, Error> {
+        Self::encoded_len_with(self.choose_encoding(data), data)
+    }
+
+    fn encode_to_slice(&self, data: &[u8], target: &mut [u8]) -> Result<usize, Error> {
+        let encoding: EncodedWordEncoding = self.choose_encoding(data);
+        let encoded_len: usize = Self::encoded_len_with(encoding, data)?;
+        if encoded_len > target.len() {
+            Err(InsufficientTargetSpace)
+        } else {
+            let mut t: usize = 0;
+            Self::encode_each(encoding, data, |token| {
+                target[t..(t + token.len())].copy_from_slice(token);
+                t += token.len();
+            });
+            debug_assert_eq!(encoded_len, t);
+            Ok(t)
+        }
+    }
+
+    fn encode_in_chunks(
+        &self,
+        data: &[u8],
+        buffer: &mut [u8],
+        f: &mut dyn FnMut(&[u8]) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let encoding: EncodedWordEncoding = self.choose_encoding(data);
+        data::util::encode_tokens_in_chunks(
+            |token_fn| Self::encode_each(encoding, data, token_fn),
+            buffer,
+            f,
+        )
+    }
+}
+
+impl StringEncoder for EncodedWordEncoder {
+    fn append_to_string(&self, data: &[u8], target: &mut String) -> Result<usize, Error> {
+        unsafe { data::util::append_to_string_unchecked(self, data, target) }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::Encoder;
+    use crate::encoded_word::{EncodedWordEncoder, EncodedWordEncoding};
+    use crate::test::test_string_encoder;
+
+    #[test]
+    fn encode_auto() {
+        let test_cases: &[(&str, &str)] = &[
+            ("", ""),
+            ("Hello World", "=?UTF-8?Q?Hello_World?="),
+            ("caf\u{00E9}", "=?UTF-8?B?Y2Fmw6k=?="),
+            ("caf\u{00E9} au lait", "=?UTF-8?Q?caf=C3=A9_au_lait?="),
+            ("\u{4F60}\u{597D}", "=?UTF-8?B?5L2g5aW9?="),
+        ];
+        let encoder: EncodedWordEncoder = EncodedWordEncoder::default();
+        test_string_encoder(&encoder, test_cases);
+    }
+
+    #[test]
+    fn encode_b() {
+        let text: String = "\u{00E9}".repeat(23);
+        let expected: String = format!(
+            "=?UTF-8?B?{}?=\r\n =?UTF-8?B?w6k=?=",
+            "w6nDqcOp".repeat(7) + "w6k="
+        );
+        let test_cases: &[(&str, &str)] = &[
+            ("a", "=?UTF-8?B?YQ==?="),
+            ("Hello World", "=?UTF-8?B?SGVsbG8gV29ybGQ=?="),
+            (&text, &expected),
+        ];
+        let encoder: EncodedWordEncoder = EncodedWordEncoder::new(Some(EncodedWordEncoding::B));
+        test_string_encoder(&encoder, test_cases);
+    }
+
+    #[test]
+    fn encode_q() {
+        let text: String = "\u{00E9}".repeat(11);
+        let expected: String =
+            format!("=?UTF-8?Q?{}?=\r\n =?UTF-8?Q?=C3=A9?=", "=C3=A9".repeat(10));
+        let test_cases: &[(&str, &str)] = &[
+            ("a b_c=d?", "=?UTF-8?Q?a_b=5Fc=3Dd=3F?="),
+            (&text, &expected),
+        ];
+        let encoder: EncodedWordEncoder = EncodedWordEncoder::new(Some(EncodedWordEncoding::Q));
+        test_string_encoder(&encoder, test_cases);
+    }
+
+    #[test]
+    fn encode_word_len() {
+        let text: String = "a\u{00E9}\u{4F60}\u{1F600} ".repeat(40);
+        for encoding in [EncodedWordEncoding::B, EncodedWordEncoding::Q] {
+            let encoder: EncodedWordEncoder = EncodedWordEncoder::new(Some(encoding));
+            let encoded: Vec<u8> = encoder.encode_as_vec(text.as_bytes()).unwrap();
+            let encoded: String = String::from_utf8(encoded).unwrap();
+            for word in encoded.split(EncodedWordEncoder::SEPARATOR) {
+                assert!(word.len() <= EncodedWordEncoder::MAX_WORD_LEN, "{word}");
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+/// The encoding of the text in an encoded-word. (RFC 2047)
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum EncodedWordEncoding {
+    /// The base-64 `B` encoding.
+    B,
+
+    /// The quoted-printable `Q` encoding.
+    Q,
+}
+
+impl EncodedWordEncoding {
+    //! Properties
+
+    /// Gets the encoding char.
+    pub const fn to_char(self) -> char {
+        match self {
+            Self::B => 'B',
+            Self::Q => 'Q',
+        }
+    }
+
+    /// Gets the encoding for the case-insensitive encoding char `c`.
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c {
+            'B' | 'b' => Some(Self::B),
+            'Q' | 'q' => Some(Self::Q),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoded_word::EncodedWordEncoding;
+
+    #[test]
+    fn to_char_from_char() {
+        let test_cases: &[(char, Option<EncodedWordEncoding>)] = &[
+            ('B', Some(EncodedWordEncoding::B)),
+            ('b', Some(EncodedWordEncoding::B)),
+            ('Q', Some(EncodedWordEncoding::Q)),
+            ('q', Some(EncodedWordEncoding::Q)),
+            ('X', None),
+        ];
+
+        for (c, expected) in test_cases {
+            assert_eq!(EncodedWordEncoding::from_char(*c), *expected);
+            if let Some(encoding) = expected {
+                assert_eq!(encoding.to_char(), c.to_ascii_uppercase());
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+pub use encoded_word_decoder::*;
+pub use encoded_word_encoder::*;
+pub use encoded_word_encoding::*;
+
+mod encoded_word_decoder;
+mod encoded_word_encoder;
+mod encoded_word_encoding;
+
+pub(in crate::data::encoded_word) mod charset;
+pub(in crate::data::encoded_word) mod parsed_word;
+pub(in crate::data::encoded_word) mod q_encoding;
@@ -0,0 +1,122 @@
+use crate::base_64::{Base64Decoder, Base64Validator};
+use crate::encoded_word::EncodedWordEncoding;
+use crate::encoded_word::charset::Charset;
+use crate::encoded_word::q_encoding::q_decode;
+use crate::{Decoder, Validator};
+
+/// A parsed `=?charset?encoding?text?=` encoded-word.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ParsedWord<'a> {
+    len: usize,
+    charset: Charset,
+    encoding: EncodedWordEncoding,
+    text: &'a [u8],
+}
+
+impl<'a> ParsedWord<'a> {
+    //! Parsing
+
+    /// Checks if `c` is valid in the charset or encoded text of an encoded-word.
+    #[inline(always)]
+    fn is_token_char(c: u8) -> bool {
+        c.is_ascii_graphic() && c != b'?'
+    }
+
+    /// Parses the encoded-word at the start of the `data`.
+    ///
+    /// Returns `None` if the `data` does not start with a decodable encoded-word.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let rest: &[u8] = data.strip_prefix(b"=?")?;
+        let charset_len: usize = rest.iter().take_while(|c| Self::is_token_char(**c)).count();
+        let charset: Charset = Charset::from_name(&rest[..charset_len])?;
+        let rest: &[u8] = rest[charset_len..].strip_prefix(b"?")?;
+        let (encoding, rest) = match rest {
+            [c, b'?', rest @ ..] => (EncodedWordEncoding::from_char(*c as char)?, rest),
+            _ => return None,
+        };
+        let text_len: usize = rest.iter().take_while(|c| Self::is_token_char(**c)).count();
+        let text: &[u8] = &rest[..text_len];
+        rest[text_len..].strip_prefix(b"?=")?;
+        if encoding == EncodedWordEncoding::B && !Base64Validator::default().is_valid(text).ok()? {
+            return None;
+        }
+        Some(Self {
+            len: 2 + charset_len + 3 + text_len + 2,
+            charset,
+            encoding,
+            text,
+        })
+    }
+}
+
+impl ParsedWord<'_> {
+    //! Properties
+
+    /// Gets the length of the encoded-word.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl ParsedWord<'_> {
+    //! Decoding
+
+    /// Passes the decoded UTF-8 text of the encoded-word to the function `f`.
+    pub fn decode<F>(&self, f: &mut F)
+    where
+        F: FnMut(&[u8]),
+    {
+        match self.encoding {
+            EncodedWordEncoding::B => {
+                let decoder: Base64Decoder = Base64Decoder::default();
+                for block in self.text.chunks(4) {
+                    let mut decoded: [u8; 3] = [0u8; 3];
+                    let decoded_len: usize = decoder.decode_to_slice(block, &mut decoded).unwrap();
+                    for c in &decoded[..decoded_len] {
+                        self.charset.transcode(*c, f);
+                    }
+                }
+            }
+            EncodedWordEncoding::Q => q_decode(self.text, |c| self.charset.transcode(c, f)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoded_word::parsed_word::ParsedWord;
+
+    #[test]
+    fn parse_decode() {
+        let test_cases: &[(&str, Option<(usize, &str)>)] = &[
+            ("=?UTF-8?B?SGk=?=", Some((16, "Hi"))),
+            ("=?utf-8?b?SGk=?= rest", Some((16, "Hi"))),
+            ("=?UTF-8?B?SGk?=", Some((15, "Hi"))),
+            ("=?UTF-8?B??=", Some((12, ""))),
+            (
+                "=?UTF-8?Q?caf=C3=A9_au_lait?=",
+                Some((29, "caf\u{00E9} au lait")),
+            ),
+            ("=?ISO-8859-1?Q?caf=E9?=", Some((23, "caf\u{00E9}"))),
+            ("=?UTF-8*en?Q?a?=", Some((16, "a"))),
+            ("=?UTF-8?B?S!k=?=", None),
+            ("=?UTF-8?X?a?=", None),
+            ("=?KOI8-R?Q?a?=", None),
+            ("=?UTF-8?Q?a b?=", None),
+            ("=?UTF-8?Q?a", None),
+            ("=?UTF-8?Q", None),
+            ("=?", None),
+            ("", None),
+        ];
+
+        for (data, expected) in test_cases {
+            let word: Option<ParsedWord> = ParsedWord::parse(data.as_bytes());
+            assert_eq!(word.map(|word| word.len()), expected.map(|e| e.0), "{data}");
+            if let (Some(word), Some((_, text))) = (word, expected) {
+                let mut decoded: Vec<u8> = Vec::default();
+                word.decode(&mut |b: &[u8]| decoded.extend_from_slice(b));
+                assert_eq!(decoded, text.as_bytes());
+            }
+        }
+    }
+}
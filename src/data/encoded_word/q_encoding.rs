@@ -0,0 +1,108 @@
+use crate::hex::{HexDecoder, HexEncoder, HexValidator};
+
+/// Checks if `c` is a literal in Q encoded text.
+///
+/// This is the most restrictive set of RFC 2047 so the encoded-word is valid in any header.
+#[inline(always)]
+fn is_literal(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, b'!' | b'*' | b'+' | b'-' | b'/')
+}
+
+/// Gets the Q encoded length of the byte `c`.
+#[inline(always)]
+pub fn q_encoded_len(c: u8) -> usize {
+    if is_literal(c) || c == b' ' { 1 } else { 3 }
+}
+
+/// Passes the Q encoded `data` to the function `f`.
+pub fn q_encode<F>(data: &[u8], mut f: F)
+where
+    F: FnMut(&[u8]),
+{
+    for c in data {
+        if is_literal(*c) {
+            f(&[*c]);
+        } else if *c == b' ' {
+            f(b"_");
+        } else {
+            let (high, low) = HexEncoder::UPPER.encode_bytes(*c);
+            f(&[b'=', high, low]);
+        }
+    }
+}
+
+/// Passes the Q decoded `data` to the function `f`.
+///
+/// Improperly encoded bytes pass through unchanged.
+pub fn q_decode<F>(data: &[u8], mut f: F)
+where
+    F: FnMut(u8),
+{
+    let mut i: usize = 0;
+    while i < data.len() {
+        match &data[i..] {
+            [b'_', ..] => {
+                f(b' ');
+                i += 1;
+            }
+            [b'=', high, low, ..]
+                if HexValidator::CASELESS.is_valid_byte(*high)
+                    && HexValidator::CASELESS.is_valid_byte(*low) =>
+            {
+                f(HexDecoder::decode_bytes(*high, *low));
+                i += 3;
+            }
+            [c, ..] => {
+                f(*c);
+                i += 1;
+            }
+            [] => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::encoded_word::q_encoding::{q_decode, q_encode, q_encoded_len};
+
+    #[test]
+    fn encode_decode() {
+        let test_cases: &[(&[u8], &str)] = &[
+            (b"", ""),
+            (b"azAZ09!*+-/", "azAZ09!*+-/"),
+            (b"a b", "a_b"),
+            (b"a_b", "a=5Fb"),
+            (b"a=?b", "a=3D=3Fb"),
+            (b"\x00\xFF", "=00=FF"),
+        ];
+
+        for (data, encoded) in test_cases {
+            let mut result: Vec<u8> = Vec::default();
+            q_encode(data, |token| result.extend_from_slice(token));
+            assert_eq!(result, encoded.as_bytes());
+            let len: usize = data.iter().map(|c| q_encoded_len(*c)).sum();
+            assert_eq!(len, encoded.len());
+
+            let mut result: Vec<u8> = Vec::default();
+            q_decode(encoded.as_bytes(), |c| result.push(c));
+            assert_eq!(result, *data);
+        }
+    }
+
+    #[test]
+    fn decode_lenient() {
+        let test_cases: &[(&str, &[u8])] = &[
+            ("=3d", b"="),
+            ("=", b"="),
+            ("=4", b"=4"),
+            ("=GG", b"=GG"),
+            ("a.b", b"a.b"),
+        ];
+
+        for (encoded, expected) in test_cases {
+            let mut result: Vec<u8> = Vec::default();
+            q_decode(encoded.as_bytes(), |c| result.push(c));
+            assert_eq!(result, *expected);
+        }
+    }
+}
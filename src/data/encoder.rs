@@ -10,6 +10,30 @@ pub trait Encoder {
     /// Returns the length of the encoded `data`.
     fn encode_to_slice(&self, data: &[u8], target: &mut [u8]) -> Result<usize, Error>;
 
+    /// Gets the block size of the encoder.
+    ///
+    /// Returns `Some(n)` if encoding the `data` in chunks of any multiple of `n` bytes and
+    /// concatenating the results is the same as encoding the `data` at once. Returns `None` if the
+    /// encoder is stateful across the `data`.
+    fn block_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Encodes the `data` in chunks through the `buffer`, passing each encoded chunk to `f`.
+    ///
+    /// Concatenating the chunks is the same as encoding the `data` at once. The chunks of a
+    /// `StringEncoder` are valid UTF-8. The default implementation encodes chunks of the block size
+    /// if the encoder has one. Otherwise, or if a chunk does not fit in the `buffer`, the chunk is
+    /// encoded as a heap allocated vec.
+    fn encode_in_chunks(
+        &self,
+        data: &[u8],
+        buffer: &mut [u8],
+        f: &mut dyn FnMut(&[u8]) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        data::util::default_encode_in_chunks(self, data, buffer, f)
+    }
+
     /// Appends the encoded `data` to the `target` vec.
     ///
     /// Returns the length of the encoded `data`.
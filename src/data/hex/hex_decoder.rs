@@ -52,6 +52,43 @@ impl HexDecoder {
     }
 }
 
+impl HexDecoder {
+    //! Const Decoding
+
+    /// Gets the length of the decoded `data` in a `const` context.
+    ///
+    /// # Panics
+    /// Panics if the `data` has an odd number of bytes.
+    pub const fn decoded_len_const(data: &[u8]) -> usize {
+        if !data.len().is_multiple_of(2) {
+            panic!("invalid hex data: odd number of bytes");
+        }
+        data.len() / 2
+    }
+
+    /// Decodes the `data` into an array in a `const` context.
+    ///
+    /// # Panics
+    /// Panics if the `data` contains an invalid hex byte or `N` is not the decoded length.
+    pub const fn decode_array<const N: usize>(data: &[u8]) -> [u8; N] {
+        if Self::decoded_len_const(data) != N {
+            panic!("invalid hex data: decoded length mismatch");
+        }
+        let mut target: [u8; N] = [0u8; N];
+        let mut i: usize = 0;
+        while i < N {
+            let high: u8 = data[i * 2];
+            let low: u8 = data[i * 2 + 1];
+            if !high.is_ascii_hexdigit() || !low.is_ascii_hexdigit() {
+                panic!("invalid hex data: invalid hex byte");
+            }
+            target[i] = Self::decode_bytes(high, low);
+            i += 1;
+        }
+        target
+    }
+}
+
 impl Decoder for HexDecoder {
     fn decoded_len(&self, data: &[u8]) -> Result<usize, Error> {
         let div: usize = data.len() / 2;
@@ -144,4 +181,28 @@ mod tests {
         ];
         test_decoder(&HexDecoder::default(), test_cases);
     }
+
+    #[test]
+    fn decode_array() {
+        assert_eq!(HexDecoder::decode_array::<0>(b""), []);
+        assert_eq!(HexDecoder::decode_array::<2>(b"aBcD"), [0xAB, 0xCD]);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hex byte")]
+    fn decode_array_invalid_byte() {
+        HexDecoder::decode_array::<1>(b"zz");
+    }
+
+    #[test]
+    #[should_panic(expected = "odd number of bytes")]
+    fn decode_array_odd_len() {
+        HexDecoder::decode_array::<1>(b"abc");
+    }
+
+    #[test]
+    #[should_panic(expected = "decoded length mismatch")]
+    fn decode_array_wrong_len() {
+        HexDecoder::decode_array::<3>(b"abcd");
+    }
 }
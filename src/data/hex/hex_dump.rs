@@ -0,0 +1,260 @@
+use crate::Error;
+use crate::hex::HexEncoder;
+use std::fmt::{Display, Formatter, Write as _};
+use std::io::Write;
+
+/// Responsible for displaying data as a hex dump in the `xxd` format.
+///
+/// # Format
+/// Each line has the offset of its first byte as 8 or more hex digits, a colon, the hex encoded
+/// bytes separated into groups, and an ASCII gutter. Printable ASCII bytes are shown in the gutter
+/// as-is and all other bytes are shown as `.`. Every line ends with `\n`.
+///
+/// ```text
+/// 00000000: 4865 6c6c 6f2c 2057 6f72 6c64 210a       Hello, World!.
+/// ```
+///
+/// # Color
+/// A hex dump created `with_color` wraps each byte in ANSI escape codes: green for printable ASCII,
+/// yellow for ASCII whitespace, white for null and red for all other bytes. Colored output cannot
+/// be parsed by the `HexDumpDecoder`.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct HexDump<'a> {
+    data: &'a [u8],
+    bytes_per_line: usize,
+    group_size: usize,
+    ascii: bool,
+    color: bool,
+}
+
+impl HexDump<'_> {
+    //! Constants
+
+    /// The default number of bytes per line.
+    pub const DEFAULT_BYTES_PER_LINE: usize = 16;
+
+    /// The default number of bytes per group.
+    pub const DEFAULT_GROUP_SIZE: usize = 2;
+
+    /// The ANSI color reset escape code.
+    const RESET: &'static str = "\x1b[0m";
+}
+
+impl<'a> HexDump<'a> {
+    //! Construction
+
+    /// Creates a new hex dump of the `data`.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            bytes_per_line: Self::DEFAULT_BYTES_PER_LINE,
+            group_size: Self::DEFAULT_GROUP_SIZE,
+            ascii: true,
+            color: false,
+        }
+    }
+}
+
+impl HexDump<'_> {
+    //! Configuration
+
+    /// Sets the number of bytes per line. A value of zero is treated as one.
+    pub fn with_bytes_per_line(mut self, bytes_per_line: usize) -> Self {
+        self.bytes_per_line = bytes_per_line.max(1);
+        self
+    }
+
+    /// Gets the number of bytes per line.
+    pub fn bytes_per_line(&self) -> usize {
+        self.bytes_per_line
+    }
+
+    /// Sets the number of bytes per group. A value of zero disables grouping.
+    pub fn with_group_size(mut self, group_size: usize) -> Self {
+        self.group_size = group_size;
+        self
+    }
+
+    /// Gets the number of bytes per group. A value of zero means grouping is disabled.
+    pub fn group_size(&self) -> usize {
+        self.group_size
+    }
+
+    /// Sets whether the ASCII gutter is shown.
+    pub fn with_ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Checks if the ASCII gutter is shown.
+    pub fn shows_ascii(&self) -> bool {
+        self.ascii
+    }
+
+    /// Sets whether the bytes are colored with ANSI escape codes.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Checks if the bytes are colored with ANSI escape codes.
+    pub fn is_colored(&self) -> bool {
+        self.color
+    }
+}
+
+impl HexDump<'_> {
+    //! Writing
+
+    /// Writes the hex dump to the `target`.
+    pub fn write_to<W>(&self, target: &mut W) -> Result<(), Error>
+    where
+        W: Write,
+    {
+        write!(target, "{self}")?;
+        Ok(())
+    }
+
+    /// Gets the ANSI color escape code for the byte `b`.
+    fn color_code(b: u8) -> &'static str {
+        match b {
+            0x00 => "\x1b[37m",
+            b if b.is_ascii_whitespace() => "\x1b[33m",
+            b if b.is_ascii_graphic() || b == b' ' => "\x1b[32m",
+            _ => "\x1b[31m",
+        }
+    }
+
+    /// Writes the `chars` for the byte `b` to the formatter `f`, colored if enabled.
+    fn write_byte_chars(&self, f: &mut Formatter<'_>, b: u8, chars: &[char]) -> std::fmt::Result {
+        if self.color {
+            f.write_str(Self::color_code(b))?;
+        }
+        for c in chars {
+            f.write_char(*c)?;
+        }
+        if self.color {
+            f.write_str(Self::RESET)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the `line` starting at the data `offset` to the formatter `f`.
+    fn write_line(&self, f: &mut Formatter<'_>, offset: usize, line: &[u8]) -> std::fmt::Result {
+        write!(f, "{offset:08x}:")?;
+        let hex_len: usize = if self.ascii {
+            self.bytes_per_line
+        } else {
+            line.len()
+        };
+        for i in 0..hex_len {
+            if i == 0 || (self.group_size != 0 && i.is_multiple_of(self.group_size)) {
+                f.write_char(' ')?;
+            }
+            match line.get(i) {
+                Some(b) => {
+                    let (high, low) = HexEncoder::LOWER.encode_chars(*b);
+                    self.write_byte_chars(f, *b, &[high, low])?;
+                }
+                None => f.write_str("  ")?,
+            }
+        }
+        if self.ascii {
+            f.write_str("  ")?;
+            for b in line {
+                let c: char = if b.is_ascii_graphic() || *b == b' ' {
+                    *b as char
+                } else {
+                    '.'
+                };
+                self.write_byte_chars(f, *b, &[c])?;
+            }
+        }
+        f.write_char('\n')
+    }
+}
+
+impl Display for HexDump<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, line) in self.data.chunks(self.bytes_per_line).enumerate() {
+            self.write_line(f, i * self.bytes_per_line, line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hex::HexDump;
+
+    #[test]
+    fn display() {
+        let data: &[u8] = b"Hello, World!\nabcdefghijklmnopq\x00\x01\xFF";
+        let expected: &str = concat!(
+            "00000000: 4865 6c6c 6f2c 2057 6f72 6c64 210a 6162  Hello, World!.ab\n",
+            "00000010: 6364 6566 6768 696a 6b6c 6d6e 6f70 7100  cdefghijklmnopq.\n",
+            "00000020: 01ff                                     ..\n",
+        );
+        assert_eq!(HexDump::new(data).to_string(), expected);
+        assert_eq!(HexDump::new(b"").to_string(), "");
+    }
+
+    #[test]
+    fn display_layout() {
+        let data: &[u8] = b"Hello, World!\n";
+        let test_cases: &[(usize, usize, bool, &str)] = &[
+            (
+                8,
+                1,
+                true,
+                "00000000: 48 65 6c 6c 6f 2c 20 57  Hello, W\n00000008: 6f 72 6c 64 21 0a        orld!.\n",
+            ),
+            (
+                16,
+                0,
+                true,
+                "00000000: 48656c6c6f2c20576f726c64210a      Hello, World!.\n",
+            ),
+            (
+                16,
+                3,
+                true,
+                "00000000: 48656c 6c6f2c 20576f 726c64 210a       Hello, World!.\n",
+            ),
+            (
+                4,
+                4,
+                false,
+                "00000000: 48656c6c\n00000004: 6f2c2057\n00000008: 6f726c64\n0000000c: 210a\n",
+            ),
+        ];
+        for (bytes_per_line, group_size, ascii, expected) in test_cases {
+            let dump: HexDump = HexDump::new(data)
+                .with_bytes_per_line(*bytes_per_line)
+                .with_group_size(*group_size)
+                .with_ascii(*ascii);
+            assert_eq!(dump.to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn display_color() {
+        let dump: HexDump = HexDump::new(b"A\n\x00\xFF").with_color(true);
+        let expected: &str = concat!(
+            "00000000: \x1b[32m41\x1b[0m\x1b[33m0a\x1b[0m \x1b[37m00\x1b[0m\x1b[31mff\x1b[0m",
+            "                              ",
+            "  \x1b[32mA\x1b[0m\x1b[33m.\x1b[0m\x1b[37m.\x1b[0m\x1b[31m.\x1b[0m\n",
+        );
+        assert_eq!(dump.to_string(), expected);
+    }
+
+    #[test]
+    fn write_to() {
+        let mut target: Vec<u8> = Vec::default();
+        HexDump::new(b"Hi").write_to(&mut target).unwrap();
+        assert_eq!(
+            target.as_slice(),
+            b"00000000: 4869                                     Hi\n"
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use crate::Error::{InsufficientTargetSpace, IntegerOverflow};
+use crate::hex::HexDecoder;
+use crate::{Decoder, Error};
+
+/// Responsible for decoding hex dumps in the `xxd` format. (`xxd -r`)
+///
+/// # Format
+/// Each line starts with the hex offset of its first byte followed by a colon. The hex encoded
+/// bytes follow, optionally separated into groups by single spaces. Two consecutive spaces end the
+/// hex column, so the ASCII gutter is ignored. Blank lines and autoskip `*` lines (`xxd -a`) are
+/// skipped.
+///
+/// # Offsets
+/// Each line is decoded at its offset. Lines may be out of order and gaps are filled with zeros, so
+/// the decoded length is the end of the line with the greatest end offset. The first line does not
+/// need to start at offset zero, as with `xxd -s`.
+///
+/// # Memory
+/// A single line with a large offset can result in a large decoded length. A decoder created
+/// `with_max_decoded_len` fails on any line that would decode beyond that length.
+///
+/// # Validation
+/// Invalid lines result in an `InvalidEncodedData` error with the offset of the invalid byte.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub struct HexDumpDecoder {
+    max_decoded_len: Option<usize>,
+}
+
+impl HexDumpDecoder {
+    //! Construction
+
+    /// Creates a new hex dump decoder with the default configuration.
+    pub const fn new() -> Self {
+        Self {
+            max_decoded_len: None,
+        }
+    }
+}
+
+impl HexDumpDecoder {
+    //! Configuration
+
+    /// Sets the maximum decoded length.
+    ///
+    /// If set, any line that would decode beyond the length is an error with the offset of the line.
+    pub const fn with_max_decoded_len(mut self, max_decoded_len: Option<usize>) -> Self {
+        self.max_decoded_len = max_decoded_len;
+        self
+    }
+
+    /// Gets the maximum decoded length.
+    pub const fn max_decoded_len(self) -> Option<usize> {
+        self.max_decoded_len
+    }
+}
+
+impl HexDumpDecoder {
+    //! Decoding
+
+    /// Parses the hex `line` starting at the `line_offset` of the encoded data.
+    ///
+    /// Calls `f` with the decoded offset and decoded value of each byte.
+    fn parse_line<F>(&self, line: &[u8], line_offset: usize, f: &mut F) -> Result<(), Error>
+    where
+        F: FnMut(usize, u8),
+    {
+        let colon: usize = line
+            .iter()
+            .position(|c| *c == b':')
+            .ok_or_else(|| Error::invalid_at(line_offset, "missing offset"))?;
+        if colon == 0 {
+            return Err(Error::invalid_at(line_offset, "missing offset"));
+        }
+        let mut offset: usize = 0;
+        for (i, c) in line[..colon].iter().enumerate() {
+            if !c.is_ascii_hexdigit() {
+                return Err(Error::invalid_at(line_offset + i, "invalid offset"));
+            }
+            offset = offset
+                .checked_mul(16)
+                .and_then(|offset| offset.checked_add(HexDecoder::decode_bytes(b'0', *c) as usize))
+                .ok_or_else(|| Error::invalid_at(line_offset, "offset too large"))?;
+        }
+
+        let mut i: usize = colon + 1;
+        if line.get(i) == Some(&b' ') {
+            i += 1;
+        }
+        while i < line.len() {
+            match line[i] {
+                b' ' => {
+                    if i + 1 == line.len() || line[i + 1] == b' ' {
+                        break;
+                    }
+                    i += 1;
+                }
+                high if high.is_ascii_hexdigit() => match line.get(i + 1) {
+                    Some(low) if low.is_ascii_hexdigit() => {
+                        let next: usize = offset.checked_add(1).ok_or(IntegerOverflow)?;
+                        if self.max_decoded_len.is_some_and(|max| next > max) {
+                            return Err(Error::invalid_at(line_offset, "decoded length too large"));
+                        }
+                        f(offset, HexDecoder::decode_bytes(high, *low));
+                        offset = next;
+                        i += 2;
+                    }
+                    _ => return Err(Error::invalid_at(line_offset + i + 1, "invalid hex byte")),
+                },
+                _ => return Err(Error::invalid_at(line_offset + i, "invalid hex byte")),
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses each line of the `data`.
+    ///
+    /// Calls `f` with the decoded offset and decoded value of each byte.
+    fn parse_each<F>(&self, data: &[u8], mut f: F) -> Result<(), Error>
+    where
+        F: FnMut(usize, u8),
+    {
+        let mut line_offset: usize = 0;
+        for line in data.split(|c| *c == b'\n') {
+            let content: &[u8] = line.strip_suffix(b"\r").unwrap_or(line);
+            if !content.iter().all(u8::is_ascii_whitespace) && content.trim_ascii() != b"*" {
+                self.parse_line(content, line_offset, &mut f)?;
+            }
+            line_offset += line.len() + 1;
+        }
+        Ok(())
+    }
+}
+
+impl Decoder for HexDumpDecoder {
+    fn decoded_len(&self, data: &[u8]) -> Result<usize, Error> {
+        let mut decoded_len: usize = 0;
+        self.parse_each(data, |offset, _| decoded_len = decoded_len.max(offset + 1))?;
+        Ok(decoded_len)
+    }
+
+    fn decode_to_slice(&self, data: &[u8], target: &mut [u8]) -> Result<usize, Error> {
+        let decoded_len: usize = self.decoded_len(data)?;
+        if decoded_len > target.len() {
+            Err(InsufficientTargetSpace)
+        } else {
+            let target: &mut [u8] = &mut target[..decoded_len];
+            target.fill(0);
+            self.parse_each(data, |offset, b| target[offset] = b)?;
+            Ok(decoded_len)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::hex::{HexDump, HexDumpDecoder};
+    use crate::test::test_decoder;
+    use crate::{Decoder, Error};
+
+    #[test]
+    fn decode() {
+        let test_cases: &[(&str, &[u8])] = &[
+            ("", b""),
+            ("\n\r\n", b""),
+            ("00000000: 4869  Hi\n", b"Hi"),
+            ("00000000: 4869  Hi", b"Hi"),
+            ("00000000: 4869\r\n", b"Hi"),
+            ("0: 48 69 21", b"Hi!"),
+            ("00000000: 48692120", b"Hi! "),
+            ("00000000: 4869 2120  4869 2120", b"Hi! "),
+            ("00000000: 4142  AB\n00000002: 4344  CD\n", b"ABCD"),
+            ("00000002: 4344  CD\n00000000: 4142  AB\n", b"ABCD"),
+            ("00000004: 4142  AB\n", b"\x00\x00\x00\x00AB"),
+            (
+                "0000000a: FF\n",
+                b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xFF",
+            ),
+            (
+                "00000000: 4142  AB\n*\n00000004: 4344  CD\n",
+                b"AB\x00\x00CD",
+            ),
+            ("00000000: 4142  AB\n  *\r\n", b"AB"),
+        ];
+        test_decoder(&HexDumpDecoder::default(), test_cases);
+    }
+
+    #[test]
+    fn decode_offset() {
+        let decoded: Vec<u8> = HexDumpDecoder::default()
+            .decode_as_vec(b"00001000: 4142  AB\n")
+            .unwrap();
+        assert_eq!(decoded.len(), 0x1002);
+        assert!(decoded[..0x1000].iter().all(|b| *b == 0));
+        assert_eq!(&decoded[0x1000..], b"AB");
+    }
+
+    #[test]
+    fn decode_autoskip() {
+        let data: &str = concat!(
+            "00000000: 4142 0000 0000 0000 0000 0000 0000 0000  AB..............\n",
+            "00000010: 0000 0000 0000 0000 0000 0000 0000 0000  ................\n",
+            "*\n",
+            "00000040: 0000 4344                                ..CD\n",
+        );
+        let mut expected: Vec<u8> = vec![0u8; 68];
+        expected[..2].copy_from_slice(b"AB");
+        expected[66..].copy_from_slice(b"CD");
+        let decoded: Vec<u8> = HexDumpDecoder::default()
+            .decode_as_vec(data.as_bytes())
+            .unwrap();
+        assert_eq!(decoded, expected);
+    }
+
+    #[test]
+    fn decode_max_decoded_len() {
+        let decoder: HexDumpDecoder = HexDumpDecoder::new().with_max_decoded_len(Some(4));
+        assert_eq!(decoder.max_decoded_len(), Some(4));
+        assert_eq!(
+            decoder.decode_as_vec(b"00000002: 4142").unwrap(),
+            b"\x00\x00AB"
+        );
+        let test_cases: &[(&str, usize)] = &[
+            ("00000003: 4142", 0),
+            ("00000000: 4142\n00000004: 00", 15),
+            ("7fffffffffffffff: 00", 0),
+            ("ffffffff: 00", 0),
+        ];
+        for (data, offset) in test_cases {
+            let error: Error = decoder.decode_as_vec(data.as_bytes()).unwrap_err();
+            assert_eq!(error.offset(), Some(*offset), "data={data:?}");
+        }
+    }
+
+    #[test]
+    fn decode_invalid() {
+        let test_cases: &[(&str, usize)] = &[
+            ("4869", 0),
+            (": 4869", 0),
+            ("0000000g: 4869", 7),
+            ("00000000: 486", 13),
+            ("00000000: 48 6", 14),
+            ("00000000: 48x9", 12),
+            ("00000000: 4869\n0000000: 4x", 25),
+            ("ffffffffffffffffffff: 00", 0),
+            ("00000000: 00\n**\n", 13),
+        ];
+        for (data, offset) in test_cases {
+            let error: Error = HexDumpDecoder::default()
+                .decode_as_vec(data.as_bytes())
+                .unwrap_err();
+            assert_eq!(error.offset(), Some(*offset), "data={data:?}");
+        }
+    }
+
+    #[test]
+    fn decode_hex_dump() {
+        let data: Vec<u8> = (0..=255u8)
+            .chain(b"Hello, World!\n".iter().copied())
+            .collect();
+        for (bytes_per_line, group_size) in [(16, 2), (8, 1), (32, 0), (7, 3)] {
+            let dump: String = HexDump::new(&data)
+                .with_bytes_per_line(bytes_per_line)
+                .with_group_size(group_size)
+                .to_string();
+            let decoded: Vec<u8> = HexDumpDecoder::default()
+                .decode_as_vec(dump.as_bytes())
+                .unwrap();
+            assert_eq!(decoded, data);
+        }
+    }
+}
@@ -88,6 +88,10 @@ impl Encoder for HexEncoder {
             Ok(encoded_len)
         }
     }
+
+    fn block_size(&self) -> Option<usize> {
+        Some(1)
+    }
 }
 
 impl StringEncoder for HexEncoder {
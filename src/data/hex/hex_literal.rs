@@ -0,0 +1,40 @@
+/// Decodes a hex string literal into a `[u8; N]` array at compile time.
+///
+/// The literal is case-insensitive. Compilation fails if the literal is not valid hex.
+///
+/// ```
+/// const KEY: [u8; 4] = enc::hex!("deadBEEF");
+/// assert_eq!(KEY, [0xDE, 0xAD, 0xBE, 0xEF]);
+/// ```
+///
+/// ```compile_fail,E0080
+/// const KEY: [u8; 1] = enc::hex!("zz");
+/// ```
+///
+/// ```compile_fail,E0080
+/// const KEY: [u8; 1] = enc::hex!("abc");
+/// ```
+#[macro_export]
+macro_rules! hex {
+    ($data:expr) => {{
+        const DATA: &[u8] = $data.as_bytes();
+        const LEN: usize = $crate::hex::HexDecoder::decoded_len_const(DATA);
+        const DECODED: [u8; LEN] = $crate::hex::HexDecoder::decode_array::<LEN>(DATA);
+        DECODED
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn hex() {
+        const KEY: [u8; 4] = crate::hex!("deadBEEF");
+        assert_eq!(KEY, [0xDE, 0xAD, 0xBE, 0xEF]);
+
+        let empty: [u8; 0] = crate::hex!("");
+        assert_eq!(empty, []);
+
+        let bytes: [u8; 3] = crate::hex!("00ff7f");
+        assert_eq!(bytes, [0x00, 0xFF, 0x7F]);
+    }
+}
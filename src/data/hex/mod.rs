@@ -1,7 +1,12 @@
 pub use hex_decoder::*;
+pub use hex_dump::*;
+pub use hex_dump_decoder::*;
 pub use hex_encoder::*;
 pub use hex_validator::*;
 
 mod hex_decoder;
+mod hex_dump;
+mod hex_dump_decoder;
 mod hex_encoder;
+mod hex_literal;
 mod hex_validator;
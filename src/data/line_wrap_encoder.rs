@@ -0,0 +1,200 @@
+use crate::Error::{InsufficientTargetSpace, IntegerOverflow, InvalidEncodedData};
+use crate::{Encoder, Error, StringEncoder};
+
+/// Responsible for wrapping the output of an encoder into lines.
+///
+/// The `separator` is inserted between lines and is not appended after the last line. The line
+/// length is measured in bytes, so the encoder should produce ASCII output when used as a
+/// `StringEncoder`.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct LineWrapEncoder<E> {
+    encoder: E,
+    line_len: usize,
+    separator: &'static str,
+}
+
+impl<E> LineWrapEncoder<E> {
+    //! Constants
+
+    /// The MIME line length. (RFC 2045)
+    pub const MIME_LINE_LEN: usize = 76;
+
+    /// The CRLF line separator.
+    pub const CRLF: &'static str = "\r\n";
+
+    /// The LF line separator.
+    pub const LF: &'static str = "\n";
+}
+
+impl<E> LineWrapEncoder<E> {
+    //! Construction
+
+    /// Creates a new line-wrapping encoder.
+    ///
+    /// Returns `None` if the `line_len` is zero.
+    pub fn new(encoder: E, line_len: usize, separator: &'static str) -> Option<Self> {
+        if line_len == 0 {
+            None
+        } else {
+            Some(Self {
+                encoder,
+                line_len,
+                separator,
+            })
+        }
+    }
+
+    /// Creates a new line-wrapping encoder with 76 character lines separated by CRLF. (RFC 2045)
+    pub fn mime(encoder: E) -> Self {
+        Self::new(encoder, Self::MIME_LINE_LEN, Self::CRLF).unwrap()
+    }
+}
+
+impl<E> LineWrapEncoder<E> {
+    //! Properties
+
+    /// Gets the wrapped encoder.
+    pub fn encoder(&self) -> &E {
+        &self.encoder
+    }
+
+    /// Gets the line length.
+    pub fn line_len(&self) -> usize {
+        self.line_len
+    }
+
+    /// Gets the line separator.
+    pub fn separator(&self) -> &'static str {
+        self.separator
+    }
+}
+
+impl<E> LineWrapEncoder<E> {
+    //! Wrapping
+
+    /// Gets the length of the `unwrapped_len` encoded data after wrapping.
+    fn wrapped_len(&self, unwrapped_len: usize) -> Result<usize, Error> {
+        let separator_count: usize = unwrapped_len.div_ceil(self.line_len).saturating_sub(1);
+        separator_count
+            .checked_mul(self.separator.len())
+            .and_then(|len| len.checked_add(unwrapped_len))
+            .ok_or(IntegerOverflow)
+    }
+
+    /// Inserts the separators into the `unwrapped_len` encoded data at the start of the `target`.
+    ///
+    /// The lines are moved from last to first so the expansion can be done in place.
+    fn wrap_in_place(&self, unwrapped_len: usize, target: &mut [u8]) {
+        let separator: &[u8] = self.separator.as_bytes();
+        let line_count: usize = unwrapped_len.div_ceil(self.line_len);
+        for line in (1..line_count).rev() {
+            let start: usize = line * self.line_len;
+            let end: usize = (start + self.line_len).min(unwrapped_len);
+            let shift: usize = line * separator.len();
+            target.copy_within(start..end, start + shift);
+            target[(start + shift - separator.len())..(start + shift)].copy_from_slice(separator);
+        }
+    }
+}
+
+impl<E> Encoder for LineWrapEncoder<E>
+where
+    E: Encoder,
+{
+    fn encoded_len(&self, data: &[u8]) -> Result<usize, Error> {
+        self.wrapped_len(self.encoder.encoded_len(data)?)
+    }
+
+    fn encode_to_slice(&self, data: &[u8], target: &mut [u8]) -> Result<usize, Error> {
+        let encoded_len: usize = self.encoded_len(data)?;
+        if encoded_len > target.len() {
+            Err(InsufficientTargetSpace)
+        } else {
+            let target: &mut [u8] = &mut target[..encoded_len];
+            let unwrapped_len: usize = self.encoder.encode_to_slice(data, target)?;
+            self.wrap_in_place(unwrapped_len, target);
+            Ok(encoded_len)
+        }
+    }
+
+    fn encode_in_chunks(
+        &self,
+        data: &[u8],
+        buffer: &mut [u8],
+        f: &mut dyn FnMut(&[u8]) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let mut column: usize = 0;
+        self.encoder
+            .encode_in_chunks(data, buffer, &mut |mut chunk| {
+                while !chunk.is_empty() {
+                    if column == self.line_len {
+                        f(self.separator.as_bytes())?;
+                        column = 0;
+                    }
+                    let len: usize = (self.line_len - column).min(chunk.len());
+                    f(&chunk[..len])?;
+                    column += len;
+                    chunk = &chunk[len..];
+                }
+                Ok(())
+            })
+    }
+}
+
+impl<E> StringEncoder for LineWrapEncoder<E>
+where
+    E: StringEncoder,
+{
+    fn append_to_string(&self, data: &[u8], target: &mut String) -> Result<usize, Error> {
+        let encoded: String =
+            String::from_utf8(self.encode_as_vec(data)?).map_err(|error| InvalidEncodedData {
+                reason: Some(error.into()),
+            })?;
+        target.push_str(&encoded);
+        Ok(encoded.len())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::LineWrapEncoder;
+    use crate::base_64::Base64Encoder;
+    use crate::hex::HexEncoder;
+    use crate::test::test_string_encoder;
+
+    #[test]
+    fn encode_mime() {
+        let line: String = "A".repeat(76);
+        let test_cases: &[(Vec<u8>, String)] = &[
+            (Vec::default(), String::default()),
+            (vec![0; 3], "AAAA".to_string()),
+            (vec![0; 57], line.clone()),
+            (vec![0; 58], format!("{line}\r\nAA==")),
+            (vec![0; 114], format!("{line}\r\n{line}")),
+            (vec![0; 115], format!("{line}\r\n{line}\r\nAA==")),
+        ];
+        let encoder: LineWrapEncoder<Base64Encoder> =
+            LineWrapEncoder::mime(Base64Encoder::default());
+        test_string_encoder(&encoder, test_cases);
+    }
+
+    #[test]
+    fn encode_hex() {
+        let test_cases: &[(&[u8], &str)] = &[
+            (b"", ""),
+            (b"\x01", "01"),
+            (b"\x01\x23", "012\n3"),
+            (b"\x01\x23\x45", "012\n345"),
+            (b"\x01\x23\x45\x67", "012\n345\n67"),
+        ];
+        let encoder: LineWrapEncoder<HexEncoder> =
+            LineWrapEncoder::new(HexEncoder::UPPER, 3, "\n").unwrap();
+        test_string_encoder(&encoder, test_cases);
+    }
+
+    #[test]
+    fn new() {
+        assert!(LineWrapEncoder::new(HexEncoder::UPPER, 0, "\n").is_none());
+    }
+}
@@ -1,10 +1,18 @@
+pub use decode_to_array::*;
 pub use decoder::*;
+pub use encode_to_array_string::*;
+pub use encoded_display::*;
 pub use encoder::*;
+pub use line_wrap_encoder::*;
 pub use string_encoder::*;
 pub use validator::*;
 
+mod decode_to_array;
 mod decoder;
+mod encode_to_array_string;
+mod encoded_display;
 mod encoder;
+mod line_wrap_encoder;
 mod string_encoder;
 mod validator;
 
@@ -12,7 +20,15 @@ pub(in crate::data) mod util;
 
 #[cfg(feature = "base-64")]
 pub mod base_64;
+#[cfg(feature = "data-url")]
+pub mod data_url;
+#[cfg(feature = "encoded-word")]
+pub mod encoded_word;
 #[cfg(feature = "hex")]
 pub mod hex;
+#[cfg(feature = "pem")]
+pub mod pem;
 #[cfg(feature = "percent")]
 pub mod percent;
+#[cfg(feature = "quoted-printable")]
+pub mod quoted_printable;
@@ -0,0 +1,7 @@
+pub use pem_block::*;
+pub use pem_decoder::*;
+pub use pem_encoder::*;
+
+mod pem_block;
+mod pem_decoder;
+mod pem_encoder;
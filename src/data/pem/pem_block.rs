@@ -0,0 +1,115 @@
+/// A PEM block with a label, optional RFC 1421 headers and the decoded data.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct PemBlock {
+    label: String,
+    headers: Vec<(String, String)>,
+    data: Vec<u8>,
+}
+
+impl PemBlock {
+    //! Construction
+
+    /// Creates a new PEM block without headers.
+    pub fn new<S>(label: S, data: Vec<u8>) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            label: label.into(),
+            headers: Vec::default(),
+            data,
+        }
+    }
+
+    /// Adds the header with the `name` and `value`.
+    pub fn with_header<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl PemBlock {
+    //! Properties
+
+    /// Gets the label. (ex: `CERTIFICATE`)
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Gets the headers as name-value pairs.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Gets the value of the first header with the `name`. (case-insensitive)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Gets the decoded data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Converts the block into its decoded data.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+impl PemBlock {
+    //! Validation
+
+    /// Checks if the `label` is valid.
+    ///
+    /// Valid labels contain printable ASCII characters with single spaces or hyphens between them.
+    pub fn is_valid_label(label: &str) -> bool {
+        let label: &[u8] = label.as_bytes();
+        let is_label_char = |c: u8| c.is_ascii_graphic() && c != b'-';
+        label.iter().enumerate().all(|(i, c)| {
+            if *c == b' ' || *c == b'-' {
+                i > 0 && i + 1 < label.len() && is_label_char(label[i - 1])
+            } else {
+                is_label_char(*c)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pem::PemBlock;
+
+    #[test]
+    fn is_valid_label() {
+        let test_cases: &[(&str, bool)] = &[
+            ("", true),
+            ("CERTIFICATE", true),
+            ("RSA PRIVATE KEY", true),
+            ("X509 CRL", true),
+            ("SSH2-ENCRYPTED", true),
+            (" KEY", false),
+            ("KEY ", false),
+            ("A  B", false),
+            ("A--B", false),
+            ("A- B", false),
+            ("A\tB", false),
+            ("-KEY", false),
+        ];
+
+        for (label, expected) in test_cases {
+            assert_eq!(
+                PemBlock::is_valid_label(label),
+                *expected,
+                "label={label:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::Error;
+use std::borrow::Cow;
+
+/// A decoded RFC 8187 extended parameter value. (`charset'language'value`)
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExtValue<'a> {
+    charset: &'a str,
+    language: &'a str,
+    value: Cow<'a, [u8]>,
+}
+
+impl<'a> ExtValue<'a> {
+    //! Construction
+
+    /// Creates a new extended value.
+    pub(in crate::data::percent) fn new(
+        charset: &'a str,
+        language: &'a str,
+        value: Cow<'a, [u8]>,
+    ) -> Self {
+        Self {
+            charset,
+            language,
+            value,
+        }
+    }
+}
+
+impl<'a> ExtValue<'a> {
+    //! Properties
+
+    /// Gets the charset.
+    pub fn charset(&self) -> &'a str {
+        self.charset
+    }
+
+    /// Gets the language tag. This may be empty.
+    pub fn language(&self) -> &'a str {
+        self.language
+    }
+
+    /// Gets the decoded value bytes in the charset.
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Converts the extended value into the decoded value bytes in the charset.
+    pub fn into_value(self) -> Cow<'a, [u8]> {
+        self.value
+    }
+}
+
+impl ExtValue<'_> {
+    //! Conversion
+
+    /// Converts the decoded value to a string.
+    ///
+    /// The `UTF-8` and `ISO-8859-1` charsets are supported. Returns an error for other charsets
+    /// or invalid UTF-8.
+    pub fn value_str(&self) -> Result<Cow<'_, str>, Error> {
+        if self.charset.eq_ignore_ascii_case("UTF-8") {
+            std::str::from_utf8(&self.value)
+                .map(Cow::Borrowed)
+                .map_err(|e| Error::invalid_at(e.valid_up_to(), "invalid UTF-8"))
+        } else if self.charset.eq_ignore_ascii_case("ISO-8859-1") {
+            if self.value.is_ascii() {
+                Ok(Cow::Borrowed(std::str::from_utf8(&self.value).unwrap()))
+            } else {
+                Ok(Cow::Owned(self.value.iter().map(|c| *c as char).collect()))
+            }
+        } else {
+            Err(Error::InvalidEncodedData {
+                reason: Some(format!("unsupported charset: {}", self.charset).into()),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::percent::ExtValue;
+    use std::borrow::Cow;
+
+    #[test]
+    fn value_str() {
+        let test_cases: &[(&str, &[u8], Option<&str>)] = &[
+            ("UTF-8", b"na\xC3\xAFve", Some("na\u{00EF}ve")),
+            ("utf-8", b"abc", Some("abc")),
+            ("UTF-8", b"\xFF", None),
+            ("ISO-8859-1", b"na\xEFve", Some("na\u{00EF}ve")),
+            ("iso-8859-1", b"abc", Some("abc")),
+            ("KOI8-R", b"abc", None),
+        ];
+
+        for (charset, value, expected) in test_cases {
+            let ext_value: ExtValue = ExtValue::new(charset, "", Cow::Borrowed(value));
+            assert_eq!(ext_value.value_str().ok().as_deref(), *expected);
+        }
+    }
+}
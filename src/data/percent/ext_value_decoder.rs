@@ -0,0 +1,158 @@
+use crate::Error::InsufficientTargetSpace;
+use crate::percent::{ExtValue, PercentDecoder, SpecialSet};
+use crate::{Decoder, Error};
+
+/// Responsible for decoding RFC 8187 extended parameter values for HTTP headers.
+///
+/// The `charset'language'value` is parsed and the value is strictly percent decoded. The `Decoder`
+/// functions produce the raw value bytes in the charset. Use `parse` to get the charset and
+/// language tag.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub struct ExtValueDecoder {}
+
+impl ExtValueDecoder {
+    //! Constants
+
+    /// The value decoder.
+    const VALUE_DECODER: PercentDecoder = PercentDecoder::new()
+        .with_strict(true)
+        .with_encoding_not_needed(Some(SpecialSet::ATTR_CHAR));
+}
+
+impl ExtValueDecoder {
+    //! Decoding
+
+    /// Checks if `c` is valid in a charset name. (mime-charsetc)
+    #[inline(always)]
+    fn is_charset_char(c: u8) -> bool {
+        c.is_ascii_alphanumeric() || b"!#$%&+-^_`{}~".contains(&c)
+    }
+
+    /// Splits the `data` into the charset, the language tag, and the offset of the encoded value.
+    fn split(data: &[u8]) -> Result<(&str, &str, usize), Error> {
+        let charset_len: usize = data
+            .iter()
+            .take_while(|c| Self::is_charset_char(**c))
+            .count();
+        if charset_len == 0 {
+            return Err(Error::invalid_at(0, "missing charset"));
+        }
+        if data.get(charset_len) != Some(&b'\'') {
+            return Err(Error::invalid_at(charset_len, "expected `'` after charset"));
+        }
+
+        let language_start: usize = charset_len + 1;
+        let language_len: usize = data[language_start..]
+            .iter()
+            .take_while(|c| c.is_ascii_alphanumeric() || **c == b'-')
+            .count();
+        let language_end: usize = language_start + language_len;
+        if data.get(language_end) != Some(&b'\'') {
+            return Err(Error::invalid_at(
+                language_end,
+                "expected `'` after language",
+            ));
+        }
+
+        let charset: &str = std::str::from_utf8(&data[..charset_len]).unwrap();
+        let language: &str = std::str::from_utf8(&data[language_start..language_end]).unwrap();
+        Ok((charset, language, language_end + 1))
+    }
+
+    /// Parses the extended value `data`.
+    pub fn parse<'a>(&self, data: &'a [u8]) -> Result<ExtValue<'a>, Error> {
+        let (charset, language, value_offset) = Self::split(data)?;
+        let value = Self::VALUE_DECODER
+            .decode_cow(&data[value_offset..])
+            .map_err(|e| e.shift_offset(value_offset))?;
+        Ok(ExtValue::new(charset, language, value))
+    }
+}
+
+impl Decoder for ExtValueDecoder {
+    fn decoded_len(&self, data: &[u8]) -> Result<usize, Error> {
+        let (_, _, value_offset) = Self::split(data)?;
+        Self::VALUE_DECODER
+            .decoded_len(&data[value_offset..])
+            .map_err(|e| e.shift_offset(value_offset))
+    }
+
+    fn decode_to_slice(&self, data: &[u8], target: &mut [u8]) -> Result<usize, Error> {
+        let decoded_len: usize = self.decoded_len(data)?;
+        if decoded_len > target.len() {
+            Err(InsufficientTargetSpace)
+        } else {
+            let (_, _, value_offset) = Self::split(data)?;
+            Self::VALUE_DECODER.decode_to_slice(&data[value_offset..], target)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::percent::{ExtValue, ExtValueDecoder, ExtValueEncoder};
+    use crate::test::test_decoder;
+    use crate::{Decoder, StringEncoder};
+
+    #[test]
+    fn decode() {
+        let test_cases: &[(&str, &[u8])] = &[
+            ("UTF-8''", b""),
+            ("UTF-8''na%C3%AFve.txt", "na\u{00EF}ve.txt".as_bytes()),
+            ("utf-8'en'%E2%82%AC%20rates", "\u{20AC} rates".as_bytes()),
+            ("iso-8859-1'en'%A3%20rates", b"\xA3 rates"),
+            ("UTF-8''!#$&+-.^_`|~", b"!#$&+-.^_`|~"),
+        ];
+        let decoder: ExtValueDecoder = ExtValueDecoder::default();
+        test_decoder(&decoder, test_cases);
+    }
+
+    #[test]
+    fn decode_invalid() {
+        let test_cases: &[(&str, usize)] = &[
+            ("", 0),
+            ("'en'abc", 0),
+            ("UTF-8", 5),
+            ("UTF-8'en", 8),
+            ("UTF-8'e n'abc", 7),
+            ("UTF-8''a b", 8),
+            ("UTF-8''a%2", 8),
+            ("UTF-8'en'a%zz", 10),
+            ("UTF-8''a'b", 8),
+        ];
+        let decoder: ExtValueDecoder = ExtValueDecoder::default();
+
+        for (data, offset) in test_cases {
+            let error = decoder.decode_as_vec(data.as_bytes()).unwrap_err();
+            assert_eq!(error.offset(), Some(*offset), "data={data:?}");
+            let error = decoder.parse(data.as_bytes()).unwrap_err();
+            assert_eq!(error.offset(), Some(*offset), "data={data:?}");
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let decoder: ExtValueDecoder = ExtValueDecoder::default();
+        let ext_value: ExtValue = decoder.parse(b"UTF-8'en-US'na%C3%AFve.txt").unwrap();
+        assert_eq!(ext_value.charset(), "UTF-8");
+        assert_eq!(ext_value.language(), "en-US");
+        assert_eq!(ext_value.value(), "na\u{00EF}ve.txt".as_bytes());
+        assert_eq!(ext_value.value_str().unwrap(), "na\u{00EF}ve.txt");
+    }
+
+    #[test]
+    fn encode_parse() {
+        let text: &str = "r\u{00E9}sum\u{00E9} 2024 (final) \u{1F600}.pdf";
+        let encoded: String = ExtValueEncoder::new("fr")
+            .unwrap()
+            .encode_as_string(text.as_bytes())
+            .unwrap();
+        let ext_value: ExtValue = ExtValueDecoder::default()
+            .parse(encoded.as_bytes())
+            .unwrap();
+        assert_eq!(ext_value.language(), "fr");
+        assert_eq!(ext_value.value_str().unwrap(), text);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::Error::{InsufficientTargetSpace, IntegerOverflow};
+use crate::percent::PercentEncoder;
+use crate::{Encoder, Error, StringEncoder};
+
+/// Responsible for encoding UTF-8 text as RFC 8187 extended parameter values for HTTP headers.
+///
+/// The encoded value is `UTF-8'language'value` with all chars except the attr-chars percent
+/// encoded.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ExtValueEncoder<'a> {
+    language: &'a str,
+}
+
+impl ExtValueEncoder<'_> {
+    //! Constants
+
+    /// The charset prefix.
+    const CHARSET: &'static str = "UTF-8";
+}
+
+impl<'a> ExtValueEncoder<'a> {
+    //! Construction
+
+    /// Creates a new extended value encoder with the `language` tag. The tag may be empty.
+    ///
+    /// Returns `None` if the `language` contains chars other than ASCII alphanumerics and `-`.
+    pub fn new(language: &'a str) -> Option<Self> {
+        if language
+            .bytes()
+            .all(|c| c.is_ascii_alphanumeric() || c == b'-')
+        {
+            Some(Self { language })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> ExtValueEncoder<'a> {
+    //! Properties
+
+    /// Gets the language tag.
+    pub fn language(&self) -> &'a str {
+        self.language
+    }
+}
+
+impl ExtValueEncoder<'_> {
+    //! Parameters
+
+    /// Creates the `filename` and `filename*` parameters for a `Content-Disposition` header.
+    /// (RFC 6266)
+    ///
+    /// The `filename` parameter is an ASCII fallback for recipients that do not support extended
+    /// values. Chars other than printable ASCII, and the `"` `\` `%` chars, are replaced with `_`.
+    pub fn filename_params(filename: &str) -> Result<String, Error> {
+        let mut params: String = String::from("filename=\"");
+        params.extend(filename.chars().map(|c| match c {
+            '"' | '\\' | '%' => '_',
+            ' '..='~' => c,
+            _ => '_',
+        }));
+        params.push_str("\"; filename*=");
+        Self::default().append_to_string(filename.as_bytes(), &mut params)?;
+        Ok(params)
+    }
+}
+
+impl Encoder for ExtValueEncoder<'_> {
+    fn encoded_len(&self, data: &[u8]) -> Result<usize, Error> {
+        let prefix_len: usize = Self::CHARSET.len() + self.language.len() + 2;
+        PercentEncoder::ATTR_CHAR
+            .encoded_len(data)?
+            .checked_add(prefix_len)
+            .ok_or(IntegerOverflow)
+    }
+
+    fn encode_to_slice(&self, data: &[u8], target: &mut [u8]) -> Result<usize, Error> {
+        let encoded_len: usize = self.encoded_len(data)?;
+        if encoded_len > target.len() {
+            Err(InsufficientTargetSpace)
+        } else {
+            let mut t: usize = 0;
+            for part in [Self::CHARSET, "'", self.language, "'"] {
+                target[t..(t + part.len())].copy_from_slice(part.as_bytes());
+                t += part.len();
+            }
+            t += PercentEncoder::ATTR_CHAR.encode_to_slice(data, &mut target[t..])?;
+            debug_assert_eq!(encoded_len, t);
+            Ok(t)
+        }
+    }
+
+    fn encode_in_chunks(
+        &self,
+        data: &[u8],
+        buffer: &mut [u8],
+        f: &mut dyn FnMut(&[u8]) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        for part in [Self::CHARSET, "'", self.language, "'"] {
+            f(part.as_bytes())?;
+        }
+        PercentEncoder::ATTR_CHAR.encode_in_chunks(data, buffer, f)
+    }
+}
+
+impl StringEncoder for ExtValueEncoder<'_> {
+    fn append_to_string(&self, data: &[u8], target: &mut String) -> Result<usize, Error> {
+        target.push_str(Self::CHARSET);
+        target.push('\'');
+        target.push_str(self.language);
+        target.push('\'');
+        let encoded_len: usize = PercentEncoder::ATTR_CHAR.append_to_string(data, target)?;
+        Ok(Self::CHARSET.len() + self.language.len() + 2 + encoded_len)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::percent::ExtValueEncoder;
+    use crate::test::test_string_encoder;
+
+    #[test]
+    fn new() {
+        assert!(ExtValueEncoder::new("").is_some());
+        assert!(ExtValueEncoder::new("en-US").is_some());
+        assert!(ExtValueEncoder::new("en'").is_none());
+        assert!(ExtValueEncoder::new("en US").is_none());
+    }
+
+    #[test]
+    fn encode() {
+        let test_cases: &[(&str, &str)] = &[
+            ("", "UTF-8''"),
+            ("na\u{00EF}ve.txt", "UTF-8''na%C3%AFve.txt"),
+            ("a b'c%", "UTF-8''a%20b%27c%25"),
+            ("!#$&+-.^_`|~", "UTF-8''!#$&+-.^_`|~"),
+            ("\u{20AC} rates", "UTF-8''%E2%82%AC%20rates"),
+        ];
+        let encoder: ExtValueEncoder = ExtValueEncoder::default();
+        test_string_encoder(&encoder, test_cases);
+    }
+
+    #[test]
+    fn encode_language() {
+        let test_cases: &[(&str, &str)] =
+            &[("", "UTF-8'en'"), ("na\u{00EF}ve", "UTF-8'en'na%C3%AFve")];
+        let encoder: ExtValueEncoder = ExtValueEncoder::new("en").unwrap();
+        test_string_encoder(&encoder, test_cases);
+    }
+
+    #[test]
+    fn filename_params() {
+        let test_cases: &[(&str, &str)] = &[
+            ("a.txt", "filename=\"a.txt\"; filename*=UTF-8''a.txt"),
+            (
+                "na\u{00EF}ve file.txt",
+                "filename=\"na_ve file.txt\"; filename*=UTF-8''na%C3%AFve%20file.txt",
+            ),
+            (
+                "a\"b\\c%d.txt",
+                "filename=\"a_b_c_d.txt\"; filename*=UTF-8''a%22b%5Cc%25d.txt",
+            ),
+        ];
+
+        for (filename, expected) in test_cases {
+            assert_eq!(
+                ExtValueEncoder::filename_params(filename).unwrap(),
+                *expected
+            );
+        }
+    }
+}
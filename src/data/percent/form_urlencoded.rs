@@ -0,0 +1,166 @@
+use crate::percent::{PercentDecoder, PercentEncoder, Utf8Policy};
+use crate::{Error, StringEncoder};
+use std::borrow::Cow;
+
+/// Serializes the name-value `pairs` in the `application/x-www-form-urlencoded` format.
+/// (WHATWG URL)
+///
+/// Returns the serialized string.
+pub fn serialize_form<K, V>(pairs: &[(K, V)]) -> Result<String, Error>
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let mut target: String = String::default();
+    append_form_to_string(pairs, &mut target)?;
+    Ok(target)
+}
+
+/// Appends the name-value `pairs` in the `application/x-www-form-urlencoded` format to the
+/// `target` string. (WHATWG URL)
+///
+/// Returns the length of the serialized `pairs`.
+pub fn append_form_to_string<K, V>(pairs: &[(K, V)], target: &mut String) -> Result<usize, Error>
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let encoder: PercentEncoder = PercentEncoder::FORM;
+    let start: usize = target.len();
+    for (i, (name, value)) in pairs.iter().enumerate() {
+        if i > 0 {
+            target.push('&');
+        }
+        encoder.append_to_string(name.as_ref().as_bytes(), target)?;
+        target.push('=');
+        encoder.append_to_string(value.as_ref().as_bytes(), target)?;
+    }
+    Ok(target.len() - start)
+}
+
+/// Parses the `application/x-www-form-urlencoded` `data` into its decoded name-value pairs.
+/// (WHATWG URL)
+///
+/// Empty sequences between `&` chars are skipped. A sequence without an `=` char has an empty
+/// value. Invalid UTF-8 is replaced with the replacement char.
+pub fn parse_form(data: &[u8]) -> FormPairs<'_> {
+    FormPairs { data }
+}
+
+/// An iterator over the decoded name-value pairs of `application/x-www-form-urlencoded` data.
+///
+/// The names and values are only allocated when they need decoding.
+#[derive(Copy, Clone, Debug)]
+pub struct FormPairs<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> FormPairs<'a> {
+    //! Decoding
+
+    /// Decodes the name or value `data`.
+    fn decode(data: &'a [u8]) -> Cow<'a, str> {
+        PercentDecoder::form_decoder()
+            .decode_cow_str(data, Utf8Policy::Lossy)
+            .expect("lossy percent decoding cannot fail")
+    }
+}
+
+impl<'a> Iterator for FormPairs<'a> {
+    type Item = (Cow<'a, str>, Cow<'a, str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.data.is_empty() {
+            let sequence: &[u8] = match self.data.iter().position(|c| *c == b'&') {
+                Some(amp) => {
+                    let sequence: &[u8] = &self.data[..amp];
+                    self.data = &self.data[(amp + 1)..];
+                    sequence
+                }
+                None => {
+                    let sequence: &[u8] = self.data;
+                    self.data = &[];
+                    sequence
+                }
+            };
+            if !sequence.is_empty() {
+                let (name, value) = match sequence.iter().position(|c| *c == b'=') {
+                    Some(eq) => (&sequence[..eq], &sequence[(eq + 1)..]),
+                    None => (sequence, &[] as &[u8]),
+                };
+                return Some((Self::decode(name), Self::decode(value)));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::percent::{parse_form, serialize_form};
+    use std::borrow::Cow;
+
+    type Pairs = &'static [(&'static str, &'static str)];
+
+    #[test]
+    fn serialize() {
+        let test_cases: &[(Pairs, &str)] = &[
+            (&[], ""),
+            (&[("a", "1")], "a=1"),
+            (&[("a", "1"), ("b", "x y")], "a=1&b=x+y"),
+            (&[("", "")], "="),
+            (&[("a&b", "c=d+e")], "a%26b=c%3Dd%2Be"),
+            (&[("caf\u{00E9}", "~*")], "caf%C3%A9=%7E*"),
+        ];
+
+        for (pairs, expected) in test_cases {
+            assert_eq!(serialize_form(pairs).unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let test_cases: &[(&[u8], Pairs)] = &[
+            (b"", &[]),
+            (b"a=1&b=x+y", &[("a", "1"), ("b", "x y")]),
+            (b"&&a=1&&", &[("a", "1")]),
+            (b"a", &[("a", "")]),
+            (b"a=", &[("a", "")]),
+            (b"=b", &[("", "b")]),
+            (b"a=b=c", &[("a", "b=c")]),
+            (b"a%26b=c%3Dd%2Be", &[("a&b", "c=d+e")]),
+            (b"a=%zz%", &[("a", "%zz%")]),
+            (b"a=%C3%A9", &[("a", "\u{00E9}")]),
+            (b"a=%FF", &[("a", "\u{FFFD}")]),
+            (b"a=\xFF", &[("a", "\u{FFFD}")]),
+        ];
+
+        for (data, expected) in test_cases {
+            let pairs: Vec<(Cow<str>, Cow<str>)> = parse_form(data).collect();
+            let pairs: Vec<(&str, &str)> = pairs
+                .iter()
+                .map(|(name, value)| (name.as_ref(), value.as_ref()))
+                .collect();
+            assert_eq!(pairs, *expected);
+        }
+    }
+
+    #[test]
+    fn parse_borrowed() {
+        let pairs: Vec<(Cow<str>, Cow<str>)> = parse_form(b"a=1&b=x+y").collect();
+        assert!(matches!(pairs[0], (Cow::Borrowed("a"), Cow::Borrowed("1"))));
+        assert!(matches!(pairs[1].1, Cow::Owned(_)));
+    }
+
+    #[test]
+    fn serialize_parse() {
+        let pairs: &[(&str, &str)] = &[("name", "J\u{00F6}rg & Co"), ("q", "1+1=2"), ("", "%")];
+        let serialized: String = serialize_form(pairs).unwrap();
+        let parsed: Vec<(Cow<str>, Cow<str>)> = parse_form(serialized.as_bytes()).collect();
+        for ((name, value), (parsed_name, parsed_value)) in pairs.iter().zip(&parsed) {
+            assert_eq!(name, parsed_name);
+            assert_eq!(value, parsed_value);
+        }
+        assert_eq!(pairs.len(), parsed.len());
+    }
+}
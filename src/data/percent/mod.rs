@@ -1,9 +1,19 @@
+pub use ext_value::*;
+pub use ext_value_decoder::*;
+pub use ext_value_encoder::*;
+pub use form_urlencoded::*;
 pub use percent_decoder::*;
 pub use percent_encoder::*;
 pub use percent_validator::*;
 pub use special_set::*;
+pub use utf8_policy::*;
 
+mod ext_value;
+mod ext_value_decoder;
+mod ext_value_encoder;
+mod form_urlencoded;
 mod percent_decoder;
 mod percent_encoder;
 mod percent_validator;
 mod special_set;
+mod utf8_policy;
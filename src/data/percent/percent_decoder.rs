@@ -1,6 +1,8 @@
 use crate::Error::InsufficientTargetSpace;
 use crate::hex::{HexDecoder, HexValidator};
+use crate::percent::{SpecialSet, Utf8Policy};
 use crate::{Decoder, Error};
+use std::borrow::Cow;
 
 /// Responsible for decoding data in the URL percent encoded format.
 ///
@@ -8,11 +10,88 @@ use crate::{Decoder, Error};
 /// This decoder is case-insensitive. All encoded `%XX %xx %xX %Xx` sequences will be decoded.
 ///
 /// # Validation
-/// No validation is done on the encoded data. All properly encoded chars will be decoded, and
-/// improperly encoded chars will pass through the decoder unchanged.
+/// By default, no validation is done on the encoded data. All properly encoded chars will be
+/// decoded, and improperly encoded chars will pass through the decoder unchanged.
+///
+/// A decoder created `with_strict` fails on any `%` char not followed by two hex digits. A decoder
+/// created `with_encoding_not_needed` also fails on any byte that is not alphanumeric or in the
+/// set, as the `PercentValidator` would. The errors have the offset of the invalid byte.
+///
+/// # Plus as Space
+/// A decoder created `with_plus_as_space` decodes the `+` char as the `SPACE` char, as in the
+/// `application/x-www-form-urlencoded` format.
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
 #[non_exhaustive]
-pub struct PercentDecoder {}
+pub struct PercentDecoder {
+    plus_as_space: bool,
+    strict: bool,
+    encoding_not_needed: Option<SpecialSet>,
+}
+
+impl PercentDecoder {
+    //! Construction
+
+    /// Creates a new percent decoder with the default configuration.
+    pub const fn new() -> Self {
+        Self {
+            plus_as_space: false,
+            strict: false,
+            encoding_not_needed: None,
+        }
+    }
+}
+
+impl PercentDecoder {
+    //! Configuration
+
+    /// Sets whether the `+` char is decoded as the `SPACE` char.
+    pub const fn with_plus_as_space(mut self, plus_as_space: bool) -> Self {
+        self.plus_as_space = plus_as_space;
+        self
+    }
+
+    /// Checks if the `+` char is decoded as the `SPACE` char.
+    pub const fn plus_as_space(self) -> bool {
+        self.plus_as_space
+    }
+
+    /// Sets whether a `%` char not followed by two hex digits is an error.
+    pub const fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Checks if a `%` char not followed by two hex digits is an error.
+    pub const fn is_strict(self) -> bool {
+        self.strict
+    }
+
+    /// Sets the special chars that do not need encoding.
+    ///
+    /// If set, any byte that is not alphanumeric, in the set, or `%` is an error. The `+` char is
+    /// also allowed when decoding `with_plus_as_space`.
+    pub const fn with_encoding_not_needed(
+        mut self,
+        encoding_not_needed: Option<SpecialSet>,
+    ) -> Self {
+        self.encoding_not_needed = encoding_not_needed;
+        self
+    }
+
+    /// Gets the special chars that do not need encoding.
+    pub const fn encoding_not_needed(self) -> Option<SpecialSet> {
+        self.encoding_not_needed
+    }
+}
+
+impl PercentDecoder {
+    //! Special Decoders
+
+    /// Gets the `application/x-www-form-urlencoded` decoder. (WHATWG URL)
+    pub const fn form_decoder() -> Self {
+        Self::new().with_plus_as_space(true)
+    }
+}
 
 impl PercentDecoder {
     //! Decoding
@@ -30,16 +109,103 @@ impl PercentDecoder {
             && HexValidator::CASELESS.is_valid_byte(data[1])
             && HexValidator::CASELESS.is_valid_byte(data[2])
     }
+
+    /// Checks if the unencoded byte `c` is allowed.
+    #[inline(always)]
+    fn is_allowed(&self, c: u8) -> bool {
+        match self.encoding_not_needed {
+            None => true,
+            Some(encoding_not_needed) => {
+                c.is_ascii_alphanumeric()
+                    || encoding_not_needed.contains(c)
+                    || (c == b'+' && self.plus_as_space)
+            }
+        }
+    }
+
+    /// Gets the offset in the encoded `data` of the decoded byte at the `decoded_offset`.
+    ///
+    /// # Note
+    /// The `decoded_offset` must not exceed the decoded length of the `data`.
+    pub fn encoded_offset(data: &[u8], decoded_offset: usize) -> usize {
+        let mut d: usize = 0;
+        for _ in 0..decoded_offset {
+            if data[d] == b'%' && Self::prefix_is_encoded(&data[d..]) {
+                d += 3;
+            } else {
+                d += 1;
+            }
+        }
+        d
+    }
+
+    /// Decodes the `data` as raw bytes.
+    ///
+    /// Returns the borrowed `data` if nothing needs decoding.
+    pub fn decode_cow<'a>(&self, data: &'a [u8]) -> Result<Cow<'a, [u8]>, Error> {
+        let decoded_len: usize = self.decoded_len(data)?;
+        if decoded_len != data.len() || (self.plus_as_space && data.contains(&b'+')) {
+            let mut decoded: Vec<u8> = vec![0u8; decoded_len];
+            self.decode_to_slice(data, &mut decoded)?;
+            Ok(Cow::Owned(decoded))
+        } else {
+            Ok(Cow::Borrowed(data))
+        }
+    }
+
+    /// Decodes the `data` as a UTF-8 string with the invalid UTF-8 `policy`.
+    ///
+    /// Returns the borrowed `data` if nothing needs decoding and the `data` is valid UTF-8.
+    pub fn decode_cow_str<'a>(
+        &self,
+        data: &'a [u8],
+        policy: Utf8Policy,
+    ) -> Result<Cow<'a, str>, Error> {
+        let invalid_at = |decoded_offset: usize| -> Error {
+            Error::invalid_at(Self::encoded_offset(data, decoded_offset), "invalid UTF-8")
+        };
+        match (self.decode_cow(data)?, policy) {
+            (Cow::Borrowed(decoded), Utf8Policy::Strict) => std::str::from_utf8(decoded)
+                .map(Cow::Borrowed)
+                .map_err(|e| invalid_at(e.valid_up_to())),
+            (Cow::Borrowed(decoded), Utf8Policy::Lossy) => Ok(String::from_utf8_lossy(decoded)),
+            (Cow::Owned(decoded), Utf8Policy::Strict) => String::from_utf8(decoded)
+                .map(Cow::Owned)
+                .map_err(|e| invalid_at(e.utf8_error().valid_up_to())),
+            (Cow::Owned(decoded), Utf8Policy::Lossy) => match String::from_utf8(decoded) {
+                Ok(decoded) => Ok(Cow::Owned(decoded)),
+                Err(e) => Ok(Cow::Owned(
+                    String::from_utf8_lossy(e.as_bytes()).into_owned(),
+                )),
+            },
+        }
+    }
+
+    /// Decodes the `data` as a UTF-8 string with the invalid UTF-8 `policy`.
+    ///
+    /// Returns the string.
+    pub fn decode_as_string(&self, data: &[u8], policy: Utf8Policy) -> Result<String, Error> {
+        self.decode_cow_str(data, policy).map(Cow::into_owned)
+    }
 }
 
 impl Decoder for PercentDecoder {
     fn decoded_len(&self, data: &[u8]) -> Result<usize, Error> {
-        let encoded: usize = data
-            .iter()
-            .enumerate()
-            .filter(|(_, c)| **c == b'%')
-            .filter(|(i, _)| Self::prefix_is_encoded(&data[*i..]))
-            .count();
+        let mut encoded: usize = 0;
+        for (i, c) in data.iter().enumerate() {
+            if *c == b'%' {
+                if Self::prefix_is_encoded(&data[i..]) {
+                    encoded += 1;
+                } else if self.strict {
+                    return Err(Error::invalid_at(i, "`%` not followed by two hex digits"));
+                }
+            } else if !self.is_allowed(*c) {
+                return Err(Error::invalid_at(
+                    i,
+                    format!("byte `0x{c:02X}` must be percent-encoded"),
+                ));
+            }
+        }
         Ok(data.len() - (encoded * 2))
     }
 
@@ -56,6 +222,10 @@ impl Decoder for PercentDecoder {
                     target[t] = HexDecoder::decode_bytes(data[d + 1], data[d + 2]);
                     t += 1;
                     d += 3;
+                } else if c == b'+' && self.plus_as_space {
+                    target[t] = b' ';
+                    t += 1;
+                    d += 1;
                 } else {
                     target[t] = c;
                     t += 1;
@@ -72,8 +242,12 @@ impl Decoder for PercentDecoder {
 #[cfg(feature = "dev")]
 mod tests {
     use crate::Decoder;
-    use crate::percent::PercentDecoder;
+    use crate::percent::{PercentDecoder, SpecialSet, Utf8Policy};
     use crate::test::test_decoder;
+    use std::borrow::Cow;
+
+    /// The expected decoded data or the offset of the invalid byte.
+    type Expected = Result<&'static [u8], usize>;
 
     #[test]
     fn decode() {
@@ -94,6 +268,129 @@ mod tests {
         test_decoder(&decoder, test_cases);
     }
 
+    #[test]
+    fn decode_plus_as_space() {
+        let test_cases: &[(&str, &str)] = &[
+            ("", ""),
+            ("a+b", "a b"),
+            ("a%2Bb", "a+b"),
+            ("++", "  "),
+            ("a b", "a b"),
+        ];
+        let decoder: PercentDecoder = PercentDecoder::form_decoder();
+        assert!(decoder.plus_as_space());
+        test_decoder(&decoder, test_cases);
+    }
+
+    #[test]
+    fn decode_cow() {
+        let test_cases: &[(&[u8], &[u8], bool)] = &[
+            (b"", b"", true),
+            (b"abc", b"abc", true),
+            (b"a+b", b"a+b", true),
+            (b"%GG", b"%GG", true),
+            (b"a%20b", b"a b", false),
+            (b"%FF", b"\xFF", false),
+        ];
+        let decoder: PercentDecoder = PercentDecoder::default();
+
+        for (data, expected, borrowed) in test_cases {
+            let decoded: Cow<[u8]> = decoder.decode_cow(data).unwrap();
+            assert_eq!(decoded.as_ref(), *expected);
+            assert_eq!(matches!(decoded, Cow::Borrowed(_)), *borrowed);
+        }
+
+        let decoded: Cow<[u8]> = PercentDecoder::form_decoder().decode_cow(b"a+b").unwrap();
+        assert!(matches!(decoded, Cow::Owned(_)));
+    }
+
+    #[test]
+    fn decode_cow_str() {
+        let test_cases: &[(&[u8], &str, Option<usize>, bool)] = &[
+            (b"", "", None, true),
+            (b"abc", "abc", None, true),
+            (b"caf%C3%A9", "caf\u{00E9}", None, false),
+            (b"caf\xC3\xA9", "caf\u{00E9}", None, true),
+            (b"a\xFFb", "a\u{FFFD}b", Some(1), false),
+            (b"%41%FFb", "A\u{FFFD}b", Some(3), false),
+            (b"%41%20%C3", "A \u{FFFD}", Some(6), false),
+            (b"%41\xC3%41", "A\u{FFFD}A", Some(3), false),
+        ];
+        let decoder: PercentDecoder = PercentDecoder::default();
+
+        for (data, expected, invalid_offset, borrowed) in test_cases {
+            match decoder.decode_cow_str(data, Utf8Policy::Strict) {
+                Ok(decoded) => {
+                    assert_eq!(*invalid_offset, None);
+                    assert_eq!(decoded, *expected);
+                    assert_eq!(matches!(decoded, Cow::Borrowed(_)), *borrowed);
+                }
+                Err(error) => assert_eq!(error.offset(), *invalid_offset),
+            }
+
+            let decoded: Cow<str> = decoder.decode_cow_str(data, Utf8Policy::Lossy).unwrap();
+            assert_eq!(decoded, *expected);
+            let decoded: String = decoder.decode_as_string(data, Utf8Policy::Lossy).unwrap();
+            assert_eq!(decoded, *expected);
+        }
+    }
+
+    #[test]
+    fn decode_strict() {
+        let test_cases: &[(&[u8], Expected)] = &[
+            (b"", Ok(b"")),
+            (b"a%20b", Ok(b"a b")),
+            (b"a b+", Ok(b"a b+")),
+            (b"%", Err(0)),
+            (b"a%2", Err(1)),
+            (b"a%zz", Err(1)),
+            (b"%41%", Err(3)),
+            (b"%%41", Err(0)),
+        ];
+        let decoder: PercentDecoder = PercentDecoder::default().with_strict(true);
+        assert!(decoder.is_strict());
+
+        for (data, expected) in test_cases {
+            match (decoder.decode_as_vec(data), expected) {
+                (Ok(decoded), Ok(expected)) => assert_eq!(decoded, *expected),
+                (Err(error), Err(offset)) => assert_eq!(error.offset(), Some(*offset)),
+                (result, _) => panic!("unexpected result: {result:?}"),
+            }
+            match (decoder.decode_cow(data), expected) {
+                (Ok(decoded), Ok(expected)) => assert_eq!(decoded.as_ref(), *expected),
+                (Err(error), Err(offset)) => assert_eq!(error.offset(), Some(*offset)),
+                (result, _) => panic!("unexpected result: {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn decode_encoding_not_needed() {
+        let test_cases: &[(&[u8], Expected)] = &[
+            (b"", Ok(b"")),
+            (b"azAZ09-.", Ok(b"azAZ09-.")),
+            (b"a%20b+c", Ok(b"a b c")),
+            (b"%zz", Ok(b"%zz")),
+            (b"a b", Err(1)),
+            (b"a/b", Err(1)),
+            (b"%41\xFF", Err(3)),
+        ];
+        let decoder: PercentDecoder = PercentDecoder::form_decoder()
+            .with_encoding_not_needed(Some(SpecialSet::from_chars("-.")));
+        assert_eq!(
+            decoder.encoding_not_needed(),
+            Some(SpecialSet::from_chars("-."))
+        );
+
+        for (data, expected) in test_cases {
+            match (decoder.decode_as_vec(data), expected) {
+                (Ok(decoded), Ok(expected)) => assert_eq!(decoded, *expected),
+                (Err(error), Err(offset)) => assert_eq!(error.offset(), Some(*offset)),
+                (result, _) => panic!("unexpected result: {result:?}"),
+            }
+        }
+    }
+
     #[test]
     fn decode_insufficient_space() {
         let decoder: PercentDecoder = PercentDecoder::default();
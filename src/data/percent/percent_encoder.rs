@@ -4,10 +4,51 @@ use crate::percent::SpecialSet;
 use crate::{Encoder, Error, StringEncoder, data};
 
 /// Responsible for encoding data in the URL percent encoded format.
+///
+/// # Space as Plus
+/// An encoder created `with_space_as_plus` encodes the `SPACE` char as `+`, and always encodes the
+/// `+` char, as in the `application/x-www-form-urlencoded` format.
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
 pub struct PercentEncoder {
     hex_encoder: HexEncoder,
     do_not_encode: SpecialSet,
+    space_as_plus: bool,
+}
+
+impl PercentEncoder {
+    //! Constants
+    //!
+    //! These encoders use the percent-encode sets of the WHATWG URL standard.
+
+    /// The C0 control percent-encode set encoder.
+    pub const C0_CONTROL: Self = Self::new(HexEncoder::UPPER, SpecialSet::C0_CONTROL);
+
+    /// The fragment percent-encode set encoder.
+    pub const FRAGMENT: Self = Self::new(HexEncoder::UPPER, SpecialSet::FRAGMENT);
+
+    /// The query percent-encode set encoder.
+    pub const QUERY: Self = Self::new(HexEncoder::UPPER, SpecialSet::QUERY);
+
+    /// The special-query percent-encode set encoder.
+    pub const SPECIAL_QUERY: Self = Self::new(HexEncoder::UPPER, SpecialSet::SPECIAL_QUERY);
+
+    /// The path percent-encode set encoder.
+    pub const PATH: Self = Self::new(HexEncoder::UPPER, SpecialSet::PATH);
+
+    /// The userinfo percent-encode set encoder.
+    pub const USERINFO: Self = Self::new(HexEncoder::UPPER, SpecialSet::USERINFO);
+
+    /// The component percent-encode set encoder.
+    pub const COMPONENT: Self = Self::new(HexEncoder::UPPER, SpecialSet::COMPONENT);
+
+    /// The `application/x-www-form-urlencoded` encoder. The `SPACE` char is encoded as `+`.
+    pub const FORM: Self = Self::new(HexEncoder::UPPER, SpecialSet::FORM).with_space_as_plus(true);
+
+    /// The RFC 3986 encoder. All chars except the unreserved chars are encoded.
+    pub const UNRESERVED: Self = Self::new(HexEncoder::UPPER, SpecialSet::UNRESERVED);
+
+    /// The RFC 8187 encoder. All chars except the attr-chars are encoded.
+    pub const ATTR_CHAR: Self = Self::new(HexEncoder::UPPER, SpecialSet::ATTR_CHAR);
 }
 
 impl PercentEncoder {
@@ -18,6 +59,7 @@ impl PercentEncoder {
         Self {
             hex_encoder,
             do_not_encode,
+            space_as_plus: false,
         }
     }
 }
@@ -28,12 +70,34 @@ impl<S: Into<SpecialSet>> From<S> for PercentEncoder {
     }
 }
 
+impl PercentEncoder {
+    //! Configuration
+
+    /// Sets whether the `SPACE` char is encoded as `+`.
+    pub const fn with_space_as_plus(mut self, space_as_plus: bool) -> Self {
+        self.space_as_plus = space_as_plus;
+        self
+    }
+
+    /// Checks if the `SPACE` char is encoded as `+`.
+    pub const fn space_as_plus(self) -> bool {
+        self.space_as_plus
+    }
+}
+
 impl PercentEncoder {
     //! Encoding
 
     /// Checks if `c` needs encoding.
     #[inline(always)]
     pub fn needs_encoding(self, c: u8) -> bool {
+        if self.space_as_plus {
+            match c {
+                b' ' => return false,
+                b'+' => return true,
+                _ => {}
+            }
+        }
         !c.is_ascii_alphanumeric() && !self.do_not_encode.contains(c)
     }
 }
@@ -61,6 +125,9 @@ impl Encoder for PercentEncoder {
                     t += 1;
                     target[t] = b;
                     t += 1;
+                } else if *c == b' ' && self.space_as_plus {
+                    target[t] = b'+';
+                    t += 1;
                 } else {
                     target[t] = *c;
                     t += 1;
@@ -70,6 +137,10 @@ impl Encoder for PercentEncoder {
             Ok(t)
         }
     }
+
+    fn block_size(&self) -> Option<usize> {
+        Some(1)
+    }
 }
 
 impl StringEncoder for PercentEncoder {
@@ -112,4 +183,82 @@ mod tests {
         let encoder: PercentEncoder = "+-.".into();
         test_string_encoder(&encoder, test_cases);
     }
+
+    #[test]
+    fn encode_space_as_plus() {
+        let test_cases: &[(&str, &str)] = &[
+            ("", ""),
+            ("a b", "a+b"),
+            ("a+b", "a%2Bb"),
+            ("-.", "-."),
+            ("!", "%21"),
+        ];
+        let encoder: PercentEncoder = PercentEncoder::from("+-. ").with_space_as_plus(true);
+        assert!(encoder.space_as_plus());
+        test_string_encoder(&encoder, test_cases);
+    }
+
+    #[test]
+    fn encode_url_sets() {
+        let data: &str = "a ~!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}\x7F\u{00E9}";
+        let test_cases: &[(PercentEncoder, &str)] = &[
+            (
+                PercentEncoder::C0_CONTROL,
+                "a ~!\"#$%25&'()*+,-./:;<=>?@[\\]^_`{|}%7F%C3%A9",
+            ),
+            (
+                PercentEncoder::FRAGMENT,
+                "a%20~!%22#$%25&'()*+,-./:;%3C=%3E?@[\\]^_%60{|}%7F%C3%A9",
+            ),
+            (
+                PercentEncoder::QUERY,
+                "a%20~!%22%23$%25&'()*+,-./:;%3C=%3E?@[\\]^_`{|}%7F%C3%A9",
+            ),
+            (
+                PercentEncoder::SPECIAL_QUERY,
+                "a%20~!%22%23$%25&%27()*+,-./:;%3C=%3E?@[\\]^_`{|}%7F%C3%A9",
+            ),
+            (
+                PercentEncoder::PATH,
+                "a%20~!%22%23$%25&'()*+,-./:;%3C=%3E%3F@[\\]%5E_%60%7B|%7D%7F%C3%A9",
+            ),
+            (
+                PercentEncoder::USERINFO,
+                "a%20~!%22%23$%25&'()*+,-.%2F%3A%3B%3C%3D%3E%3F%40%5B%5C%5D%5E_%60%7B%7C%7D%7F%C3%A9",
+            ),
+            (
+                PercentEncoder::COMPONENT,
+                "a%20~!%22%23%24%25%26'()*%2B%2C-.%2F%3A%3B%3C%3D%3E%3F%40%5B%5C%5D%5E_%60%7B%7C%7D%7F%C3%A9",
+            ),
+            (
+                PercentEncoder::FORM,
+                "a+%7E%21%22%23%24%25%26%27%28%29*%2B%2C-.%2F%3A%3B%3C%3D%3E%3F%40%5B%5C%5D%5E_%60%7B%7C%7D%7F%C3%A9",
+            ),
+            (
+                PercentEncoder::UNRESERVED,
+                "a%20~%21%22%23%24%25%26%27%28%29%2A%2B%2C-.%2F%3A%3B%3C%3D%3E%3F%40%5B%5C%5D%5E_%60%7B%7C%7D%7F%C3%A9",
+            ),
+            (
+                PercentEncoder::ATTR_CHAR,
+                "a%20~!%22#$%25&%27%28%29%2A+%2C-.%2F%3A%3B%3C%3D%3E%3F%40%5B%5C%5D^_`%7B|%7D%7F%C3%A9",
+            ),
+        ];
+
+        for (encoder, expected) in test_cases {
+            test_string_encoder(encoder, &[(data, *expected)]);
+        }
+    }
+
+    #[test]
+    fn encode_form() {
+        let test_cases: &[(&str, &str)] = &[
+            ("azAZ09*-._", "azAZ09*-._"),
+            ("a b+c", "a+b%2Bc"),
+            ("~!'()", "%7E%21%27%28%29"),
+            ("&=%/?#", "%26%3D%25%2F%3F%23"),
+            ("\u{00E9}", "%C3%A9"),
+        ];
+        let encoder: PercentEncoder = PercentEncoder::FORM;
+        test_string_encoder(&encoder, test_cases);
+    }
 }
@@ -23,11 +23,7 @@ pub struct SpecialSet {
 
 impl From<&str> for SpecialSet {
     fn from(chars: &str) -> Self {
-        let mut set: Self = Self::default();
-        for c in chars.as_bytes() {
-            set.add(*c);
-        }
-        set
+        Self::from_chars(chars)
     }
 }
 
@@ -37,6 +33,12 @@ impl SpecialSet {
     /// The set of valid chars. (in order of both their bit index and their ASCII code)
     const CHARS: &'static [u8; 32] = b" !\"#$&'()*+,-./:;<=>?@[\\]^_`{|}~";
 
+    /// The empty set.
+    pub const EMPTY: Self = Self { bits: 0 };
+
+    /// The set of all 32 valid chars.
+    pub const ALL: Self = Self { bits: u32::MAX };
+
     /// The index table.
     const INDEX_TABLE: [u8; 128] = Self::create_index_table();
 
@@ -81,6 +83,59 @@ impl SpecialSet {
     }
 }
 
+impl SpecialSet {
+    //! Construction
+
+    /// Creates a new set from the `chars`. Invalid chars are ignored.
+    pub const fn from_chars(chars: &str) -> Self {
+        let chars: &[u8] = chars.as_bytes();
+        let mut set: Self = Self::EMPTY;
+        let mut i: usize = 0;
+        while i < chars.len() {
+            set.add(chars[i]);
+            i += 1;
+        }
+        set
+    }
+}
+
+impl SpecialSet {
+    //! URL Sets
+    //!
+    //! These sets contain the chars that are *not* encoded by the percent-encode sets of the
+    //! WHATWG URL standard. The `%` char is always encoded.
+
+    /// The chars not in the C0 control percent-encode set.
+    pub const C0_CONTROL: Self = Self::ALL;
+
+    /// The chars not in the fragment percent-encode set.
+    pub const FRAGMENT: Self = Self::C0_CONTROL.difference(Self::from_chars(" \"<>`"));
+
+    /// The chars not in the query percent-encode set.
+    pub const QUERY: Self = Self::C0_CONTROL.difference(Self::from_chars(" \"#<>"));
+
+    /// The chars not in the special-query percent-encode set.
+    pub const SPECIAL_QUERY: Self = Self::QUERY.difference(Self::from_chars("'"));
+
+    /// The chars not in the path percent-encode set.
+    pub const PATH: Self = Self::QUERY.difference(Self::from_chars("?^`{}"));
+
+    /// The chars not in the userinfo percent-encode set.
+    pub const USERINFO: Self = Self::PATH.difference(Self::from_chars("/:;=@[\\]^|"));
+
+    /// The chars not in the component percent-encode set.
+    pub const COMPONENT: Self = Self::USERINFO.difference(Self::from_chars("$&+,"));
+
+    /// The chars not in the `application/x-www-form-urlencoded` percent-encode set.
+    pub const FORM: Self = Self::COMPONENT.difference(Self::from_chars("!'()~"));
+
+    /// The unreserved chars that are not alphanumeric. (RFC 3986)
+    pub const UNRESERVED: Self = Self::from_chars("-._~");
+
+    /// The attr-chars that are not alphanumeric. (RFC 8187)
+    pub const ATTR_CHAR: Self = Self::from_chars("!#$&+-.^_`|~");
+}
+
 impl SpecialSet {
     //! Index
 
@@ -101,7 +156,7 @@ impl SpecialSet {
     //! Mutations
 
     /// Adds `c`. If `c` is invalid or already present, this has no effect.
-    pub fn add(&mut self, c: u8) {
+    pub const fn add(&mut self, c: u8) {
         let index: u32 = Self::index_of(c) as u32;
         if index < 32 {
             self.bits |= 1u32 << index;
@@ -109,7 +164,7 @@ impl SpecialSet {
     }
 
     /// Removes `c`. If `c` is invalid or not present, this has no effect.
-    pub fn remove(&mut self, c: u8) {
+    pub const fn remove(&mut self, c: u8) {
         let index: u32 = Self::index_of(c) as u32;
         if index < 32 {
             self.bits &= !(1u32 << index);
@@ -123,17 +178,47 @@ impl SpecialSet {
     /// Checks if the set contains `c`.
     ///
     /// If `c` is invalid, this will return false.
-    pub fn contains(self, c: u8) -> bool {
+    pub const fn contains(self, c: u8) -> bool {
         let index: u32 = Self::index_of(c) as u32;
         index < 32 && (self.bits & (1u32 << index)) != 0
     }
 
     /// Gets the number of chars in the set.
-    pub fn size(self) -> usize {
+    pub const fn size(self) -> usize {
         self.bits.count_ones() as usize
     }
 }
 
+impl SpecialSet {
+    //! Set Operations
+
+    /// Gets the set of chars in either `self` or `other`.
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// Gets the set of chars in both `self` and `other`.
+    pub const fn intersection(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    /// Gets the set of chars in `self` but not in `other`.
+    pub const fn difference(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & !other.bits,
+        }
+    }
+
+    /// Gets the set of valid chars not in `self`.
+    pub const fn complement(self) -> Self {
+        Self { bits: !self.bits }
+    }
+}
+
 impl SpecialSet {
     //! Iteration
 
@@ -206,6 +291,49 @@ mod tests {
         assert_eq!(set.size(), 0);
     }
 
+    #[test]
+    fn from_chars() {
+        const SET: SpecialSet = SpecialSet::from_chars("+-.a\x00");
+        assert_eq!(SET.to_string(), "+-.");
+        assert_eq!(SET, SpecialSet::from("+-."));
+        assert_eq!(SpecialSet::from_chars(""), SpecialSet::EMPTY);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: SpecialSet = SpecialSet::from_chars(" !\"");
+        let b: SpecialSet = SpecialSet::from_chars("\"#");
+
+        assert_eq!(a.union(b).to_string(), " !\"#");
+        assert_eq!(a.intersection(b).to_string(), "\"");
+        assert_eq!(a.difference(b).to_string(), " !");
+        assert_eq!(a.complement().size(), 29);
+        assert!(!a.complement().contains(b' '));
+        assert!(a.complement().contains(b'#'));
+        assert_eq!(SpecialSet::EMPTY.complement(), SpecialSet::ALL);
+        assert_eq!(SpecialSet::ALL.size(), 32);
+    }
+
+    #[test]
+    fn url_sets() {
+        let test_cases: &[(SpecialSet, &str)] = &[
+            (SpecialSet::C0_CONTROL, " !\"#$&'()*+,-./:;<=>?@[\\]^_`{|}~"),
+            (SpecialSet::FRAGMENT, "!#$&'()*+,-./:;=?@[\\]^_{|}~"),
+            (SpecialSet::QUERY, "!$&'()*+,-./:;=?@[\\]^_`{|}~"),
+            (SpecialSet::SPECIAL_QUERY, "!$&()*+,-./:;=?@[\\]^_`{|}~"),
+            (SpecialSet::PATH, "!$&'()*+,-./:;=@[\\]_|~"),
+            (SpecialSet::USERINFO, "!$&'()*+,-._~"),
+            (SpecialSet::COMPONENT, "!'()*-._~"),
+            (SpecialSet::FORM, "*-._"),
+            (SpecialSet::UNRESERVED, "-._~"),
+            (SpecialSet::ATTR_CHAR, "!#$&+-.^_`|~"),
+        ];
+
+        for (set, expected) in test_cases {
+            assert_eq!(set.to_string(), *expected);
+        }
+    }
+
     #[test]
     fn iter_display() {
         let mut set: SpecialSet = SpecialSet::default();
@@ -0,0 +1,12 @@
+/// The policy for handling invalid UTF-8 when decoding to a string.
+///
+/// Use `PercentDecoder::decode_cow` to get the raw decoded bytes instead.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub enum Utf8Policy {
+    /// Invalid UTF-8 is an error with the offset of the invalid encoded data.
+    #[default]
+    Strict,
+
+    /// Invalid UTF-8 is replaced with the `U+FFFD` replacement char.
+    Lossy,
+}
@@ -0,0 +1,9 @@
+pub use quoted_printable_decoder::*;
+pub use quoted_printable_encoder::*;
+pub use quoted_printable_mode::*;
+pub use quoted_printable_validator::*;
+
+mod quoted_printable_decoder;
+mod quoted_printable_encoder;
+mod quoted_printable_mode;
+mod quoted_printable_validator;
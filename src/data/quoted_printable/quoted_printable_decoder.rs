@@ -0,0 +1,141 @@
+use crate::Error::InsufficientTargetSpace;
+use crate::hex::{HexDecoder, HexValidator};
+use crate::{Decoder, Error};
+
+/// Responsible for decoding data in the quoted-printable format. (RFC 2045)
+///
+/// # Line Breaks
+/// The `=\r\n` and `=\n` soft line breaks are removed. Hard line breaks pass through unchanged.
+///
+/// # Case
+/// This decoder is case-insensitive. All encoded `=XX =xx =xX =Xx` sequences will be decoded.
+///
+/// # Validation
+/// No validation is done on the encoded data. All properly encoded chars will be decoded, and
+/// improperly encoded chars will pass through the decoder unchanged.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub struct QuotedPrintableDecoder {}
+
+impl QuotedPrintableDecoder {
+    //! Decoding
+
+    /// Decodes the prefix of the `data`.
+    ///
+    /// Returns the number of encoded bytes consumed and the decoded byte, if any.
+    ///
+    /// # Note
+    /// The `data` must not be empty.
+    #[inline(always)]
+    fn decode_prefix(data: &[u8]) -> (usize, Option<u8>) {
+        debug_assert!(!data.is_empty());
+
+        match data {
+            [b'=', b'\r', b'\n', ..] => (3, None),
+            [b'=', b'\n', ..] => (2, None),
+            [b'=', high, low, ..]
+                if HexValidator::CASELESS.is_valid_byte(*high)
+                    && HexValidator::CASELESS.is_valid_byte(*low) =>
+            {
+                (3, Some(HexDecoder::decode_bytes(*high, *low)))
+            }
+            _ => (1, Some(data[0])),
+        }
+    }
+}
+
+impl Decoder for QuotedPrintableDecoder {
+    fn decoded_len(&self, data: &[u8]) -> Result<usize, Error> {
+        let mut decoded_len: usize = 0;
+        let mut d: usize = 0;
+        while d < data.len() {
+            let (consumed, decoded) = Self::decode_prefix(&data[d..]);
+            if decoded.is_some() {
+                decoded_len += 1;
+            }
+            d += consumed;
+        }
+        Ok(decoded_len)
+    }
+
+    fn decode_to_slice(&self, data: &[u8], target: &mut [u8]) -> Result<usize, Error> {
+        let decoded_len: usize = self.decoded_len(data)?;
+        if decoded_len > target.len() {
+            Err(InsufficientTargetSpace)
+        } else {
+            let mut d: usize = 0;
+            let mut t: usize = 0;
+            while d < data.len() {
+                let (consumed, decoded) = Self::decode_prefix(&data[d..]);
+                if let Some(c) = decoded {
+                    target[t] = c;
+                    t += 1;
+                }
+                d += consumed;
+            }
+            debug_assert_eq!(t, decoded_len);
+            Ok(t)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::Decoder;
+    use crate::quoted_printable::{
+        QuotedPrintableDecoder, QuotedPrintableEncoder, QuotedPrintableMode,
+        QuotedPrintableValidator,
+    };
+    use crate::test::test_decoder;
+    use crate::{Encoder, Error, Validator};
+
+    #[test]
+    fn decode() {
+        let test_cases: &[(&str, &[u8])] = &[
+            ("", b""),
+            ("hello world", b"hello world"),
+            ("a=3Db", b"a=b"),
+            ("caf=C3=A9", "caf\u{00E9}".as_bytes()),
+            ("caf=c3=a9", "caf\u{00E9}".as_bytes()),
+            ("a=\r\nb", b"ab"),
+            ("a=\nb", b"ab"),
+            ("a\r\nb", b"a\r\nb"),
+            ("a=20\r\n", b"a \r\n"),
+            ("=", b"="),
+            ("=0", b"=0"),
+            ("=GG", b"=GG"),
+            ("=\r", b"=\r"),
+            ("==41", b"=A"),
+        ];
+        let decoder: QuotedPrintableDecoder = QuotedPrintableDecoder::default();
+        test_decoder(&decoder, test_cases);
+    }
+
+    #[test]
+    fn decode_encoded() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        let decoder: QuotedPrintableDecoder = QuotedPrintableDecoder::default();
+        for mode in [QuotedPrintableMode::Binary, QuotedPrintableMode::Text] {
+            let encoded: Vec<u8> = QuotedPrintableEncoder::new(mode)
+                .encode_as_vec(&data)
+                .unwrap();
+            assert!(
+                QuotedPrintableValidator::default()
+                    .is_valid(&encoded)
+                    .unwrap()
+            );
+            assert_eq!(decoder.decode_as_vec(&encoded).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn decode_insufficient_space() {
+        let decoder: QuotedPrintableDecoder = QuotedPrintableDecoder::default();
+        let mut target: Vec<u8> = vec![];
+        assert!(matches!(
+            decoder.decode_to_slice(b"=FF", &mut target),
+            Err(Error::InsufficientTargetSpace)
+        ));
+    }
+}
@@ -0,0 +1,188 @@
+use crate::Error::{InsufficientTargetSpace, IntegerOverflow};
+use crate::hex::HexEncoder;
+use crate::quoted_printable::QuotedPrintableMode;
+use crate::{Encoder, Error, StringEncoder, data};
+
+/// Responsible for encoding data in the quoted-printable format. (RFC 2045)
+///
+/// Bytes are encoded as `=XX` unless they are printable ASCII other than `=`. Spaces and tabs are
+/// only encoded at the end of a line. Lines are wrapped at 76 characters with `=\r\n` soft line
+/// breaks and encoded bytes are never split across lines.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub struct QuotedPrintableEncoder {
+    mode: QuotedPrintableMode,
+}
+
+impl QuotedPrintableEncoder {
+    //! Constants
+
+    /// The maximum length of an encoded line, excluding the line break.
+    pub const MAX_LINE_LEN: usize = 76;
+
+    /// The soft line break.
+    pub const SOFT_LINE_BREAK: &'static [u8] = b"=\r\n";
+}
+
+impl QuotedPrintableEncoder {
+    //! Construction
+
+    /// Creates a new quoted-printable encoder.
+    pub const fn new(mode: QuotedPrintableMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl QuotedPrintableEncoder {
+    //! Properties
+
+    /// Gets the mode.
+    pub const fn mode(self) -> QuotedPrintableMode {
+        self.mode
+    }
+}
+
+impl QuotedPrintableEncoder {
+    //! Encoding
+
+    /// Checks if `c` is a literal when it is not at the end of a line.
+    #[inline(always)]
+    fn is_literal(c: u8) -> bool {
+        (b'!'..=b'~').contains(&c) && c != b'=' || c == b' ' || c == b'\t'
+    }
+
+    /// Passes each encoded token of the `data` to the function `f`.
+    fn encode_each<F>(self, data: &[u8], mut f: F)
+    where
+        F: FnMut(&[u8]),
+    {
+        let mut line_len: usize = 0;
+        let mut i: usize = 0;
+        while i < data.len() {
+            let line_break_len: usize = self.mode.line_break_len(&data[i..]);
+            if line_break_len > 0 {
+                f(&data[i..(i + line_break_len)]);
+                line_len = 0;
+                i += line_break_len;
+                continue;
+            }
+
+            let c: u8 = data[i];
+            let rest: &[u8] = &data[(i + 1)..];
+            let at_line_end: bool = rest.is_empty() || self.mode.line_break_len(rest) > 0;
+            let literal: bool = Self::is_literal(c) && !(at_line_end && (c == b' ' || c == b'\t'));
+            let mut token: [u8; 3] = [c, 0, 0];
+            let token: &[u8] = if literal {
+                &token[..1]
+            } else {
+                let (high, low) = HexEncoder::UPPER.encode_bytes(c);
+                token = [b'=', high, low];
+                &token
+            };
+
+            let max_len: usize = if at_line_end {
+                Self::MAX_LINE_LEN
+            } else {
+                Self::MAX_LINE_LEN - 1
+            };
+            if line_len + token.len() > max_len {
+                f(Self::SOFT_LINE_BREAK);
+                line_len = 0;
+            }
+            f(token);
+            line_len += token.len();
+            i += 1;
+        }
+    }
+}
+
+impl Encoder for QuotedPrintableEncoder {
+    fn encoded_len(&self, data: &[u8]) -> Result<usize, Error> {
+        let mut encoded_len: Option<usize> = Some(0);
+        self.encode_each(data, |token| {
+            encoded_len = encoded_len.and_then(|len| len.checked_add(token.len()));
+        });
+        encoded_len.ok_or(IntegerOverflow)
+    }
+
+    fn encode_to_slice(&self, data: &[u8], target: &mut [u8]) -> Result<usize, Error> {
+        let encoded_len: usize = self.encoded_len(data)?;
+        if encoded_len > target.len() {
+            Err(InsufficientTargetSpace)
+        } else {
+            let mut t: usize = 0;
+            self.encode_each(data, |token| {
+                target[t..(t + token.len())].copy_from_slice(token);
+                t += token.len();
+            });
+            debug_assert_eq!(encoded_len, t);
+            Ok(t)
+        }
+    }
+
+    fn encode_in_chunks(
+        &self,
+        data: &[u8],
+        buffer: &mut [u8],
+        f: &mut dyn FnMut(&[u8]) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        data::util::encode_tokens_in_chunks(|token_fn| self.encode_each(data, token_fn), buffer, f)
+    }
+}
+
+impl StringEncoder for QuotedPrintableEncoder {
+    fn append_to_string(&self, data: &[u8], target: &mut String) -> Result<usize, Error> {
+        unsafe { data::util::append_to_string_unchecked(self, data, target) }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::quoted_printable::{QuotedPrintableEncoder, QuotedPrintableMode};
+    use crate::test::test_string_encoder;
+
+    #[test]
+    fn encode_text() {
+        let line_75: String = "a".repeat(75);
+        let line_76: String = "a".repeat(76);
+        let test_cases: &[(String, String)] = &[
+            (String::default(), String::default()),
+            ("hello".to_string(), "hello".to_string()),
+            ("a=b".to_string(), "a=3Db".to_string()),
+            ("caf\u{00E9}".to_string(), "caf=C3=A9".to_string()),
+            ("a b\tc".to_string(), "a b\tc".to_string()),
+            ("trailing ".to_string(), "trailing=20".to_string()),
+            ("tab\t".to_string(), "tab=09".to_string()),
+            ("a \r\nb\t\nc".to_string(), "a=20\r\nb=09\nc".to_string()),
+            ("a\rb".to_string(), "a=0Db".to_string()),
+            (line_76.clone(), line_76.clone()),
+            (format!("{line_76}\r\n"), format!("{line_76}\r\n")),
+            (format!("{line_76}a"), format!("{line_75}=\r\naa")),
+            (format!("{line_75}=="), format!("{line_75}=\r\n=3D=3D")),
+            (
+                format!("{}=", "a".repeat(73)),
+                format!("{}=3D", "a".repeat(73)),
+            ),
+            (
+                format!("{}==", "a".repeat(73)),
+                format!("{}=\r\n=3D=3D", "a".repeat(73)),
+            ),
+        ];
+        let encoder: QuotedPrintableEncoder =
+            QuotedPrintableEncoder::new(QuotedPrintableMode::Text);
+        test_string_encoder(&encoder, test_cases);
+    }
+
+    #[test]
+    fn encode_binary() {
+        let test_cases: &[(&[u8], &str)] = &[
+            (b"", ""),
+            (b"a\r\nb", "a=0D=0Ab"),
+            (b"a \nb", "a =0Ab"),
+            (b"\x00\xFF ", "=00=FF=20"),
+        ];
+        let encoder: QuotedPrintableEncoder =
+            QuotedPrintableEncoder::new(QuotedPrintableMode::Binary);
+        test_string_encoder(&encoder, test_cases);
+    }
+}
@@ -0,0 +1,50 @@
+/// The quoted-printable encoding mode.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub enum QuotedPrintableMode {
+    /// All CR and LF bytes are encoded. All line breaks in the encoded data are soft line breaks.
+    Binary,
+
+    /// The `\r\n` and `\n` line breaks are preserved as hard line breaks.
+    #[default]
+    Text,
+}
+
+impl QuotedPrintableMode {
+    //! Line Breaks
+
+    /// Gets the length of the line break at the start of the `data`.
+    ///
+    /// Returns 0 if the `data` does not start with a line break or the mode is `Binary`.
+    #[inline(always)]
+    pub(in crate::data::quoted_printable) fn line_break_len(self, data: &[u8]) -> usize {
+        match (self, data) {
+            (Self::Binary, _) => 0,
+            (Self::Text, [b'\r', b'\n', ..]) => 2,
+            (Self::Text, [b'\n', ..]) => 1,
+            (Self::Text, _) => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::quoted_printable::QuotedPrintableMode;
+
+    #[test]
+    fn line_break_len() {
+        let test_cases: &[(&[u8], usize, usize)] = &[
+            (b"", 0, 0),
+            (b"a", 0, 0),
+            (b"\r", 0, 0),
+            (b"\ra", 0, 0),
+            (b"\n", 0, 1),
+            (b"\r\n", 0, 2),
+            (b"\r\na", 0, 2),
+        ];
+
+        for (data, binary, text) in test_cases {
+            assert_eq!(QuotedPrintableMode::Binary.line_break_len(data), *binary);
+            assert_eq!(QuotedPrintableMode::Text.line_break_len(data), *text);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::hex::HexValidator;
+use crate::quoted_printable::QuotedPrintableEncoder;
+use crate::{Error, Validator};
+
+/// Responsible for validating quoted-printable encoded data. (RFC 2045)
+///
+/// Valid data contains only printable ASCII, spaces, tabs, `=XX` encoded bytes, and `\r\n` or `\n`
+/// line breaks. Lines must not exceed 76 chars and must not end with a space or tab.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct QuotedPrintableValidator {
+    hex_validator: HexValidator,
+}
+
+impl QuotedPrintableValidator {
+    //! Construction
+
+    /// Creates a new quoted-printable validator.
+    pub const fn new(hex_validator: HexValidator) -> Self {
+        Self { hex_validator }
+    }
+}
+
+impl Default for QuotedPrintableValidator {
+    fn default() -> Self {
+        Self::new(HexValidator::UPPER_ONLY)
+    }
+}
+
+impl Validator for QuotedPrintableValidator {
+    fn is_valid(&self, data: &[u8]) -> Result<bool, Error> {
+        let mut line_start: usize = 0;
+        let mut i: usize = 0;
+        while i <= data.len() {
+            let line_break_len: usize = match &data[i..] {
+                [] => 0,
+                [b'\r', b'\n', ..] => 2,
+                [b'\n', ..] => 1,
+                [b'\r', ..] => return Ok(false),
+                [b'=', b'\r', b'\n', ..] | [b'=', b'\n', ..] => {
+                    i += 1;
+                    continue;
+                }
+                [b'=', high, low, ..] => {
+                    if !self.hex_validator.is_valid_byte(*high)
+                        || !self.hex_validator.is_valid_byte(*low)
+                    {
+                        return Ok(false);
+                    }
+                    i += 3;
+                    continue;
+                }
+                [b'=', ..] => return Ok(false),
+                [c, ..] => {
+                    if !(b'!'..=b'~').contains(c) && *c != b' ' && *c != b'\t' {
+                        return Ok(false);
+                    }
+                    i += 1;
+                    continue;
+                }
+            };
+
+            let line: &[u8] = &data[line_start..i];
+            if line.len() > QuotedPrintableEncoder::MAX_LINE_LEN
+                || matches!(line.last(), Some(b' ' | b'\t'))
+            {
+                return Ok(false);
+            }
+            if line_break_len == 0 {
+                break;
+            }
+            i += line_break_len;
+            line_start = i;
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::hex::HexValidator;
+    use crate::quoted_printable::QuotedPrintableValidator;
+    use crate::test::test_validator;
+
+    #[test]
+    fn is_valid() {
+        let line_76: String = "a".repeat(76);
+        let line_77: String = "a".repeat(77);
+        let test_cases: &[(String, bool)] = &[
+            (String::default(), true),
+            ("hello world".to_string(), true),
+            ("a=3Db".to_string(), true),
+            ("a=3db".to_string(), false),
+            ("a=".to_string(), false),
+            ("a=3".to_string(), false),
+            ("a=GG".to_string(), false),
+            ("a=\r\nb".to_string(), true),
+            ("a=\nb".to_string(), true),
+            ("a\r\nb\nc".to_string(), true),
+            ("a\rb".to_string(), false),
+            ("a\x00b".to_string(), false),
+            ("caf\u{00E9}".to_string(), false),
+            ("a b\tc".to_string(), true),
+            ("a ".to_string(), false),
+            ("a\t\r\nb".to_string(), false),
+            ("a =\r\nb".to_string(), true),
+            (line_76.clone(), true),
+            (line_77.clone(), false),
+            (format!("{line_76}\r\n{line_76}"), true),
+            (format!("{line_76}\r\n{line_77}"), false),
+            (format!("{}=\r\nb", "a".repeat(75)), true),
+            (format!("{}=\r\nb", "a".repeat(76)), false),
+        ];
+        let validator: QuotedPrintableValidator = QuotedPrintableValidator::default();
+        test_validator(&validator, test_cases);
+    }
+
+    #[test]
+    fn is_valid_caseless() {
+        let test_cases: &[(&str, bool)] = &[("=3D", true), ("=3d", true), ("=3G", false)];
+        let validator: QuotedPrintableValidator =
+            QuotedPrintableValidator::new(HexValidator::CASELESS);
+        test_validator(&validator, test_cases);
+    }
+}
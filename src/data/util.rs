@@ -1,7 +1,6 @@
-#[cfg(any(feature = "hex", feature = "base-64", feature = "percent"))]
 use crate::Encoder;
 use crate::Error;
-use crate::Error::IntegerOverflow;
+use crate::Error::{InsufficientTargetSpace, IntegerOverflow};
 
 /// The default implementation of the `append_to_vec` function for `Encoder`s and `Decoder`s.
 ///
@@ -36,13 +35,100 @@ where
     }
 }
 
+/// The default implementation of the `encode_in_chunks` function for `Encoder`s.
+pub(crate) fn default_encode_in_chunks<E>(
+    encoder: &E,
+    data: &[u8],
+    buffer: &mut [u8],
+    f: &mut dyn FnMut(&[u8]) -> Result<(), Error>,
+) -> Result<(), Error>
+where
+    E: Encoder + ?Sized,
+{
+    let chunk_len: usize = match encoder.block_size() {
+        Some(block_size) => {
+            let block_size: usize = block_size.max(1);
+            ((buffer.len() / 4) / block_size).max(1) * block_size
+        }
+        None => data.len(),
+    };
+    if data.is_empty() || chunk_len >= data.len() {
+        return encode_chunk(encoder, data, buffer, f);
+    }
+    for chunk in data.chunks(chunk_len) {
+        encode_chunk(encoder, chunk, buffer, f)?;
+    }
+    Ok(())
+}
+
+/// Encodes the `chunk` through the `buffer` if it fits, otherwise as a vec, and passes it to `f`.
+fn encode_chunk<E>(
+    encoder: &E,
+    chunk: &[u8],
+    buffer: &mut [u8],
+    f: &mut dyn FnMut(&[u8]) -> Result<(), Error>,
+) -> Result<(), Error>
+where
+    E: Encoder + ?Sized,
+{
+    match encoder.encode_to_slice(chunk, buffer) {
+        Ok(encoded_len) => f(&buffer[..encoded_len]),
+        Err(InsufficientTargetSpace) => f(&encoder.encode_as_vec(chunk)?),
+        Err(error) => Err(error),
+    }
+}
+
+/// Collects the encoded tokens passed by the `encode_each` function into chunks in the `buffer`,
+/// passing each chunk to `f`. Tokens that do not fit in the `buffer` are passed as-is.
+#[cfg(any(feature = "quoted-printable", feature = "encoded-word"))]
+pub(crate) fn encode_tokens_in_chunks<T>(
+    encode_each: T,
+    buffer: &mut [u8],
+    f: &mut dyn FnMut(&[u8]) -> Result<(), Error>,
+) -> Result<(), Error>
+where
+    T: FnOnce(&mut dyn FnMut(&[u8])),
+{
+    let mut len: usize = 0;
+    let mut result: Result<(), Error> = Ok(());
+    encode_each(&mut |token: &[u8]| {
+        if result.is_err() {
+            return;
+        }
+        if len > 0 && len + token.len() > buffer.len() {
+            result = f(&buffer[..len]);
+            len = 0;
+        }
+        if result.is_ok() {
+            if token.len() > buffer.len() {
+                result = f(token);
+            } else {
+                buffer[len..(len + token.len())].copy_from_slice(token);
+                len += token.len();
+            }
+        }
+    });
+    result?;
+    if len > 0 {
+        f(&buffer[..len])?;
+    }
+    Ok(())
+}
+
 /// Appends the encoded `data` to the `target` string.
 ///
 /// Returns the length of the encoded `data`.
 ///
 /// # Safety
 /// The encoded `data` must be a valid UTF-8 byte sequence.
-#[cfg(any(feature = "hex", feature = "base-64", feature = "percent"))]
+#[cfg(any(
+    feature = "hex",
+    feature = "base-64",
+    feature = "percent",
+    feature = "quoted-printable",
+    feature = "encoded-word",
+    feature = "data-url"
+))]
 pub(crate) unsafe fn append_to_string_unchecked<E>(
     encoder: &E,
     data: &[u8],
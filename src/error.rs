@@ -1,3 +1,4 @@
+use crate::PositionedReason;
 use std::fmt::{Display, Formatter};
 use std::io;
 use std::io::ErrorKind;
@@ -21,6 +22,52 @@ pub enum Error {
     },
 }
 
+impl Error {
+    //! Positions
+
+    /// Creates an `InvalidEncodedData` error with a positioned reason.
+    pub fn invalid_at<S>(offset: usize, message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::InvalidEncodedData {
+            reason: Some(Box::new(PositionedReason::new(offset, message))),
+        }
+    }
+
+    /// Gets the byte offset of the invalid data.
+    ///
+    /// Returns `None` if the error does not have a positioned reason.
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Self::InvalidEncodedData {
+                reason: Some(reason),
+            } => reason
+                .downcast_ref::<PositionedReason>()
+                .map(PositionedReason::offset),
+            _ => None,
+        }
+    }
+
+    /// Shifts the offset of a positioned reason by `shift` bytes.
+    ///
+    /// This is used when the invalid data was part of larger encoded data. Errors without a
+    /// positioned reason are returned unchanged.
+    pub fn shift_offset(self, shift: usize) -> Self {
+        match self {
+            Self::InvalidEncodedData {
+                reason: Some(reason),
+            } => match reason.downcast::<PositionedReason>() {
+                Ok(reason) => Self::invalid_at(reason.offset() + shift, reason.message()),
+                Err(reason) => Self::InvalidEncodedData {
+                    reason: Some(reason),
+                },
+            },
+            error => error,
+        }
+    }
+}
+
 impl From<Error> for io::Error {
     fn from(error: Error) -> Self {
         match error {
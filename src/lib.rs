@@ -6,6 +6,7 @@
 #[cfg(feature = "data")]
 pub use data::*;
 pub use error::*;
+pub use positioned_reason::*;
 pub use util::*;
 #[cfg(feature = "value")]
 pub use value::*;
@@ -13,6 +14,7 @@ pub use value::*;
 #[cfg(feature = "data")]
 mod data;
 mod error;
+mod positioned_reason;
 mod util;
 #[cfg(feature = "value")]
 mod value;
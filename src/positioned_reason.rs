@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter};
+
+/// The reason encoded data was invalid with the byte offset of the invalid data.
+///
+/// This is used as the `reason` of an `Error::InvalidEncodedData` error.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct PositionedReason {
+    offset: usize,
+    message: String,
+}
+
+impl PositionedReason {
+    //! Construction
+
+    /// Creates a new positioned reason.
+    pub fn new<S>(offset: usize, message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            offset,
+            message: message.into(),
+        }
+    }
+}
+
+impl PositionedReason {
+    //! Properties
+
+    /// Gets the byte offset of the invalid data.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Gets the message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for PositionedReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (offset={})", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for PositionedReason {}
@@ -1,9 +1,11 @@
 pub use test_decoder::*;
 pub use test_encoder::*;
+pub use test_forgiving_base_64::*;
 pub use test_validator::*;
 
 mod test_decoder;
 mod test_encoder;
+mod test_forgiving_base_64;
 mod test_validator;
 
 mod util;
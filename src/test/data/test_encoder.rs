@@ -15,7 +15,20 @@ where
         |input, output| encoder.encode_to_slice(input, output),
         |input, output| encoder.append_to_vec(input, output),
         |input| encoder.encode_as_vec(input),
-    )
+    );
+
+    for (input, expected) in test_cases {
+        let input: &[u8] = input.as_ref();
+        let mut buffer: [u8; 8] = [0u8; 8];
+        let mut output: Vec<u8> = Vec::default();
+        encoder
+            .encode_in_chunks(input, &mut buffer, &mut |chunk| {
+                output.extend_from_slice(chunk);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(expected.as_ref(), output, "input={}", hex(input));
+    }
 }
 
 /// Tests the string `encoder`.
@@ -0,0 +1,104 @@
+/// The forgiving-base64 decode conformance test cases. (WHATWG Infra, web-platform-tests `atob`)
+///
+/// Each case is the encoded input and the decoded output, or `None` if decoding fails.
+pub const FORGIVING_BASE_64_CASES: &[(&str, Option<&[u8]>)] = &[
+    ("", Some(b"")),
+    ("abcd", Some(&[105, 183, 29])),
+    (" abcd", Some(&[105, 183, 29])),
+    ("abcd ", Some(&[105, 183, 29])),
+    (" abcd===", None),
+    ("abcd=== ", None),
+    ("abcd ===", None),
+    ("a", None),
+    ("ab", Some(&[105])),
+    ("abc", Some(&[105, 183])),
+    ("abcde", None),
+    ("\u{10000}", None),
+    ("=", None),
+    ("==", None),
+    ("===", None),
+    ("====", None),
+    ("=====", None),
+    ("a=", None),
+    ("a==", None),
+    ("a===", None),
+    ("a====", None),
+    ("a=====", None),
+    ("ab=", None),
+    ("ab==", Some(&[105])),
+    ("ab===", None),
+    ("ab====", None),
+    ("ab=====", None),
+    ("abc=", Some(&[105, 183])),
+    ("abc==", None),
+    ("abc===", None),
+    ("abc====", None),
+    ("abc=====", None),
+    ("abcd=", None),
+    ("abcd==", None),
+    ("abcd===", None),
+    ("abcd====", None),
+    ("abcd=====", None),
+    ("abcde=", None),
+    ("abcde==", None),
+    ("abcde===", None),
+    ("abcde====", None),
+    ("abcde=====", None),
+    ("=a", None),
+    ("=a=", None),
+    ("a=b", None),
+    ("a=b=", None),
+    ("ab=c", None),
+    ("ab=c=", None),
+    ("abc=d", None),
+    ("abc=d=", None),
+    ("ab\u{000B}cd", None),
+    ("ab\u{3000}cd", None),
+    ("ab\u{3001}cd", None),
+    ("ab\tcd", Some(&[105, 183, 29])),
+    ("ab\ncd", Some(&[105, 183, 29])),
+    ("ab\u{000C}cd", Some(&[105, 183, 29])),
+    ("ab\rcd", Some(&[105, 183, 29])),
+    ("ab cd", Some(&[105, 183, 29])),
+    ("ab\u{00A0}cd", None),
+    ("ab\t\n\u{000C}\r cd", Some(&[105, 183, 29])),
+    (
+        " \t\n\u{000C}\r ab\t\n\u{000C}\r cd\t\n\u{000C}\r ",
+        Some(&[105, 183, 29]),
+    ),
+    (
+        "ab\t\n\u{000C}\r =\t\n\u{000C}\r =\t\n\u{000C}\r ",
+        Some(&[105]),
+    ),
+    ("A", None),
+    ("/A", Some(&[252])),
+    ("//A", Some(&[255, 240])),
+    ("///A", Some(&[255, 255, 192])),
+    ("////A", None),
+    ("/", None),
+    ("A/", Some(&[3])),
+    ("AA/", Some(&[0, 15])),
+    ("AAAA/", None),
+    ("AAA/", Some(&[0, 0, 63])),
+    ("\u{0000}nonsense", None),
+    ("abcd\u{0000}nonsense", None),
+    ("YQ", Some(&[97])),
+    ("YR", Some(&[97])),
+    ("~~", None),
+    ("..", None),
+    ("--", None),
+    ("__", None),
+];
+
+/// Tests the `decode` function with the forgiving-base64 conformance test cases.
+///
+/// The `decode` function returns `None` if decoding fails.
+pub fn test_forgiving_base_64<F>(decode: F)
+where
+    F: Fn(&[u8]) -> Option<Vec<u8>>,
+{
+    for (encoded, expected) in FORGIVING_BASE_64_CASES {
+        let result: Option<Vec<u8>> = decode(encoded.as_bytes());
+        assert_eq!(result.as_deref(), *expected, "encoded={encoded:?}");
+    }
+}
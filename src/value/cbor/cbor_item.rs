@@ -0,0 +1,55 @@
+/// A single CBOR data item header or scalar read by a `CborReader`.
+///
+/// Definite-length strings are read in full. Indefinite-length strings, arrays and maps are
+/// reported by their start item and their contents are followed by a `Break` item.
+#[derive(Clone, PartialEq, Debug)]
+pub enum CborItem {
+    /// An unsigned integer. (major type 0)
+    Unsigned(u64),
+
+    /// A negative integer with the value `-1 - n`. (major type 1)
+    Negative(u64),
+
+    /// A definite-length byte string. (major type 2)
+    Bytes(Vec<u8>),
+
+    /// The start of an indefinite-length byte string.
+    BytesStart,
+
+    /// A definite-length text string. (major type 3)
+    Text(String),
+
+    /// The start of an indefinite-length text string.
+    TextStart,
+
+    /// The start of an array with the optional length. (major type 4)
+    ///
+    /// A length of `None` indicates an indefinite-length array.
+    Array(Option<u64>),
+
+    /// The start of a map with the optional number of pairs. (major type 5)
+    ///
+    /// A length of `None` indicates an indefinite-length map.
+    Map(Option<u64>),
+
+    /// A tag for the following item. (major type 6)
+    Tag(u64),
+
+    /// A simple value other than `false`, `true`, `null` and `undefined`. (major type 7)
+    Simple(u8),
+
+    /// A boolean value.
+    Bool(bool),
+
+    /// The `null` value.
+    Null,
+
+    /// The `undefined` value.
+    Undefined,
+
+    /// A half, single or double-precision float.
+    Float(f64),
+
+    /// The end of an indefinite-length item.
+    Break,
+}
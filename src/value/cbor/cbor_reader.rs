@@ -0,0 +1,358 @@
+use crate::Error::InvalidEncodedData;
+use crate::cbor::float::f16_to_f64;
+use crate::cbor::header::{
+    ARRAY, BYTES, EIGHT_BYTES, FOUR_BYTES, INDEFINITE, MAP, NEGATIVE, ONE_BYTE, SIMPLE, TAG, TEXT,
+    TWO_BYTES, UNSIGNED,
+};
+use crate::cbor::{CborItem, CborValue};
+use crate::{Error, read_optional_byte, read_single_byte};
+use std::io;
+use std::io::ErrorKind::UnexpectedEof;
+use std::io::Read;
+
+/// Responsible for reading CBOR data items from a `Read`.
+///
+/// Items can be read one at a time with `read_item` or as complete values with `read_value`.
+#[derive(Debug)]
+pub struct CborReader<R> {
+    r: R,
+}
+
+impl<R: Read> CborReader<R> {
+    //! Constants
+
+    /// The maximum nesting depth of arrays, maps and tags when reading values.
+    pub const MAX_DEPTH: usize = 256;
+}
+
+impl<R: Read> CborReader<R> {
+    //! Construction
+
+    /// Creates a new CBOR reader.
+    pub fn new(r: R) -> Self {
+        Self { r }
+    }
+
+    /// Gets the underlying `Read`.
+    pub fn into_inner(self) -> R {
+        self.r
+    }
+}
+
+impl<R: Read> CborReader<R> {
+    //! Items
+
+    /// Reads the next item.
+    ///
+    /// Returns `None` if the `Read` is empty.
+    pub fn read_item(&mut self) -> Result<Option<CborItem>, Error> {
+        if let Some(first) = read_optional_byte(&mut self.r)? {
+            Ok(Some(self.read_item_with_first_byte(first)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads the next item given the `first` byte.
+    pub fn read_item_with_first_byte(&mut self, first: u8) -> Result<CborItem, Error> {
+        let major: u8 = first >> 5;
+        let additional: u8 = first & 0x1F;
+        if major == SIMPLE {
+            return self.read_simple(additional);
+        }
+        let argument: Option<u64> = self.read_argument(additional)?;
+        match (major, argument) {
+            (UNSIGNED, Some(value)) => Ok(CborItem::Unsigned(value)),
+            (NEGATIVE, Some(n)) => Ok(CborItem::Negative(n)),
+            (BYTES, Some(len)) => Ok(CborItem::Bytes(self.read_payload(len)?)),
+            (BYTES, None) => Ok(CborItem::BytesStart),
+            (TEXT, Some(len)) => {
+                let payload: Vec<u8> = self.read_payload(len)?;
+                let text: String =
+                    String::from_utf8(payload).map_err(|error| InvalidEncodedData {
+                        reason: Some(error.into()),
+                    })?;
+                Ok(CborItem::Text(text))
+            }
+            (TEXT, None) => Ok(CborItem::TextStart),
+            (ARRAY, len) => Ok(CborItem::Array(len)),
+            (MAP, len) => Ok(CborItem::Map(len)),
+            (TAG, Some(tag)) => Ok(CborItem::Tag(tag)),
+            _ => Err(Self::invalid_initial_byte(first)),
+        }
+    }
+
+    /// Reads the argument for the `additional` info.
+    ///
+    /// Returns `None` for an indefinite length.
+    fn read_argument(&mut self, additional: u8) -> Result<Option<u64>, Error> {
+        let len: usize = match additional {
+            0..ONE_BYTE => return Ok(Some(additional as u64)),
+            ONE_BYTE => 1,
+            TWO_BYTES => 2,
+            FOUR_BYTES => 4,
+            EIGHT_BYTES => 8,
+            INDEFINITE => return Ok(None),
+            _ => {
+                return Err(InvalidEncodedData {
+                    reason: Some(format!("reserved additional info: {additional}").into()),
+                });
+            }
+        };
+        let mut buffer: [u8; 8] = [0u8; 8];
+        self.r.read_exact(&mut buffer[(8 - len)..])?;
+        Ok(Some(u64::from_be_bytes(buffer)))
+    }
+
+    /// Reads a simple value or float with the `additional` info.
+    fn read_simple(&mut self, additional: u8) -> Result<CborItem, Error> {
+        match additional {
+            20 => Ok(CborItem::Bool(false)),
+            21 => Ok(CborItem::Bool(true)),
+            22 => Ok(CborItem::Null),
+            23 => Ok(CborItem::Undefined),
+            0..ONE_BYTE => Ok(CborItem::Simple(additional)),
+            ONE_BYTE => match read_single_byte(&mut self.r)? {
+                value @ 32.. => Ok(CborItem::Simple(value)),
+                value => Err(InvalidEncodedData {
+                    reason: Some(format!("invalid two-byte simple value: {value}").into()),
+                }),
+            },
+            TWO_BYTES => {
+                let mut buffer: [u8; 2] = [0u8; 2];
+                self.r.read_exact(&mut buffer)?;
+                Ok(CborItem::Float(f16_to_f64(u16::from_be_bytes(buffer))))
+            }
+            FOUR_BYTES => {
+                let mut buffer: [u8; 4] = [0u8; 4];
+                self.r.read_exact(&mut buffer)?;
+                Ok(CborItem::Float(f32::from_be_bytes(buffer) as f64))
+            }
+            EIGHT_BYTES => {
+                let mut buffer: [u8; 8] = [0u8; 8];
+                self.r.read_exact(&mut buffer)?;
+                Ok(CborItem::Float(f64::from_be_bytes(buffer)))
+            }
+            INDEFINITE => Ok(CborItem::Break),
+            _ => Err(Self::invalid_initial_byte((SIMPLE << 5) | additional)),
+        }
+    }
+
+    /// Reads a string payload of `len` bytes.
+    fn read_payload(&mut self, len: u64) -> Result<Vec<u8>, Error> {
+        let mut payload: Vec<u8> = Vec::default();
+        (&mut self.r).take(len).read_to_end(&mut payload)?;
+        if payload.len() as u64 != len {
+            Err(io::Error::from(UnexpectedEof).into())
+        } else {
+            Ok(payload)
+        }
+    }
+
+    /// Creates the error for the invalid `first` byte.
+    fn invalid_initial_byte(first: u8) -> Error {
+        InvalidEncodedData {
+            reason: Some(format!("invalid initial byte: {first:#04X}").into()),
+        }
+    }
+}
+
+impl<R: Read> CborReader<R> {
+    //! Values
+
+    /// Reads the next complete value.
+    ///
+    /// Indefinite-length strings are concatenated and indefinite-length arrays and maps are
+    /// collected. Returns `None` if the `Read` is empty.
+    pub fn read_value(&mut self) -> Result<Option<CborValue>, Error> {
+        if let Some(first) = read_optional_byte(&mut self.r)? {
+            Ok(Some(self.read_value_with_first_byte(first)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads the next complete value given the `first` byte.
+    pub fn read_value_with_first_byte(&mut self, first: u8) -> Result<CborValue, Error> {
+        let item: CborItem = self.read_item_with_first_byte(first)?;
+        self.read_value_from_item(item, 0)
+    }
+
+    /// Reads the next required item.
+    fn read_required_item(&mut self) -> Result<CborItem, Error> {
+        let first: u8 = read_single_byte(&mut self.r)?;
+        self.read_item_with_first_byte(first)
+    }
+
+    /// Reads the next required value at the `depth`.
+    ///
+    /// Returns `None` for a break.
+    fn read_nested_value(&mut self, depth: usize) -> Result<Option<CborValue>, Error> {
+        match self.read_required_item()? {
+            CborItem::Break => Ok(None),
+            item => Ok(Some(self.read_value_from_item(item, depth)?)),
+        }
+    }
+
+    /// Reads the rest of the value starting with the `item` at the `depth`.
+    fn read_value_from_item(&mut self, item: CborItem, depth: usize) -> Result<CborValue, Error> {
+        if depth > Self::MAX_DEPTH {
+            return Err(InvalidEncodedData {
+                reason: Some(format!("max depth exceeded: {}", Self::MAX_DEPTH).into()),
+            });
+        }
+        let unexpected_break = || InvalidEncodedData {
+            reason: Some("unexpected break".into()),
+        };
+        Ok(match item {
+            CborItem::Unsigned(value) => CborValue::Unsigned(value),
+            CborItem::Negative(n) => CborValue::Negative(n),
+            CborItem::Bytes(value) => CborValue::Bytes(value),
+            CborItem::BytesStart => {
+                let mut value: Vec<u8> = Vec::default();
+                loop {
+                    match self.read_required_item()? {
+                        CborItem::Bytes(chunk) => value.extend_from_slice(&chunk),
+                        CborItem::Break => break CborValue::Bytes(value),
+                        item => return Err(Self::invalid_chunk(item)),
+                    }
+                }
+            }
+            CborItem::Text(value) => CborValue::Text(value),
+            CborItem::TextStart => {
+                let mut value: String = String::default();
+                loop {
+                    match self.read_required_item()? {
+                        CborItem::Text(chunk) => value.push_str(&chunk),
+                        CborItem::Break => break CborValue::Text(value),
+                        item => return Err(Self::invalid_chunk(item)),
+                    }
+                }
+            }
+            CborItem::Array(len) => {
+                let mut items: Vec<CborValue> = Vec::default();
+                if let Some(len) = len {
+                    for _ in 0..len {
+                        items.push(
+                            self.read_nested_value(depth + 1)?
+                                .ok_or_else(unexpected_break)?,
+                        );
+                    }
+                } else {
+                    while let Some(item) = self.read_nested_value(depth + 1)? {
+                        items.push(item);
+                    }
+                }
+                CborValue::Array(items)
+            }
+            CborItem::Map(len) => {
+                let mut entries: Vec<(CborValue, CborValue)> = Vec::default();
+                if let Some(len) = len {
+                    for _ in 0..len {
+                        let key: CborValue = self
+                            .read_nested_value(depth + 1)?
+                            .ok_or_else(unexpected_break)?;
+                        let value: CborValue = self
+                            .read_nested_value(depth + 1)?
+                            .ok_or_else(unexpected_break)?;
+                        entries.push((key, value));
+                    }
+                } else {
+                    while let Some(key) = self.read_nested_value(depth + 1)? {
+                        let value: CborValue = self
+                            .read_nested_value(depth + 1)?
+                            .ok_or_else(unexpected_break)?;
+                        entries.push((key, value));
+                    }
+                }
+                CborValue::Map(entries)
+            }
+            CborItem::Tag(tag) => {
+                let value: CborValue = self
+                    .read_nested_value(depth + 1)?
+                    .ok_or_else(unexpected_break)?;
+                CborValue::Tag(tag, Box::new(value))
+            }
+            CborItem::Simple(value) => CborValue::Simple(value),
+            CborItem::Bool(value) => CborValue::Bool(value),
+            CborItem::Null => CborValue::Null,
+            CborItem::Undefined => CborValue::Undefined,
+            CborItem::Float(value) => CborValue::Float(value),
+            CborItem::Break => return Err(unexpected_break()),
+        })
+    }
+
+    /// Creates the error for an invalid indefinite-length string chunk `item`.
+    fn invalid_chunk(item: CborItem) -> Error {
+        InvalidEncodedData {
+            reason: Some(format!("invalid indefinite-length string chunk: {item:?}").into()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::Error;
+    use crate::cbor::{CborItem, CborReader};
+
+    #[test]
+    fn read_items() -> Result<(), Error> {
+        let encoded: &[u8] =
+            b"\x9F\x01\x5F\x41\x02\xFF\xBF\xFF\xC1\x1A\x00\x0F\x42\x40\xF8\x20\xFF";
+        let mut r: CborReader<&[u8]> = CborReader::new(encoded);
+        let expected: &[CborItem] = &[
+            CborItem::Array(None),
+            CborItem::Unsigned(1),
+            CborItem::BytesStart,
+            CborItem::Bytes(vec![2]),
+            CborItem::Break,
+            CborItem::Map(None),
+            CborItem::Break,
+            CborItem::Tag(1),
+            CborItem::Unsigned(1_000_000),
+            CborItem::Simple(32),
+            CborItem::Break,
+        ];
+        for item in expected {
+            assert_eq!(r.read_item()?.as_ref(), Some(item));
+        }
+        assert_eq!(r.read_item()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn read_invalid() {
+        let test_cases: &[&[u8]] = &[
+            b"\x1C",             // reserved additional info
+            b"\x3F",             // indefinite negative integer
+            b"\xDF",             // indefinite tag
+            b"\xFC",             // reserved simple additional info
+            b"\xF8\x18",         // invalid two-byte simple value
+            b"\x62\xC3\x28",     // invalid UTF-8
+            b"\x5F\x61a\xFF",    // text chunk in a byte string
+            b"\x5F\x5F\xFF\xFF", // nested indefinite-length byte string
+            b"\x82\x01\xFF",     // break in a definite-length array
+            b"\xA1\x01",         // missing map value
+            b"\xFF",             // unexpected break
+        ];
+
+        for encoded in test_cases {
+            let mut r: CborReader<&[u8]> = CborReader::new(encoded);
+            match r.read_value() {
+                Err(Error::InvalidEncodedData { .. }) | Err(Error::Stream(_)) => {}
+                result => panic!("encoded={encoded:?} result={result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn max_depth() {
+        let encoded: Vec<u8> = vec![0x81; CborReader::<&[u8]>::MAX_DEPTH + 2];
+        let mut r: CborReader<&[u8]> = CborReader::new(&encoded);
+        assert!(matches!(
+            r.read_value(),
+            Err(Error::InvalidEncodedData { .. })
+        ));
+    }
+}
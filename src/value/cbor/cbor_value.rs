@@ -0,0 +1,315 @@
+use crate::Error::{IntegerOverflow, InvalidEncodedData};
+use crate::cbor::float::{f64_to_f16, f64_to_f32};
+use crate::cbor::header::header_len;
+use crate::cbor::{CborReader, CborWriter};
+use crate::{Error, impl_decode_from_read_by_prefix};
+use std::io::{Read, Write};
+
+/// A complete CBOR data item.
+///
+/// Values are always encoded with definite lengths, shortest form integer arguments and shortest
+/// form floats. Map entries are encoded in their given order.
+#[derive(Clone, PartialEq, Debug)]
+pub enum CborValue {
+    /// An unsigned integer.
+    Unsigned(u64),
+
+    /// The negative integer: `-1 - n`.
+    Negative(u64),
+
+    /// A byte string.
+    Bytes(Vec<u8>),
+
+    /// A text string.
+    Text(String),
+
+    /// An array of values.
+    Array(Vec<CborValue>),
+
+    /// A map of key-value pairs.
+    Map(Vec<(CborValue, CborValue)>),
+
+    /// A tagged value.
+    Tag(u64, Box<CborValue>),
+
+    /// A simple value not covered by the other variants.
+    ///
+    /// The values `[20, 23]` are covered by the `Bool`, `Null` & `Undefined` variants and the values
+    /// `[24, 31]` are reserved, so both ranges are invalid.
+    Simple(u8),
+
+    /// A boolean value.
+    Bool(bool),
+
+    /// The `null` value.
+    Null,
+
+    /// The `undefined` value.
+    Undefined,
+
+    /// A floating-point value.
+    Float(f64),
+}
+
+impl From<u64> for CborValue {
+    fn from(value: u64) -> Self {
+        Self::Unsigned(value)
+    }
+}
+
+impl From<i64> for CborValue {
+    fn from(value: i64) -> Self {
+        if value < 0 {
+            Self::Negative(!value as u64)
+        } else {
+            Self::Unsigned(value as u64)
+        }
+    }
+}
+
+impl From<&str> for CborValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for CborValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<Vec<u8>> for CborValue {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl From<bool> for CborValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<f64> for CborValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl crate::EncodedLen for CborValue {
+    fn encoded_len(&self) -> Result<usize, Error> {
+        let add = |a: usize, b: usize| a.checked_add(b).ok_or(IntegerOverflow);
+        match self {
+            Self::Unsigned(argument) | Self::Negative(argument) => Ok(header_len(*argument)),
+            Self::Bytes(value) => add(header_len(value.len() as u64), value.len()),
+            Self::Text(value) => add(header_len(value.len() as u64), value.len()),
+            Self::Array(items) => items.iter().try_fold(
+                header_len(items.len() as u64),
+                |len: usize, item: &CborValue| add(len, item.encoded_len()?),
+            ),
+            Self::Map(entries) => entries.iter().try_fold(
+                header_len(entries.len() as u64),
+                |len: usize, (key, value): &(CborValue, CborValue)| {
+                    add(add(len, key.encoded_len()?)?, value.encoded_len()?)
+                },
+            ),
+            Self::Tag(tag, value) => add(header_len(*tag), value.encoded_len()?),
+            Self::Simple(value) => match value {
+                0..20 => Ok(1),
+                20..32 => Err(InvalidEncodedData {
+                    reason: Some(format!("reserved simple value: {value}").into()),
+                }),
+                _ => Ok(2),
+            },
+            Self::Bool(_) | Self::Null | Self::Undefined => Ok(1),
+            Self::Float(value) => Ok(if f64_to_f16(*value).is_some() {
+                3
+            } else if f64_to_f32(*value).is_some() {
+                5
+            } else {
+                9
+            }),
+        }
+    }
+}
+
+impl crate::EncodeToSlice for CborValue {
+    unsafe fn encode_to_slice_unchecked(&self, target: &mut [u8]) -> Result<usize, Error> {
+        let mut w: &mut [u8] = target;
+        CborWriter::new(&mut w).write_value(self)
+    }
+}
+
+impl crate::EncodeToWrite for CborValue {
+    fn encode_to_write<W>(&self, w: &mut W) -> Result<usize, Error>
+    where
+        W: Write,
+    {
+        CborWriter::new(w).write_value(self)
+    }
+}
+
+impl crate::DecodeFromReadPrefix for CborValue {
+    fn decode_from_read_prefix_with_first_byte<R>(r: &mut R, first: u8) -> Result<Self, Error>
+    where
+        R: Read,
+    {
+        CborReader::new(r).read_value_with_first_byte(first)
+    }
+}
+
+impl_decode_from_read_by_prefix!(CborValue);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::cbor::CborValue;
+    use crate::test::{test_decode_from_read_prefix, test_io};
+    use crate::{EncodedLen, Error};
+
+    #[test]
+    fn cbor_value() {
+        let test_cases: &[(CborValue, &[u8])] = &[
+            (CborValue::from(0u64), b"\x00"),
+            (CborValue::from(23u64), b"\x17"),
+            (CborValue::from(24u64), b"\x18\x18"),
+            (CborValue::from(1_000u64), b"\x19\x03\xE8"),
+            (CborValue::from(1_000_000u64), b"\x1A\x00\x0F\x42\x40"),
+            (
+                CborValue::from(u64::MAX),
+                b"\x1B\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+            ),
+            (CborValue::from(-1i64), b"\x20"),
+            (CborValue::from(-1_000i64), b"\x39\x03\xE7"),
+            (
+                CborValue::Negative(u64::MAX),
+                b"\x3B\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+            ),
+            (CborValue::from(0.0), b"\xF9\x00\x00"),
+            (CborValue::from(-0.0), b"\xF9\x80\x00"),
+            (CborValue::from(1.5), b"\xF9\x3E\x00"),
+            (CborValue::from(65504.0), b"\xF9\x7B\xFF"),
+            (CborValue::from(5.960464477539063e-8), b"\xF9\x00\x01"),
+            (CborValue::from(100000.0), b"\xFA\x47\xC3\x50\x00"),
+            (
+                CborValue::from(1.1),
+                b"\xFB\x3F\xF1\x99\x99\x99\x99\x99\x9A",
+            ),
+            (CborValue::from(f64::INFINITY), b"\xF9\x7C\x00"),
+            (CborValue::from(f64::NEG_INFINITY), b"\xF9\xFC\x00"),
+            (CborValue::from(false), b"\xF4"),
+            (CborValue::from(true), b"\xF5"),
+            (CborValue::Null, b"\xF6"),
+            (CborValue::Undefined, b"\xF7"),
+            (CborValue::Simple(16), b"\xF0"),
+            (CborValue::Simple(255), b"\xF8\xFF"),
+            (CborValue::from(Vec::default()), b"\x40"),
+            (CborValue::from(vec![1, 2, 3, 4]), b"\x44\x01\x02\x03\x04"),
+            (CborValue::from(""), b"\x60"),
+            (CborValue::from("IETF"), b"\x64IETF"),
+            (CborValue::from("\u{00FC}"), b"\x62\xC3\xBC"),
+            (CborValue::Array(Vec::default()), b"\x80"),
+            (
+                CborValue::Array(vec![
+                    CborValue::from(1u64),
+                    CborValue::Array(vec![CborValue::from(2u64), CborValue::from(3u64)]),
+                    CborValue::Array(vec![CborValue::from(4u64), CborValue::from(5u64)]),
+                ]),
+                b"\x83\x01\x82\x02\x03\x82\x04\x05",
+            ),
+            (CborValue::Map(Vec::default()), b"\xA0"),
+            (
+                CborValue::Map(vec![
+                    (CborValue::from("a"), CborValue::from(1u64)),
+                    (
+                        CborValue::from("b"),
+                        CborValue::Array(vec![CborValue::from(2u64), CborValue::from(3u64)]),
+                    ),
+                ]),
+                b"\xA2\x61a\x01\x61b\x82\x02\x03",
+            ),
+            (
+                CborValue::Tag(0, Box::new(CborValue::from("2013-03-21T20:04:00Z"))),
+                b"\xC0\x742013-03-21T20:04:00Z",
+            ),
+            (
+                CborValue::Tag(32, Box::new(CborValue::from("http://www.example.com"))),
+                b"\xD8\x20\x76http://www.example.com",
+            ),
+        ];
+
+        for (value, encoded) in test_cases {
+            test_io(value, encoded, false);
+        }
+    }
+
+    #[test]
+    fn cbor_value_invalid_simple() {
+        use crate::EncodeToSlice;
+
+        for value in [20, 21, 22, 23, 24, 31] {
+            let value: CborValue = CborValue::Simple(value);
+            assert!(matches!(
+                value.encoded_len(),
+                Err(Error::InvalidEncodedData { .. })
+            ));
+            assert!(matches!(
+                value.encode_as_vec(),
+                Err(Error::InvalidEncodedData { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn decode_indefinite() {
+        let test_cases: &[(&[u8], CborValue)] = &[
+            (
+                b"\x5F\x42\x01\x02\x43\x03\x04\x05\xFF",
+                CborValue::from(vec![1, 2, 3, 4, 5]),
+            ),
+            (b"\x7F\x65strea\x64ming\xFF", CborValue::from("streaming")),
+            (b"\x9F\xFF", CborValue::Array(Vec::default())),
+            (
+                b"\x9F\x01\x82\x02\x03\x9F\x04\x05\xFF\xFF",
+                CborValue::Array(vec![
+                    CborValue::from(1u64),
+                    CborValue::Array(vec![CborValue::from(2u64), CborValue::from(3u64)]),
+                    CborValue::Array(vec![CborValue::from(4u64), CborValue::from(5u64)]),
+                ]),
+            ),
+            (
+                b"\xBF\x61a\x01\x61b\x9F\x02\x03\xFF\xFF",
+                CborValue::Map(vec![
+                    (CborValue::from("a"), CborValue::from(1u64)),
+                    (
+                        CborValue::from("b"),
+                        CborValue::Array(vec![CborValue::from(2u64), CborValue::from(3u64)]),
+                    ),
+                ]),
+            ),
+        ];
+
+        for (encoded, value) in test_cases {
+            test_decode_from_read_prefix(encoded, value, false);
+        }
+    }
+
+    #[test]
+    fn nan() -> Result<(), crate::Error> {
+        use crate::{DecodeFromRead, EncodeToSlice};
+
+        let encoded: Vec<u8> = CborValue::from(f64::NAN).encode_as_vec()?;
+        assert_eq!(encoded, b"\xF9\x7E\x00");
+        let test_cases: &[&[u8]] = &[
+            b"\xF9\x7E\x00",
+            b"\xFA\x7F\xC0\x00\x00",
+            b"\xFB\x7F\xF8\x00\x00\x00\x00\x00\x00",
+        ];
+        for encoded in test_cases {
+            let value: CborValue = CborValue::decode_from_read(&mut &encoded[..])?;
+            assert!(matches!(value, CborValue::Float(value) if value.is_nan()));
+        }
+        Ok(())
+    }
+}
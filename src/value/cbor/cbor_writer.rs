@@ -0,0 +1,440 @@
+use crate::Error;
+use crate::Error::InvalidEncodedData;
+use crate::cbor::CborValue;
+use crate::cbor::float::{f64_to_f16, f64_to_f32};
+use crate::cbor::header::{
+    ARRAY, BREAK, BYTES, EIGHT_BYTES, FOUR_BYTES, INDEFINITE, MAP, MAX_HEADER_LEN, NEGATIVE,
+    ONE_BYTE, SIMPLE, TAG, TEXT, TWO_BYTES, UNSIGNED, encode_header,
+};
+use std::io::Write;
+
+/// Responsible for writing CBOR data items to a `Write`.
+///
+/// Each write function returns the number of bytes written. Integer arguments and lengths are
+/// always written in their shortest form.
+///
+/// # Deterministic Mode
+/// A deterministic writer follows the core deterministic encoding requirements of RFC 8949 §4.2:
+/// - Floats are written in the shortest form that preserves their value.
+/// - Indefinite-length items are rejected with an `InvalidEncodedData` error.
+/// - Map entries written with `write_value` are sorted by their encoded keys.
+///
+/// Maps written item-by-item must be given in sorted key order by the caller.
+#[derive(Debug)]
+pub struct CborWriter<W> {
+    w: W,
+    deterministic: bool,
+}
+
+impl<W: Write> CborWriter<W> {
+    //! Construction
+
+    /// Creates a new CBOR writer.
+    pub fn new(w: W) -> Self {
+        Self {
+            w,
+            deterministic: false,
+        }
+    }
+
+    /// Creates a new deterministic CBOR writer.
+    pub fn deterministic(w: W) -> Self {
+        Self {
+            w,
+            deterministic: true,
+        }
+    }
+
+    /// Gets the underlying `Write`.
+    pub fn into_inner(self) -> W {
+        self.w
+    }
+}
+
+impl<W: Write> CborWriter<W> {
+    //! Properties
+
+    /// Checks if the writer is in deterministic mode.
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+}
+
+impl<W: Write> CborWriter<W> {
+    //! Headers
+
+    /// Writes the header with the `major` type and the `argument`.
+    fn write_header(&mut self, major: u8, argument: u64) -> Result<usize, Error> {
+        let mut buffer: [u8; MAX_HEADER_LEN] = [0u8; MAX_HEADER_LEN];
+        let len: usize = encode_header(major, argument, &mut buffer);
+        self.w.write_all(&buffer[..len])?;
+        Ok(len)
+    }
+
+    /// Writes the indefinite-length header with the `major` type.
+    fn write_indefinite(&mut self, major: u8) -> Result<usize, Error> {
+        if self.deterministic {
+            Err(InvalidEncodedData {
+                reason: Some("indefinite-length item in deterministic mode".into()),
+            })
+        } else {
+            self.w.write_all(&[(major << 5) | INDEFINITE])?;
+            Ok(1)
+        }
+    }
+}
+
+impl<W: Write> CborWriter<W> {
+    //! Integers
+
+    /// Writes an unsigned integer.
+    pub fn write_unsigned(&mut self, value: u64) -> Result<usize, Error> {
+        self.write_header(UNSIGNED, value)
+    }
+
+    /// Writes the negative integer: `-1 - n`.
+    pub fn write_negative(&mut self, n: u64) -> Result<usize, Error> {
+        self.write_header(NEGATIVE, n)
+    }
+
+    /// Writes a signed integer.
+    pub fn write_int(&mut self, value: i64) -> Result<usize, Error> {
+        if value < 0 {
+            self.write_negative(!value as u64)
+        } else {
+            self.write_unsigned(value as u64)
+        }
+    }
+}
+
+impl<W: Write> CborWriter<W> {
+    //! Strings
+
+    /// Writes a definite-length byte string.
+    pub fn write_bytes(&mut self, value: &[u8]) -> Result<usize, Error> {
+        let header_len: usize = self.write_header(BYTES, value.len() as u64)?;
+        self.w.write_all(value)?;
+        Ok(header_len + value.len())
+    }
+
+    /// Writes the start of an indefinite-length byte string.
+    ///
+    /// The chunks must be written with `write_bytes` and followed by `write_break`.
+    pub fn write_bytes_start(&mut self) -> Result<usize, Error> {
+        self.write_indefinite(BYTES)
+    }
+
+    /// Writes a definite-length text string.
+    pub fn write_text(&mut self, value: &str) -> Result<usize, Error> {
+        let header_len: usize = self.write_header(TEXT, value.len() as u64)?;
+        self.w.write_all(value.as_bytes())?;
+        Ok(header_len + value.len())
+    }
+
+    /// Writes the start of an indefinite-length text string.
+    ///
+    /// The chunks must be written with `write_text` and followed by `write_break`.
+    pub fn write_text_start(&mut self) -> Result<usize, Error> {
+        self.write_indefinite(TEXT)
+    }
+}
+
+impl<W: Write> CborWriter<W> {
+    //! Containers
+
+    /// Writes the start of an array with the `len` items.
+    ///
+    /// A `len` of `None` starts an indefinite-length array that must be followed by `write_break`.
+    pub fn write_array_start(&mut self, len: Option<u64>) -> Result<usize, Error> {
+        match len {
+            Some(len) => self.write_header(ARRAY, len),
+            None => self.write_indefinite(ARRAY),
+        }
+    }
+
+    /// Writes the start of a map with the `len` key-value pairs.
+    ///
+    /// A `len` of `None` starts an indefinite-length map that must be followed by `write_break`.
+    pub fn write_map_start(&mut self, len: Option<u64>) -> Result<usize, Error> {
+        match len {
+            Some(len) => self.write_header(MAP, len),
+            None => self.write_indefinite(MAP),
+        }
+    }
+
+    /// Writes the break that ends an indefinite-length item.
+    pub fn write_break(&mut self) -> Result<usize, Error> {
+        self.w.write_all(&[BREAK])?;
+        Ok(1)
+    }
+
+    /// Writes a tag for the following item.
+    pub fn write_tag(&mut self, tag: u64) -> Result<usize, Error> {
+        self.write_header(TAG, tag)
+    }
+}
+
+impl<W: Write> CborWriter<W> {
+    //! Simple Values
+
+    /// Writes a simple value.
+    ///
+    /// Returns an `InvalidEncodedData` error for the values with dedicated writers: `[20, 23]`, and
+    /// for the reserved values: `[24, 31]`.
+    pub fn write_simple(&mut self, value: u8) -> Result<usize, Error> {
+        match value {
+            0..20 => self.write_simple_byte(value),
+            20..32 => Err(InvalidEncodedData {
+                reason: Some(format!("reserved simple value: {value}").into()),
+            }),
+            _ => {
+                self.w.write_all(&[(SIMPLE << 5) | ONE_BYTE, value])?;
+                Ok(2)
+            }
+        }
+    }
+
+    /// Writes the single byte simple `value`. (`value` < 24)
+    fn write_simple_byte(&mut self, value: u8) -> Result<usize, Error> {
+        self.w.write_all(&[(SIMPLE << 5) | value])?;
+        Ok(1)
+    }
+
+    /// Writes a boolean value.
+    pub fn write_bool(&mut self, value: bool) -> Result<usize, Error> {
+        self.write_simple_byte(if value { 21 } else { 20 })
+    }
+
+    /// Writes the `null` value.
+    pub fn write_null(&mut self) -> Result<usize, Error> {
+        self.write_simple_byte(22)
+    }
+
+    /// Writes the `undefined` value.
+    pub fn write_undefined(&mut self) -> Result<usize, Error> {
+        self.write_simple_byte(23)
+    }
+}
+
+impl<W: Write> CborWriter<W> {
+    //! Floats
+
+    /// Writes the float `bytes` with the `additional` info.
+    fn write_float_bytes<const N: usize>(
+        &mut self,
+        additional: u8,
+        bytes: [u8; N],
+    ) -> Result<usize, Error> {
+        self.w.write_all(&[(SIMPLE << 5) | additional])?;
+        self.w.write_all(&bytes)?;
+        Ok(1 + N)
+    }
+
+    /// Writes the half-precision float `bits`.
+    pub fn write_f16_bits(&mut self, bits: u16) -> Result<usize, Error> {
+        self.write_float_bytes(TWO_BYTES, bits.to_be_bytes())
+    }
+
+    /// Writes a single-precision float.
+    ///
+    /// In deterministic mode the shortest form that preserves the value is written.
+    pub fn write_f32(&mut self, value: f32) -> Result<usize, Error> {
+        if self.deterministic {
+            self.write_float(value as f64)
+        } else {
+            self.write_float_bytes(FOUR_BYTES, value.to_be_bytes())
+        }
+    }
+
+    /// Writes a double-precision float.
+    ///
+    /// In deterministic mode the shortest form that preserves the value is written.
+    pub fn write_f64(&mut self, value: f64) -> Result<usize, Error> {
+        if self.deterministic {
+            self.write_float(value)
+        } else {
+            self.write_float_bytes(EIGHT_BYTES, value.to_be_bytes())
+        }
+    }
+
+    /// Writes a float in the shortest form that preserves its value.
+    ///
+    /// All NaN values are written as the half-precision quiet NaN: `0xF97E00`.
+    pub fn write_float(&mut self, value: f64) -> Result<usize, Error> {
+        if let Some(bits) = f64_to_f16(value) {
+            self.write_f16_bits(bits)
+        } else if let Some(value) = f64_to_f32(value) {
+            self.write_float_bytes(FOUR_BYTES, value.to_be_bytes())
+        } else {
+            self.write_float_bytes(EIGHT_BYTES, value.to_be_bytes())
+        }
+    }
+}
+
+impl<W: Write> CborWriter<W> {
+    //! Values
+
+    /// Writes the `value` with definite lengths.
+    ///
+    /// In deterministic mode the map entries are sorted by their encoded keys.
+    pub fn write_value(&mut self, value: &CborValue) -> Result<usize, Error> {
+        match value {
+            CborValue::Unsigned(value) => self.write_unsigned(*value),
+            CborValue::Negative(n) => self.write_negative(*n),
+            CborValue::Bytes(value) => self.write_bytes(value),
+            CborValue::Text(value) => self.write_text(value),
+            CborValue::Array(items) => {
+                let mut len: usize = self.write_array_start(Some(items.len() as u64))?;
+                for item in items {
+                    len += self.write_value(item)?;
+                }
+                Ok(len)
+            }
+            CborValue::Map(entries) => {
+                let mut len: usize = self.write_map_start(Some(entries.len() as u64))?;
+                if self.deterministic {
+                    let mut sorted: Vec<(Vec<u8>, &CborValue)> = entries
+                        .iter()
+                        .map(|(key, value)| {
+                            let mut w: CborWriter<Vec<u8>> =
+                                CborWriter::deterministic(Vec::default());
+                            w.write_value(key)?;
+                            Ok((w.into_inner(), value))
+                        })
+                        .collect::<Result<_, Error>>()?;
+                    sorted.sort_by(|a, b| a.0.cmp(&b.0));
+                    for (key, value) in sorted {
+                        self.w.write_all(&key)?;
+                        len += key.len();
+                        len += self.write_value(value)?;
+                    }
+                } else {
+                    for (key, value) in entries {
+                        len += self.write_value(key)?;
+                        len += self.write_value(value)?;
+                    }
+                }
+                Ok(len)
+            }
+            CborValue::Tag(tag, value) => Ok(self.write_tag(*tag)? + self.write_value(value)?),
+            CborValue::Simple(value) => self.write_simple(*value),
+            CborValue::Bool(value) => self.write_bool(*value),
+            CborValue::Null => self.write_null(),
+            CborValue::Undefined => self.write_undefined(),
+            CborValue::Float(value) => self.write_float(*value),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::Error;
+    use crate::cbor::{CborValue, CborWriter};
+
+    #[test]
+    fn write_indefinite() -> Result<(), Error> {
+        let mut w: CborWriter<Vec<u8>> = CborWriter::new(Vec::default());
+        w.write_array_start(None)?;
+        w.write_unsigned(1)?;
+        w.write_array_start(Some(2))?;
+        w.write_unsigned(2)?;
+        w.write_unsigned(3)?;
+        w.write_array_start(None)?;
+        w.write_unsigned(4)?;
+        w.write_unsigned(5)?;
+        w.write_break()?;
+        w.write_break()?;
+        w.write_text_start()?;
+        w.write_text("strea")?;
+        w.write_text("ming")?;
+        w.write_break()?;
+        assert_eq!(
+            w.into_inner().as_slice(),
+            b"\x9F\x01\x82\x02\x03\x9F\x04\x05\xFF\xFF\x7F\x65strea\x64ming\xFF"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn write_floats() -> Result<(), Error> {
+        let mut w: CborWriter<Vec<u8>> = CborWriter::new(Vec::default());
+        w.write_f32(1.5)?;
+        w.write_f64(1.5)?;
+        w.write_float(1.5)?;
+        assert_eq!(
+            w.into_inner().as_slice(),
+            b"\xFA\x3F\xC0\x00\x00\xFB\x3F\xF8\x00\x00\x00\x00\x00\x00\xF9\x3E\x00"
+        );
+
+        let mut w: CborWriter<Vec<u8>> = CborWriter::deterministic(Vec::default());
+        w.write_f32(1.5)?;
+        w.write_f64(1.5)?;
+        w.write_f64(100000.0)?;
+        w.write_f64(1.1)?;
+        assert_eq!(
+            w.into_inner().as_slice(),
+            b"\xF9\x3E\x00\xF9\x3E\x00\xFA\x47\xC3\x50\x00\xFB\x3F\xF1\x99\x99\x99\x99\x99\x9A"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn write_simple() -> Result<(), Error> {
+        let mut w: CborWriter<Vec<u8>> = CborWriter::new(Vec::default());
+        w.write_simple(16)?;
+        w.write_simple(255)?;
+        for value in [20, 23, 24, 31] {
+            assert!(matches!(
+                w.write_simple(value),
+                Err(Error::InvalidEncodedData { .. })
+            ));
+        }
+        w.write_bool(false)?;
+        w.write_undefined()?;
+        assert_eq!(w.into_inner().as_slice(), b"\xF0\xF8\xFF\xF4\xF7");
+        Ok(())
+    }
+
+    #[test]
+    fn deterministic() -> Result<(), Error> {
+        let value: CborValue = CborValue::Map(vec![
+            (CborValue::from("aa"), CborValue::Null),
+            (CborValue::from(-1i64), CborValue::Null),
+            (CborValue::from("b"), CborValue::Null),
+            (CborValue::from(10u64), CborValue::Null),
+            (CborValue::from(100u64), CborValue::Null),
+        ]);
+
+        let mut w: CborWriter<Vec<u8>> = CborWriter::deterministic(Vec::default());
+        w.write_value(&value)?;
+        assert_eq!(
+            w.into_inner().as_slice(),
+            b"\xA5\x0A\xF6\x18\x64\xF6\x20\xF6\x61b\xF6\x62aa\xF6"
+        );
+
+        let mut w: CborWriter<Vec<u8>> = CborWriter::deterministic(Vec::default());
+        assert!(matches!(
+            w.write_map_start(None),
+            Err(Error::InvalidEncodedData { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn deterministic_map_key() -> Result<(), Error> {
+        let key: CborValue = CborValue::Map(vec![
+            (CborValue::from("b"), CborValue::Null),
+            (CborValue::from("a"), CborValue::Float(1.5)),
+        ]);
+        let value: CborValue = CborValue::Map(vec![(key, CborValue::Null)]);
+
+        let mut w: CborWriter<Vec<u8>> = CborWriter::deterministic(Vec::default());
+        w.write_value(&value)?;
+        assert_eq!(
+            w.into_inner().as_slice(),
+            b"\xA1\xA2\x61a\xF9\x3E\x00\x61b\xF6\xF6"
+        );
+        Ok(())
+    }
+}
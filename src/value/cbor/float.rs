@@ -0,0 +1,108 @@
+/// Converts the half-precision float `bits` to an `f64`.
+pub fn f16_to_f64(bits: u16) -> f64 {
+    let sign: f64 = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent: i32 = ((bits >> 10) & 0x1F) as i32;
+    let mantissa: f64 = (bits & 0x03FF) as f64;
+    sign * match exponent {
+        0 => mantissa * 2f64.powi(-24),
+        0x1F => {
+            if mantissa == 0.0 {
+                f64::INFINITY
+            } else {
+                f64::NAN
+            }
+        }
+        _ => (1024.0 + mantissa) * 2f64.powi(exponent - 25),
+    }
+}
+
+/// Converts the `value` to half-precision float bits.
+///
+/// Returns `None` if the `value` cannot be represented exactly. All NaN values are converted to
+/// the canonical quiet NaN: `0x7E00`.
+pub fn f64_to_f16(value: f64) -> Option<u16> {
+    let bits: u64 = value.to_bits();
+    let sign: u16 = ((bits >> 48) & 0x8000) as u16;
+    let exponent: i32 = ((bits >> 52) & 0x7FF) as i32 - 1023;
+    let mantissa: u64 = bits & ((1 << 52) - 1);
+    if value.is_nan() {
+        Some(0x7E00)
+    } else if value.is_infinite() {
+        Some(sign | 0x7C00)
+    } else if value == 0.0 {
+        Some(sign)
+    } else if (-14..=15).contains(&exponent) {
+        if mantissa & ((1 << 42) - 1) == 0 {
+            Some(sign | (((exponent + 15) as u16) << 10) | (mantissa >> 42) as u16)
+        } else {
+            None
+        }
+    } else if (-24..-14).contains(&exponent) {
+        let shift: i32 = 28 - exponent;
+        let significand: u64 = (1 << 52) | mantissa;
+        if significand & ((1 << shift) - 1) == 0 {
+            Some(sign | (significand >> shift) as u16)
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+/// Converts the `value` to an `f32`.
+///
+/// Returns `None` if the `value` cannot be represented exactly.
+pub fn f64_to_f32(value: f64) -> Option<f32> {
+    let converted: f32 = value as f32;
+    if converted as f64 == value || value.is_nan() {
+        Some(converted)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cbor::float::{f16_to_f64, f64_to_f16, f64_to_f32};
+
+    #[test]
+    fn f16() {
+        let test_cases: &[(f64, Option<u16>)] = &[
+            (0.0, Some(0x0000)),
+            (-0.0, Some(0x8000)),
+            (1.0, Some(0x3C00)),
+            (1.5, Some(0x3E00)),
+            (-4.0, Some(0xC400)),
+            (65504.0, Some(0x7BFF)),
+            (5.960464477539063e-8, Some(0x0001)),
+            (0.00006103515625, Some(0x0400)),
+            (f64::INFINITY, Some(0x7C00)),
+            (f64::NEG_INFINITY, Some(0xFC00)),
+            (1.1, None),
+            (65536.0, None),
+            (2.98e-8, None),
+        ];
+
+        for (value, expected) in test_cases {
+            assert_eq!(f64_to_f16(*value), *expected, "value={value}");
+            if let Some(bits) = expected {
+                assert_eq!(f16_to_f64(*bits), *value);
+                assert_eq!(
+                    f16_to_f64(*bits).is_sign_negative(),
+                    value.is_sign_negative()
+                );
+            }
+        }
+
+        assert_eq!(f64_to_f16(f64::NAN), Some(0x7E00));
+        assert!(f16_to_f64(0x7E00).is_nan());
+    }
+
+    #[test]
+    fn f32() {
+        assert_eq!(f64_to_f32(100000.0), Some(100000.0));
+        assert_eq!(f64_to_f32(1.1), None);
+        assert_eq!(f64_to_f32(1.0e300), None);
+    }
+}
@@ -0,0 +1,102 @@
+/// The unsigned integer major type.
+pub const UNSIGNED: u8 = 0;
+
+/// The negative integer major type.
+pub const NEGATIVE: u8 = 1;
+
+/// The byte string major type.
+pub const BYTES: u8 = 2;
+
+/// The text string major type.
+pub const TEXT: u8 = 3;
+
+/// The array major type.
+pub const ARRAY: u8 = 4;
+
+/// The map major type.
+pub const MAP: u8 = 5;
+
+/// The tag major type.
+pub const TAG: u8 = 6;
+
+/// The simple value & float major type.
+pub const SIMPLE: u8 = 7;
+
+/// The additional info for a 1-byte argument.
+pub const ONE_BYTE: u8 = 24;
+
+/// The additional info for a 2-byte argument. (or a half-precision float)
+pub const TWO_BYTES: u8 = 25;
+
+/// The additional info for a 4-byte argument. (or a single-precision float)
+pub const FOUR_BYTES: u8 = 26;
+
+/// The additional info for an 8-byte argument. (or a double-precision float)
+pub const EIGHT_BYTES: u8 = 27;
+
+/// The additional info for an indefinite length. (or a break)
+pub const INDEFINITE: u8 = 31;
+
+/// The break stop code.
+pub const BREAK: u8 = (SIMPLE << 5) | INDEFINITE;
+
+/// The maximum length of an encoded header.
+pub const MAX_HEADER_LEN: usize = 9;
+
+/// Gets the length of the header with the `argument`.
+pub const fn header_len(argument: u64) -> usize {
+    match argument {
+        0..24 => 1,
+        24..=0xFF => 2,
+        0x100..=0xFFFF => 3,
+        0x1_0000..=0xFFFF_FFFF => 5,
+        _ => 9,
+    }
+}
+
+/// Encodes the header with the `major` type and shortest form `argument` into the `target`.
+///
+/// Returns the length of the encoded header.
+pub fn encode_header(major: u8, argument: u64, target: &mut [u8; MAX_HEADER_LEN]) -> usize {
+    let major: u8 = major << 5;
+    let len: usize = header_len(argument);
+    let bytes: [u8; 8] = argument.to_be_bytes();
+    match len {
+        1 => target[0] = major | argument as u8,
+        2 => target[0] = major | ONE_BYTE,
+        3 => target[0] = major | TWO_BYTES,
+        5 => target[0] = major | FOUR_BYTES,
+        _ => target[0] = major | EIGHT_BYTES,
+    }
+    if len > 1 {
+        target[1..len].copy_from_slice(&bytes[(9 - len)..]);
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cbor::header::{MAX_HEADER_LEN, encode_header, header_len};
+
+    #[test]
+    fn fn_encode_header() {
+        let test_cases: &[(u8, u64, &[u8])] = &[
+            (0, 0, b"\x00"),
+            (0, 23, b"\x17"),
+            (0, 24, b"\x18\x18"),
+            (1, 0xFF, b"\x38\xFF"),
+            (2, 0x100, b"\x59\x01\x00"),
+            (3, 0xFFFF, b"\x79\xFF\xFF"),
+            (4, 0x1_0000, b"\x9A\x00\x01\x00\x00"),
+            (5, 0xFFFF_FFFF, b"\xBA\xFF\xFF\xFF\xFF"),
+            (6, u64::MAX, b"\xDB\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"),
+        ];
+
+        for (major, argument, expected) in test_cases {
+            let mut target: [u8; MAX_HEADER_LEN] = [0u8; MAX_HEADER_LEN];
+            let len: usize = encode_header(*major, *argument, &mut target);
+            assert_eq!(len, header_len(*argument));
+            assert_eq!(&target[..len], *expected);
+        }
+    }
+}
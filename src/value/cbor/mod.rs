@@ -0,0 +1,12 @@
+pub use cbor_item::*;
+pub use cbor_reader::*;
+pub use cbor_value::*;
+pub use cbor_writer::*;
+
+mod cbor_item;
+mod cbor_reader;
+mod cbor_value;
+mod cbor_writer;
+
+pub(in crate::value::cbor) mod float;
+pub(in crate::value::cbor) mod header;
@@ -0,0 +1,94 @@
+use crate::Error::{IntegerOverflow, InvalidEncodedData};
+use crate::{Error, read_single_byte};
+use std::io::Read;
+
+/// Gets the length of the base-128 encoded `value`.
+pub const fn base_128_len(value: u64) -> usize {
+    if value == 0 {
+        1
+    } else {
+        (u64::BITS - value.leading_zeros()).div_ceil(7) as usize
+    }
+}
+
+/// Encodes the `value` in big-endian base-128 with continuation bits into the `target`.
+///
+/// Returns the encoded length. The `target` must have at least `base_128_len(value)` bytes.
+pub fn encode_base_128(value: u64, target: &mut [u8]) -> usize {
+    let len: usize = base_128_len(value);
+    for (i, b) in target[..len].iter_mut().enumerate() {
+        let shift: usize = 7 * (len - 1 - i);
+        let continuation: u8 = if i + 1 < len { 0x80 } else { 0x00 };
+        *b = ((value >> shift) as u8 & 0x7F) | continuation;
+    }
+    len
+}
+
+/// Decodes a big-endian base-128 value with continuation bits from the `Read`.
+///
+/// Returns an `InvalidEncodedData` error if the value has a leading `0x80` byte.
+pub fn decode_base_128<R>(r: &mut R) -> Result<u64, Error>
+where
+    R: Read,
+{
+    let mut value: u64 = 0;
+    let mut first: bool = true;
+    loop {
+        let b: u8 = read_single_byte(r)?;
+        if first && b == 0x80 {
+            return Err(InvalidEncodedData {
+                reason: Some("non-minimal base-128 value".into()),
+            });
+        }
+        first = false;
+        if value >> (u64::BITS - 7) != 0 {
+            return Err(IntegerOverflow);
+        }
+        value = (value << 7) | (b & 0x7F) as u64;
+        if b & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error;
+    use crate::der::base_128::{base_128_len, decode_base_128, encode_base_128};
+
+    #[test]
+    fn base_128() -> Result<(), Error> {
+        let test_cases: &[(u64, &[u8])] = &[
+            (0, b"\x00"),
+            (0x7F, b"\x7F"),
+            (0x80, b"\x81\x00"),
+            (113_549, b"\x86\xF7\x0D"),
+            (u64::MAX, b"\x81\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x7F"),
+        ];
+
+        for (value, encoded) in test_cases {
+            assert_eq!(base_128_len(*value), encoded.len());
+            let mut buffer: [u8; 10] = [0u8; 10];
+            assert_eq!(encode_base_128(*value, &mut buffer), encoded.len());
+            assert_eq!(&buffer[..encoded.len()], *encoded);
+            assert_eq!(decode_base_128(&mut &encoded[..])?, *value);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            decode_base_128(&mut &b"\x80\x01"[..]),
+            Err(Error::InvalidEncodedData { .. })
+        ));
+        assert!(matches!(
+            decode_base_128(&mut &b"\x82\x80\x80\x80\x80\x80\x80\x80\x80\x00"[..]),
+            Err(Error::IntegerOverflow)
+        ));
+        assert!(matches!(
+            decode_base_128(&mut &b"\x81"[..]),
+            Err(Error::Stream(_))
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::Error;
+use crate::Error::InvalidEncodedData;
+use crate::der::{EncodingRules, Tag, UniversalType, impl_universal_type_value};
+
+/// An ASN.1 `BIT STRING`.
+///
+/// The bits are held in big-endian bytes with the unused bits at the end of the last byte. The
+/// unused bits are always zero.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct BitString {
+    unused_bits: u8,
+    bytes: Vec<u8>,
+}
+
+impl From<Vec<u8>> for BitString {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self {
+            unused_bits: 0,
+            bytes,
+        }
+    }
+}
+
+impl BitString {
+    //! Construction
+
+    /// Creates a new bit string with the `unused_bits` at the end of the last byte.
+    ///
+    /// The unused bits are cleared. Returns `None` if the `unused_bits` is greater than 7 or the
+    /// `bytes` are empty and the `unused_bits` is not 0.
+    pub fn new(mut bytes: Vec<u8>, unused_bits: u8) -> Option<Self> {
+        if unused_bits > 7 || (bytes.is_empty() && unused_bits != 0) {
+            return None;
+        }
+        if let Some(last) = bytes.last_mut() {
+            *last &= Self::used_mask(unused_bits);
+        }
+        Some(Self { unused_bits, bytes })
+    }
+
+    /// Gets the mask for the used bits of the last byte.
+    fn used_mask(unused_bits: u8) -> u8 {
+        0xFF << unused_bits
+    }
+}
+
+impl BitString {
+    //! Properties
+
+    /// Gets the number of unused bits at the end of the last byte.
+    pub fn unused_bits(&self) -> u8 {
+        self.unused_bits
+    }
+
+    /// Gets the bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Gets the number of bits.
+    pub fn bit_len(&self) -> usize {
+        self.bytes.len() * 8 - self.unused_bits as usize
+    }
+}
+
+impl UniversalType for BitString {
+    const TAG: Tag = Tag::BIT_STRING;
+
+    fn contents_len(&self) -> Result<usize, Error> {
+        Ok(1 + self.bytes.len())
+    }
+
+    fn encode_contents(&self, target: &mut [u8]) -> Result<usize, Error> {
+        target[0] = self.unused_bits;
+        target[1..(1 + self.bytes.len())].copy_from_slice(&self.bytes);
+        Ok(1 + self.bytes.len())
+    }
+
+    fn decode_contents(contents: &[u8], rules: EncodingRules) -> Result<Self, Error> {
+        let (unused_bits, bytes): (u8, &[u8]) = match contents {
+            [unused_bits, bytes @ ..] => (*unused_bits, bytes),
+            [] => {
+                return Err(InvalidEncodedData {
+                    reason: Some("empty bit string".into()),
+                });
+            }
+        };
+        if rules == EncodingRules::Der
+            && let Some(last) = bytes.last()
+            && unused_bits <= 7
+            && *last & !Self::used_mask(unused_bits) != 0
+        {
+            return Err(InvalidEncodedData {
+                reason: Some("non-zero unused bits".into()),
+            });
+        }
+        Self::new(bytes.to_vec(), unused_bits).ok_or_else(|| InvalidEncodedData {
+            reason: Some(format!("invalid unused bits: {unused_bits}").into()),
+        })
+    }
+}
+
+impl_universal_type_value!(BitString);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::der::{BitString, EncodingRules, UniversalType};
+    use crate::test::test_io;
+    use crate::{DecodeFromReadPrefix, Error};
+    use std::io::Cursor;
+
+    #[test]
+    fn bit_string() {
+        let test_cases: &[(BitString, &[u8])] = &[
+            (BitString::from(Vec::default()), b"\x03\x01\x00"),
+            (BitString::from(vec![0xA5]), b"\x03\x02\x00\xA5"),
+            (
+                BitString::new(vec![0x0A, 0x3B, 0x5F, 0x29, 0x1C, 0xD0], 4).unwrap(),
+                b"\x03\x07\x04\x0A\x3B\x5F\x29\x1C\xD0",
+            ),
+        ];
+
+        for (value, encoded) in test_cases {
+            test_io(value, encoded, false);
+        }
+    }
+
+    #[test]
+    fn new() {
+        assert!(BitString::new(vec![0xFF], 8).is_none());
+        assert!(BitString::new(Vec::default(), 1).is_none());
+        let value: BitString = BitString::new(vec![0xFF], 3).unwrap();
+        assert_eq!(value.as_bytes(), [0xF8]);
+        assert_eq!(value.bit_len(), 5);
+    }
+
+    #[test]
+    fn unused_bits() -> Result<(), Error> {
+        let contents: &[u8] = b"\x04\xFF";
+        assert!(BitString::decode_contents(contents, EncodingRules::Der).is_err());
+        let value: BitString = BitString::decode_contents(contents, EncodingRules::Ber)?;
+        assert_eq!(value.as_bytes(), [0xF0]);
+
+        let test_cases: &[&[u8]] = &[b"\x03\x00", b"\x03\x01\x01", b"\x03\x02\x08\x00"];
+        for encoded in test_cases {
+            assert!(matches!(
+                BitString::decode_from_read_prefix(&mut Cursor::new(encoded)),
+                Err(Error::InvalidEncodedData { .. })
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt::{Display, Formatter};
+
+/// The ASN.1 encoding rules used when decoding.
+///
+/// Values are always encoded with the distinguished encoding rules.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+pub enum EncodingRules {
+    /// The basic encoding rules.
+    ///
+    /// Lengths may be encoded in the long form with leading zero bytes. Unused bits in bit strings
+    /// may be set.
+    Ber,
+
+    /// The distinguished encoding rules.
+    ///
+    /// Lengths must be encoded in their shortest form. Unused bits in bit strings must be zero.
+    #[default]
+    Der,
+}
+
+impl Display for EncodingRules {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ber => write!(f, "BER"),
+            Self::Der => write!(f, "DER"),
+        }
+    }
+}
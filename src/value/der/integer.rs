@@ -0,0 +1,203 @@
+use crate::Error;
+use crate::Error::{IntegerOverflow, InvalidEncodedData};
+use crate::der::{EncodingRules, Tag, UniversalType, impl_universal_type_value};
+
+/// An ASN.1 `INTEGER` of any size.
+///
+/// The integer is held as its minimal big-endian two's complement bytes.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Integer {
+    bytes: Vec<u8>,
+}
+
+impl From<i64> for Integer {
+    fn from(value: i64) -> Self {
+        Self::from_minimal(&value.to_be_bytes())
+    }
+}
+
+impl From<u64> for Integer {
+    fn from(value: u64) -> Self {
+        Self::from_unsigned_bytes(&value.to_be_bytes())
+    }
+}
+
+impl Integer {
+    //! Construction
+
+    /// Creates a new non-negative integer from the big-endian unsigned `bytes`.
+    ///
+    /// This is useful for large values such as certificate serial numbers and RSA moduli.
+    pub fn from_unsigned_bytes(bytes: &[u8]) -> Self {
+        let start: usize = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+        let bytes: &[u8] = &bytes[start..];
+        if bytes.first().is_none_or(|b| *b & 0x80 != 0) {
+            let mut padded: Vec<u8> = Vec::with_capacity(bytes.len() + 1);
+            padded.push(0);
+            padded.extend_from_slice(bytes);
+            Self { bytes: padded }
+        } else {
+            Self {
+                bytes: bytes.to_vec(),
+            }
+        }
+    }
+
+    /// Creates a new integer from the big-endian two's complement `bytes` by removing the
+    /// redundant leading bytes.
+    fn from_minimal(bytes: &[u8]) -> Self {
+        let mut start: usize = 0;
+        while start + 1 < bytes.len() && Self::is_redundant(bytes[start], bytes[start + 1]) {
+            start += 1;
+        }
+        Self {
+            bytes: bytes[start..].to_vec(),
+        }
+    }
+
+    /// Checks if the `first` byte is redundant given the `second` byte.
+    fn is_redundant(first: u8, second: u8) -> bool {
+        (first == 0x00 && second & 0x80 == 0) || (first == 0xFF && second & 0x80 != 0)
+    }
+}
+
+impl Integer {
+    //! Properties
+
+    /// Gets the big-endian two's complement bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Checks if the integer is negative.
+    pub fn is_negative(&self) -> bool {
+        self.bytes[0] & 0x80 != 0
+    }
+}
+
+impl Integer {
+    //! Conversions
+
+    /// Converts the integer to an `i64`.
+    ///
+    /// Returns an `IntegerOverflow` error if the integer does not fit.
+    pub fn to_i64(&self) -> Result<i64, Error> {
+        if self.bytes.len() > size_of::<i64>() {
+            return Err(IntegerOverflow);
+        }
+        let fill: u8 = if self.is_negative() { 0xFF } else { 0x00 };
+        let mut buffer: [u8; 8] = [fill; 8];
+        buffer[(8 - self.bytes.len())..].copy_from_slice(&self.bytes);
+        Ok(i64::from_be_bytes(buffer))
+    }
+
+    /// Converts the integer to a `u64`.
+    ///
+    /// Returns an `IntegerOverflow` error if the integer is negative or does not fit.
+    pub fn to_u64(&self) -> Result<u64, Error> {
+        let bytes: &[u8] = if self.bytes.len() > 1 && self.bytes[0] == 0 {
+            &self.bytes[1..]
+        } else {
+            &self.bytes
+        };
+        if self.is_negative() || bytes.len() > size_of::<u64>() {
+            return Err(IntegerOverflow);
+        }
+        let mut buffer: [u8; 8] = [0u8; 8];
+        buffer[(8 - bytes.len())..].copy_from_slice(bytes);
+        Ok(u64::from_be_bytes(buffer))
+    }
+}
+
+impl UniversalType for Integer {
+    const TAG: Tag = Tag::INTEGER;
+
+    fn contents_len(&self) -> Result<usize, Error> {
+        Ok(self.bytes.len())
+    }
+
+    fn encode_contents(&self, target: &mut [u8]) -> Result<usize, Error> {
+        target[..self.bytes.len()].copy_from_slice(&self.bytes);
+        Ok(self.bytes.len())
+    }
+
+    fn decode_contents(contents: &[u8], _rules: EncodingRules) -> Result<Self, Error> {
+        match contents {
+            [] => Err(InvalidEncodedData {
+                reason: Some("empty integer".into()),
+            }),
+            [first, second, ..] if Self::is_redundant(*first, *second) => Err(InvalidEncodedData {
+                reason: Some("non-minimal integer".into()),
+            }),
+            _ => Ok(Self {
+                bytes: contents.to_vec(),
+            }),
+        }
+    }
+}
+
+impl_universal_type_value!(Integer);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::der::Integer;
+    use crate::test::test_io;
+    use crate::{DecodeFromReadPrefix, Error};
+    use std::io::Cursor;
+
+    #[test]
+    fn integer() -> Result<(), Error> {
+        let test_cases: &[(i64, &[u8])] = &[
+            (0, b"\x02\x01\x00"),
+            (127, b"\x02\x01\x7F"),
+            (128, b"\x02\x02\x00\x80"),
+            (256, b"\x02\x02\x01\x00"),
+            (-1, b"\x02\x01\xFF"),
+            (-128, b"\x02\x01\x80"),
+            (-129, b"\x02\x02\xFF\x7F"),
+            (i64::MAX, b"\x02\x08\x7F\xFF\xFF\xFF\xFF\xFF\xFF\xFF"),
+            (i64::MIN, b"\x02\x08\x80\x00\x00\x00\x00\x00\x00\x00"),
+        ];
+
+        for (value, encoded) in test_cases {
+            let integer: Integer = Integer::from(*value);
+            test_io(&integer, encoded, false);
+            assert_eq!(integer.to_i64()?, *value);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unsigned() -> Result<(), Error> {
+        let integer: Integer = Integer::from(u64::MAX);
+        test_io(
+            &integer,
+            b"\x02\x09\x00\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+            false,
+        );
+        assert_eq!(integer.to_u64()?, u64::MAX);
+        assert!(matches!(integer.to_i64(), Err(Error::IntegerOverflow)));
+        assert!(matches!(
+            Integer::from(-1i64).to_u64(),
+            Err(Error::IntegerOverflow)
+        ));
+
+        let serial: Integer = Integer::from_unsigned_bytes(&[0x00, 0x00, 0x8F, 0x01]);
+        assert_eq!(serial.as_bytes(), [0x00, 0x8F, 0x01]);
+        assert_eq!(Integer::from_unsigned_bytes(&[]).as_bytes(), [0x00]);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let test_cases: &[&[u8]] = &[b"\x02\x00", b"\x02\x02\x00\x7F", b"\x02\x02\xFF\x80"];
+
+        for encoded in test_cases {
+            assert!(matches!(
+                Integer::decode_from_read_prefix(&mut Cursor::new(encoded)),
+                Err(Error::InvalidEncodedData { .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::Error::{IntegerOverflow, InvalidEncodedData};
+use crate::der::EncodingRules;
+use crate::{Error, impl_decode_from_read_by_prefix, impl_encode_to_write_stack_buf};
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+
+/// An ASN.1 definite length.
+///
+/// # Format
+/// - `[0x00, 0x7F]`: the short form with the length as a single byte.
+/// - `0x80 | n`: the long form with the length in the following `n` big-endian bytes.
+///
+/// The indefinite length `0x80` and the reserved value `0xFF` are not supported.
+///
+/// # Canonical Form
+/// Lengths are always encoded in their shortest form. Decoding with the DER rules (used by the
+/// value traits) will fail for lengths not in their shortest form.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Length {
+    value: usize,
+}
+
+impl From<usize> for Length {
+    fn from(value: usize) -> Self {
+        Self { value }
+    }
+}
+
+impl From<&usize> for Length {
+    fn from(value: &usize) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl Length {
+    //! Constants
+
+    /// The maximum length of an encoded length.
+    pub const MAX_ENCODED_LEN: usize = 1 + size_of::<usize>();
+
+    /// The long form bit.
+    const LONG_FORM: u8 = 0x80;
+
+    /// The reserved first byte.
+    const RESERVED: u8 = 0xFF;
+}
+
+impl Length {
+    //! Properties
+
+    /// Gets the value.
+    pub fn value(self) -> usize {
+        self.value
+    }
+}
+
+impl Display for Length {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl crate::EncodedLen for Length {
+    fn encoded_len(&self) -> Result<usize, Error> {
+        if self.value < Self::LONG_FORM as usize {
+            Ok(1)
+        } else {
+            Ok(1 + (usize::BITS - self.value.leading_zeros()).div_ceil(8) as usize)
+        }
+    }
+}
+
+impl crate::EncodeToSlice for Length {
+    unsafe fn encode_to_slice_unchecked(&self, target: &mut [u8]) -> Result<usize, Error> {
+        if self.value < Self::LONG_FORM as usize {
+            target[0] = self.value as u8;
+            Ok(1)
+        } else {
+            let len: usize = (usize::BITS - self.value.leading_zeros()).div_ceil(8) as usize;
+            let bytes: [u8; size_of::<usize>()] = self.value.to_be_bytes();
+            target[0] = Self::LONG_FORM | len as u8;
+            target[1..(1 + len)].copy_from_slice(&bytes[(bytes.len() - len)..]);
+            Ok(1 + len)
+        }
+    }
+}
+
+impl_encode_to_write_stack_buf!(Length, Self::MAX_ENCODED_LEN);
+
+impl Length {
+    //! Decoding
+
+    /// Decodes a length from the `Read` prefix given the `first` byte and the `rules`.
+    pub fn decode_from_read_prefix_with_rules<R>(
+        r: &mut R,
+        first: u8,
+        rules: EncodingRules,
+    ) -> Result<Self, Error>
+    where
+        R: Read,
+    {
+        if first < Self::LONG_FORM {
+            return Ok(Self::from(first as usize));
+        } else if first == Self::LONG_FORM {
+            return Err(InvalidEncodedData {
+                reason: Some("indefinite length".into()),
+            });
+        } else if first == Self::RESERVED {
+            return Err(InvalidEncodedData {
+                reason: Some("reserved length: 0xFF".into()),
+            });
+        }
+
+        let len: usize = (first & !Self::LONG_FORM) as usize;
+        let mut value: usize = 0;
+        for i in 0..len {
+            let mut b: [u8; 1] = [0u8; 1];
+            r.read_exact(&mut b)?;
+            if rules == EncodingRules::Der && i == 0 && b[0] == 0 {
+                return Err(InvalidEncodedData {
+                    reason: Some("non-minimal length: leading zero byte".into()),
+                });
+            }
+            if value >> (usize::BITS - 8) != 0 {
+                return Err(IntegerOverflow);
+            }
+            value = (value << 8) | b[0] as usize;
+        }
+        if rules == EncodingRules::Der && value < Self::LONG_FORM as usize {
+            return Err(InvalidEncodedData {
+                reason: Some(format!("non-minimal length: {value} in the long form").into()),
+            });
+        }
+        Ok(Self::from(value))
+    }
+}
+
+impl crate::DecodeFromReadPrefix for Length {
+    fn decode_from_read_prefix_with_first_byte<R>(r: &mut R, first: u8) -> Result<Self, Error>
+    where
+        R: Read,
+    {
+        Self::decode_from_read_prefix_with_rules(r, first, EncodingRules::Der)
+    }
+}
+
+impl_decode_from_read_by_prefix!(Length);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::der::{EncodingRules, Length};
+    use crate::test::test_io;
+    use crate::{DecodeFromReadPrefix, Error, read_single_byte};
+    use std::io::Cursor;
+
+    #[test]
+    fn length() {
+        let test_cases: &[(usize, &[u8])] = &[
+            (0, b"\x00"),
+            (0x7F, b"\x7F"),
+            (0x80, b"\x81\x80"),
+            (0xFF, b"\x81\xFF"),
+            (0x100, b"\x82\x01\x00"),
+            (0x1_0000, b"\x83\x01\x00\x00"),
+        ];
+
+        for (value, encoded) in test_cases {
+            test_io(&Length::from(value), encoded, false);
+        }
+    }
+
+    #[test]
+    fn non_minimal() -> Result<(), Error> {
+        let test_cases: &[(&[u8], usize)] = &[
+            (b"\x81\x01", 1),
+            (b"\x81\x7F", 0x7F),
+            (b"\x82\x00\x80", 0x80),
+            (b"\x84\x00\x00\x01\x00", 0x100),
+        ];
+
+        for (encoded, value) in test_cases {
+            assert!(matches!(
+                Length::decode_from_read_prefix(&mut Cursor::new(encoded)),
+                Err(Error::InvalidEncodedData { .. })
+            ));
+
+            let mut r: Cursor<&[u8]> = Cursor::new(encoded);
+            let first: u8 = read_single_byte(&mut r)?;
+            let length: Length =
+                Length::decode_from_read_prefix_with_rules(&mut r, first, EncodingRules::Ber)?;
+            assert_eq!(length.value(), *value);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let test_cases: &[&[u8]] = &[b"\x80", b"\xFF", b"\x82\x01"];
+
+        for encoded in test_cases {
+            assert!(Length::decode_from_read_prefix(&mut Cursor::new(encoded)).is_err());
+        }
+    }
+}
@@ -0,0 +1,27 @@
+pub use bit_string::*;
+pub use encoding_rules::*;
+pub use integer::*;
+pub use length::*;
+pub use object_identifier::*;
+pub use octet_string::*;
+pub use sequence::*;
+pub use tag::*;
+pub use tag_class::*;
+pub use tlv::*;
+pub use universal_type::*;
+pub use utf8_string::*;
+
+mod bit_string;
+mod encoding_rules;
+mod integer;
+mod length;
+mod object_identifier;
+mod octet_string;
+mod sequence;
+mod tag;
+mod tag_class;
+mod tlv;
+mod universal_type;
+mod utf8_string;
+
+pub(in crate::value::der) mod base_128;
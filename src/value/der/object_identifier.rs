@@ -0,0 +1,163 @@
+use crate::Error;
+use crate::Error::InvalidEncodedData;
+use crate::der::base_128::{base_128_len, decode_base_128, encode_base_128};
+use crate::der::{EncodingRules, Tag, UniversalType, impl_universal_type_value};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// An ASN.1 `OBJECT IDENTIFIER`. (ex: `1.2.840.113549`)
+///
+/// # Format
+/// The first two arcs are combined as `40 * first + second`. Each combined arc and each remaining
+/// arc is encoded in base-128 with continuation bits.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct ObjectIdentifier {
+    arcs: Vec<u64>,
+}
+
+impl ObjectIdentifier {
+    //! Construction
+
+    /// Creates a new object identifier.
+    ///
+    /// Returns `None` if there are fewer than two `arcs`, the first arc is greater than 2, the
+    /// second arc is greater than 39 when the first arc is 0 or 1, or the combined first two arcs
+    /// overflow a `u64`.
+    pub fn new(arcs: Vec<u64>) -> Option<Self> {
+        match arcs.as_slice() {
+            [0 | 1, second, ..] if *second < 40 => Some(Self { arcs }),
+            [2, second, ..] if second.checked_add(80).is_some() => Some(Self { arcs }),
+            _ => None,
+        }
+    }
+}
+
+impl ObjectIdentifier {
+    //! Properties
+
+    /// Gets the arcs.
+    pub fn arcs(&self) -> &[u64] {
+        &self.arcs
+    }
+
+    /// Gets the combined first two arcs.
+    fn first_subidentifier(&self) -> u64 {
+        40 * self.arcs[0] + self.arcs[1]
+    }
+}
+
+impl Display for ObjectIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arcs[0])?;
+        for arc in &self.arcs[1..] {
+            write!(f, ".{arc}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ObjectIdentifier {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidEncodedData {
+            reason: Some(format!("invalid object identifier: {s}").into()),
+        };
+        let arcs: Vec<u64> = s
+            .split('.')
+            .map(|arc| {
+                if arc.bytes().all(|c| c.is_ascii_digit()) {
+                    arc.parse::<u64>().map_err(|_| invalid())
+                } else {
+                    Err(invalid())
+                }
+            })
+            .collect::<Result<_, Error>>()?;
+        Self::new(arcs).ok_or_else(invalid)
+    }
+}
+
+impl UniversalType for ObjectIdentifier {
+    const TAG: Tag = Tag::OBJECT_IDENTIFIER;
+
+    fn contents_len(&self) -> Result<usize, Error> {
+        Ok(self.arcs[2..]
+            .iter()
+            .map(|arc| base_128_len(*arc))
+            .sum::<usize>()
+            + base_128_len(self.first_subidentifier()))
+    }
+
+    fn encode_contents(&self, target: &mut [u8]) -> Result<usize, Error> {
+        let mut len: usize = encode_base_128(self.first_subidentifier(), target);
+        for arc in &self.arcs[2..] {
+            len += encode_base_128(*arc, &mut target[len..]);
+        }
+        Ok(len)
+    }
+
+    fn decode_contents(contents: &[u8], _rules: EncodingRules) -> Result<Self, Error> {
+        if contents.is_empty() {
+            return Err(InvalidEncodedData {
+                reason: Some("empty object identifier".into()),
+            });
+        }
+        let mut r: &[u8] = contents;
+        let first: u64 = decode_base_128(&mut r)?;
+        let mut arcs: Vec<u64> = match first {
+            0..40 => vec![0, first],
+            40..80 => vec![1, first - 40],
+            _ => vec![2, first - 80],
+        };
+        while !r.is_empty() {
+            arcs.push(decode_base_128(&mut r)?);
+        }
+        Ok(Self { arcs })
+    }
+}
+
+impl_universal_type_value!(ObjectIdentifier);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::der::ObjectIdentifier;
+    use crate::test::test_io;
+    use crate::{DecodeFromReadPrefix, Error};
+    use std::io::Cursor;
+    use std::str::FromStr;
+
+    #[test]
+    fn object_identifier() -> Result<(), Error> {
+        let test_cases: &[(&str, &[u8])] = &[
+            ("0.0", b"\x06\x01\x00"),
+            ("1.39", b"\x06\x01\x4F"),
+            ("2.5.4.3", b"\x06\x03\x55\x04\x03"),
+            (
+                "1.2.840.113549.1.1.11",
+                b"\x06\x09\x2A\x86\x48\x86\xF7\x0D\x01\x01\x0B",
+            ),
+            ("2.999.3", b"\x06\x03\x88\x37\x03"),
+        ];
+
+        for (value, encoded) in test_cases {
+            let oid: ObjectIdentifier = ObjectIdentifier::from_str(value)?;
+            assert_eq!(oid.to_string(), *value);
+            test_io(&oid, encoded, false);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let test_cases: &[&str] = &["", "1", "3.1", "0.40", "1.2.", "1.+2", "1.2.x"];
+        for value in test_cases {
+            assert!(ObjectIdentifier::from_str(value).is_err(), "value={value}");
+        }
+
+        let test_cases: &[&[u8]] = &[b"\x06\x00", b"\x06\x02\x2A\x86", b"\x06\x02\x80\x01"];
+        for encoded in test_cases {
+            assert!(ObjectIdentifier::decode_from_read_prefix(&mut Cursor::new(encoded)).is_err());
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::Error;
+use crate::der::{EncodingRules, Tag, UniversalType, impl_universal_type_value};
+
+/// An ASN.1 `OCTET STRING`.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct OctetString {
+    bytes: Vec<u8>,
+}
+
+impl From<Vec<u8>> for OctetString {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+}
+
+impl From<&[u8]> for OctetString {
+    fn from(bytes: &[u8]) -> Self {
+        Self::from(bytes.to_vec())
+    }
+}
+
+impl OctetString {
+    //! Properties
+
+    /// Gets the bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Converts the octet string into its bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl UniversalType for OctetString {
+    const TAG: Tag = Tag::OCTET_STRING;
+
+    fn contents_len(&self) -> Result<usize, Error> {
+        Ok(self.bytes.len())
+    }
+
+    fn encode_contents(&self, target: &mut [u8]) -> Result<usize, Error> {
+        target[..self.bytes.len()].copy_from_slice(&self.bytes);
+        Ok(self.bytes.len())
+    }
+
+    fn decode_contents(contents: &[u8], _rules: EncodingRules) -> Result<Self, Error> {
+        Ok(Self::from(contents))
+    }
+}
+
+impl_universal_type_value!(OctetString);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::der::OctetString;
+    use crate::test::test_io;
+
+    #[test]
+    fn octet_string() {
+        let test_cases: &[(&[u8], &[u8])] = &[
+            (b"", b"\x04\x00"),
+            (b"\x01\x23\x45\x67", b"\x04\x04\x01\x23\x45\x67"),
+        ];
+
+        for (bytes, encoded) in test_cases {
+            test_io(&OctetString::from(*bytes), encoded, false);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::der::{EncodingRules, Tag, Tlv, UniversalType, impl_universal_type_value};
+use crate::{EncodeToSlice, EncodedLen, Error, read_single_byte};
+
+/// An ASN.1 `SEQUENCE` of tag-length-values.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Sequence {
+    items: Vec<Tlv>,
+}
+
+impl From<Vec<Tlv>> for Sequence {
+    fn from(items: Vec<Tlv>) -> Self {
+        Self { items }
+    }
+}
+
+impl Sequence {
+    //! Properties
+
+    /// Gets the items.
+    pub fn items(&self) -> &[Tlv] {
+        &self.items
+    }
+
+    /// Converts the sequence into its items.
+    pub fn into_items(self) -> Vec<Tlv> {
+        self.items
+    }
+}
+
+impl Sequence {
+    //! Mutations
+
+    /// Adds the `item`.
+    pub fn push(&mut self, item: Tlv) {
+        self.items.push(item);
+    }
+
+    /// Adds the universal type `value` as an item.
+    pub fn push_value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: UniversalType,
+    {
+        self.items.push(Tlv::from_value(value)?);
+        Ok(())
+    }
+}
+
+impl UniversalType for Sequence {
+    const TAG: Tag = Tag::SEQUENCE;
+
+    fn contents_len(&self) -> Result<usize, Error> {
+        self.items
+            .iter()
+            .try_fold(0usize, |len: usize, item: &Tlv| {
+                len.checked_add(item.encoded_len()?)
+                    .ok_or(Error::IntegerOverflow)
+            })
+    }
+
+    fn encode_contents(&self, target: &mut [u8]) -> Result<usize, Error> {
+        let mut len: usize = 0;
+        for item in &self.items {
+            len += unsafe { item.encode_to_slice_unchecked(&mut target[len..])? };
+        }
+        Ok(len)
+    }
+
+    fn decode_contents(contents: &[u8], rules: EncodingRules) -> Result<Self, Error> {
+        let mut r: &[u8] = contents;
+        let mut items: Vec<Tlv> = Vec::default();
+        while !r.is_empty() {
+            let first: u8 = read_single_byte(&mut r)?;
+            items.push(Tlv::decode_from_read_prefix_with_rules(
+                &mut r, first, rules,
+            )?);
+        }
+        Ok(Self { items })
+    }
+}
+
+impl_universal_type_value!(Sequence);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::der::{
+        BitString, EncodingRules, Integer, ObjectIdentifier, Sequence, Tag, Tlv, UniversalType,
+    };
+    use crate::test::test_io;
+    use crate::{DecodeFromReadPrefix, Error};
+    use std::io::Cursor;
+    use std::str::FromStr;
+
+    #[test]
+    fn sequence() -> Result<(), Error> {
+        let mut algorithm: Sequence = Sequence::from(Vec::default());
+        algorithm.push_value(&ObjectIdentifier::from_str("1.2.840.113549.1.1.1")?)?;
+        algorithm.push(Tlv::new(Tag::NULL, Vec::default()));
+
+        let mut key: Sequence = Sequence::from(Vec::default());
+        key.push_value(&algorithm)?;
+        key.push_value(&BitString::from(vec![0x00]))?;
+        key.push_value(&Integer::from(3i64))?;
+
+        let encoded: &[u8] = b"\x30\x16\
+            \x30\x0D\x06\x09\x2A\x86\x48\x86\xF7\x0D\x01\x01\x01\x05\x00\
+            \x03\x02\x00\x00\
+            \x02\x01\x03";
+        test_io(&key, encoded, false);
+        test_io(&Sequence::from(Vec::default()), b"\x30\x00", false);
+
+        let items: &[Tlv] = key.items();
+        let algorithm: Sequence = items[0].decode_value(EncodingRules::Der)?;
+        let oid: ObjectIdentifier = algorithm.items()[0].decode_value(EncodingRules::Der)?;
+        assert_eq!(oid.to_string(), "1.2.840.113549.1.1.1");
+        Ok(())
+    }
+
+    #[test]
+    fn ber() -> Result<(), Error> {
+        let contents: &[u8] = b"\x02\x81\x01\x05";
+        assert!(Sequence::decode_contents(contents, EncodingRules::Der).is_err());
+        let sequence: Sequence = Sequence::decode_contents(contents, EncodingRules::Ber)?;
+        assert_eq!(sequence.items(), [Tlv::new(Tag::INTEGER, vec![5])]);
+
+        assert!(matches!(
+            Sequence::decode_from_read_prefix(&mut Cursor::new(b"\x30\x03\x02\x02\x01")),
+            Err(Error::Stream(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use crate::Error::{IntegerOverflow, InvalidEncodedData};
+use crate::der::TagClass;
+use crate::der::base_128::{base_128_len, decode_base_128, encode_base_128};
+use crate::{Error, impl_decode_from_read_by_prefix, impl_encode_to_write_stack_buf};
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+
+/// An ASN.1 tag. (a class, a constructed flag and a tag number)
+///
+/// # Format
+/// The first byte holds the class in bits 8-7 and the constructed flag in bit 6. Tag numbers less
+/// than 31 are held in bits 5-1. Larger tag numbers set bits 5-1 and follow in base-128 with
+/// continuation bits.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Tag {
+    class: TagClass,
+    constructed: bool,
+    number: u32,
+}
+
+impl Tag {
+    //! Constants
+
+    /// The maximum length of an encoded tag.
+    pub const MAX_ENCODED_LEN: usize = 6;
+
+    /// The universal `BOOLEAN` tag.
+    pub const BOOLEAN: Self = Self::universal(1, false);
+
+    /// The universal `INTEGER` tag.
+    pub const INTEGER: Self = Self::universal(2, false);
+
+    /// The universal `BIT STRING` tag.
+    pub const BIT_STRING: Self = Self::universal(3, false);
+
+    /// The universal `OCTET STRING` tag.
+    pub const OCTET_STRING: Self = Self::universal(4, false);
+
+    /// The universal `NULL` tag.
+    pub const NULL: Self = Self::universal(5, false);
+
+    /// The universal `OBJECT IDENTIFIER` tag.
+    pub const OBJECT_IDENTIFIER: Self = Self::universal(6, false);
+
+    /// The universal `UTF8String` tag.
+    pub const UTF8_STRING: Self = Self::universal(12, false);
+
+    /// The universal `SEQUENCE` tag.
+    pub const SEQUENCE: Self = Self::universal(16, true);
+
+    /// The universal `SET` tag.
+    pub const SET: Self = Self::universal(17, true);
+
+    /// The tag number bits that mark a high tag number.
+    const HIGH_TAG_NUMBER: u8 = 0x1F;
+}
+
+impl Tag {
+    //! Construction
+
+    /// Creates a new tag.
+    pub const fn new(class: TagClass, constructed: bool, number: u32) -> Self {
+        Self {
+            class,
+            constructed,
+            number,
+        }
+    }
+
+    /// Creates a new universal tag.
+    const fn universal(number: u32, constructed: bool) -> Self {
+        Self::new(TagClass::Universal, constructed, number)
+    }
+
+    /// Creates a new context-specific tag: `[number]`.
+    pub const fn context_specific(number: u32, constructed: bool) -> Self {
+        Self::new(TagClass::ContextSpecific, constructed, number)
+    }
+}
+
+impl Tag {
+    //! Properties
+
+    /// Gets the class.
+    pub const fn class(self) -> TagClass {
+        self.class
+    }
+
+    /// Checks if the tag is constructed.
+    pub const fn is_constructed(self) -> bool {
+        self.constructed
+    }
+
+    /// Gets the tag number.
+    pub const fn number(self) -> u32 {
+        self.number
+    }
+
+    /// Gets the class & constructed bits of the first byte.
+    const fn leading_bits(self) -> u8 {
+        (self.class.bits() << 6) | if self.constructed { 0x20 } else { 0x00 }
+    }
+}
+
+impl Display for Tag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{} {}]", self.class, self.number)?;
+        if self.constructed {
+            write!(f, " (constructed)")?;
+        }
+        Ok(())
+    }
+}
+
+impl crate::EncodedLen for Tag {
+    fn encoded_len(&self) -> Result<usize, Error> {
+        if self.number < Self::HIGH_TAG_NUMBER as u32 {
+            Ok(1)
+        } else {
+            Ok(1 + base_128_len(self.number as u64))
+        }
+    }
+}
+
+impl crate::EncodeToSlice for Tag {
+    unsafe fn encode_to_slice_unchecked(&self, target: &mut [u8]) -> Result<usize, Error> {
+        if self.number < Self::HIGH_TAG_NUMBER as u32 {
+            target[0] = self.leading_bits() | self.number as u8;
+            Ok(1)
+        } else {
+            target[0] = self.leading_bits() | Self::HIGH_TAG_NUMBER;
+            Ok(1 + encode_base_128(self.number as u64, &mut target[1..]))
+        }
+    }
+}
+
+impl_encode_to_write_stack_buf!(Tag, Self::MAX_ENCODED_LEN);
+
+impl crate::DecodeFromReadPrefix for Tag {
+    fn decode_from_read_prefix_with_first_byte<R>(r: &mut R, first: u8) -> Result<Self, Error>
+    where
+        R: Read,
+    {
+        let class: TagClass = TagClass::from_bits(first >> 6);
+        let constructed: bool = first & 0x20 != 0;
+        let number: u32 = if first & Self::HIGH_TAG_NUMBER == Self::HIGH_TAG_NUMBER {
+            let number: u64 = decode_base_128(r)?;
+            if number < Self::HIGH_TAG_NUMBER as u64 {
+                return Err(InvalidEncodedData {
+                    reason: Some(format!("low tag number in the high form: {number}").into()),
+                });
+            }
+            u32::try_from(number).map_err(|_| IntegerOverflow)?
+        } else {
+            (first & Self::HIGH_TAG_NUMBER) as u32
+        };
+        Ok(Self::new(class, constructed, number))
+    }
+}
+
+impl_decode_from_read_by_prefix!(Tag);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::DecodeFromReadPrefix;
+    use crate::der::{Tag, TagClass};
+    use crate::test::test_io;
+    use std::io::Cursor;
+
+    #[test]
+    fn tag() {
+        let test_cases: &[(Tag, &[u8])] = &[
+            (Tag::INTEGER, b"\x02"),
+            (Tag::SEQUENCE, b"\x30"),
+            (Tag::context_specific(0, true), b"\xA0"),
+            (Tag::context_specific(3, false), b"\x83"),
+            (Tag::new(TagClass::Application, false, 30), b"\x5E"),
+            (Tag::new(TagClass::Application, false, 31), b"\x5F\x1F"),
+            (Tag::new(TagClass::Private, true, 201), b"\xFF\x81\x49"),
+            (
+                Tag::new(TagClass::Universal, false, u32::MAX),
+                b"\x1F\x8F\xFF\xFF\xFF\x7F",
+            ),
+        ];
+
+        for (tag, encoded) in test_cases {
+            test_io(tag, encoded, false);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let test_cases: &[&[u8]] = &[
+            b"\x1F\x1E",                 // low tag number in the high form
+            b"\x1F\x80\x7F",             // leading 0x80 byte
+            b"\x1F\x90\x80\x80\x80\x00", // tag number overflow
+        ];
+
+        for encoded in test_cases {
+            assert!(Tag::decode_from_read_prefix(&mut Cursor::new(encoded)).is_err());
+        }
+    }
+}
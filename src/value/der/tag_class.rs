@@ -0,0 +1,47 @@
+use std::fmt::{Display, Formatter};
+
+/// An ASN.1 tag class.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum TagClass {
+    /// The universal class. (types defined in X.680)
+    Universal = 0,
+
+    /// The application class.
+    Application = 1,
+
+    /// The context-specific class. (`[n]` tags)
+    ContextSpecific = 2,
+
+    /// The private class.
+    Private = 3,
+}
+
+impl TagClass {
+    //! Conversions
+
+    /// Gets the tag class for the 2-bit `value`.
+    pub const fn from_bits(value: u8) -> Self {
+        match value & 0x03 {
+            0 => Self::Universal,
+            1 => Self::Application,
+            2 => Self::ContextSpecific,
+            _ => Self::Private,
+        }
+    }
+
+    /// Gets the 2-bit value of the tag class.
+    pub const fn bits(self) -> u8 {
+        self as u8
+    }
+}
+
+impl Display for TagClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Universal => write!(f, "UNIVERSAL"),
+            Self::Application => write!(f, "APPLICATION"),
+            Self::ContextSpecific => write!(f, "CONTEXT"),
+            Self::Private => write!(f, "PRIVATE"),
+        }
+    }
+}
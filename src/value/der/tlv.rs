@@ -0,0 +1,207 @@
+use crate::Error::{IntegerOverflow, InvalidEncodedData};
+use crate::der::{EncodingRules, Length, Tag, UniversalType};
+use crate::{
+    DecodeFromReadPrefix, EncodeToSlice, EncodedLen, Error, impl_decode_from_read_by_prefix,
+};
+use std::io;
+use std::io::ErrorKind::UnexpectedEof;
+use std::io::{Read, Write};
+
+/// An ASN.1 tag-length-value with definite length contents.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Tlv {
+    tag: Tag,
+    contents: Vec<u8>,
+}
+
+impl Tlv {
+    //! Construction
+
+    /// Creates a new tag-length-value.
+    pub fn new(tag: Tag, contents: Vec<u8>) -> Self {
+        Self { tag, contents }
+    }
+
+    /// Creates a new tag-length-value from the universal type `value`.
+    pub fn from_value<T>(value: &T) -> Result<Self, Error>
+    where
+        T: UniversalType,
+    {
+        let mut contents: Vec<u8> = vec![0u8; value.contents_len()?];
+        let len: usize = value.encode_contents(&mut contents)?;
+        debug_assert_eq!(len, contents.len());
+        Ok(Self::new(T::TAG, contents))
+    }
+}
+
+impl Tlv {
+    //! Properties
+
+    /// Gets the tag.
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Gets the contents.
+    pub fn contents(&self) -> &[u8] {
+        &self.contents
+    }
+
+    /// Converts the tag-length-value into its contents.
+    pub fn into_contents(self) -> Vec<u8> {
+        self.contents
+    }
+}
+
+impl Tlv {
+    //! Values
+
+    /// Decodes the universal type value from the contents with the `rules`.
+    ///
+    /// Returns an `InvalidEncodedData` error if the tag is not the tag of the universal type.
+    pub fn decode_value<T>(&self, rules: EncodingRules) -> Result<T, Error>
+    where
+        T: UniversalType,
+    {
+        if self.tag != T::TAG {
+            Err(InvalidEncodedData {
+                reason: Some(format!("expected tag {}, found {}", T::TAG, self.tag).into()),
+            })
+        } else {
+            T::decode_contents(&self.contents, rules)
+        }
+    }
+}
+
+impl EncodedLen for Tlv {
+    fn encoded_len(&self) -> Result<usize, Error> {
+        let header_len: usize =
+            self.tag.encoded_len()? + Length::from(self.contents.len()).encoded_len()?;
+        header_len
+            .checked_add(self.contents.len())
+            .ok_or(IntegerOverflow)
+    }
+}
+
+impl EncodeToSlice for Tlv {
+    unsafe fn encode_to_slice_unchecked(&self, target: &mut [u8]) -> Result<usize, Error> {
+        let length: Length = Length::from(self.contents.len());
+        let mut len: usize = unsafe { self.tag.encode_to_slice_unchecked(target)? };
+        len += unsafe { length.encode_to_slice_unchecked(&mut target[len..])? };
+        target[len..(len + self.contents.len())].copy_from_slice(&self.contents);
+        Ok(len + self.contents.len())
+    }
+}
+
+impl crate::EncodeToWrite for Tlv {
+    fn encode_to_write<W>(&self, w: &mut W) -> Result<usize, Error>
+    where
+        W: Write,
+    {
+        let length: Length = Length::from(self.contents.len());
+        let len: usize = self.tag.encode_to_write(w)? + length.encode_to_write(w)?;
+        w.write_all(&self.contents)?;
+        Ok(len + self.contents.len())
+    }
+}
+
+impl Tlv {
+    //! Decoding
+
+    /// Decodes a tag-length-value from the `Read` prefix given the `first` byte and the `rules`.
+    pub fn decode_from_read_prefix_with_rules<R>(
+        r: &mut R,
+        first: u8,
+        rules: EncodingRules,
+    ) -> Result<Self, Error>
+    where
+        R: Read,
+    {
+        let tag: Tag = Tag::decode_from_read_prefix_with_first_byte(r, first)?;
+        let first: u8 = crate::read_single_byte(r)?;
+        let len: usize = Length::decode_from_read_prefix_with_rules(r, first, rules)?.value();
+        let mut contents: Vec<u8> = Vec::default();
+        r.take(len as u64).read_to_end(&mut contents)?;
+        if contents.len() != len {
+            Err(io::Error::from(UnexpectedEof).into())
+        } else {
+            Ok(Self::new(tag, contents))
+        }
+    }
+}
+
+impl DecodeFromReadPrefix for Tlv {
+    fn decode_from_read_prefix_with_first_byte<R>(r: &mut R, first: u8) -> Result<Self, Error>
+    where
+        R: Read,
+    {
+        Self::decode_from_read_prefix_with_rules(r, first, EncodingRules::Der)
+    }
+}
+
+impl_decode_from_read_by_prefix!(Tlv);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::der::{EncodingRules, Integer, OctetString, Tag, Tlv};
+    use crate::test::test_io;
+    use crate::{DecodeFromReadPrefix, Error, read_single_byte};
+    use std::io::Cursor;
+
+    #[test]
+    fn tlv() {
+        let test_cases: &[(Tlv, &[u8])] = &[
+            (Tlv::new(Tag::NULL, Vec::default()), b"\x05\x00"),
+            (Tlv::new(Tag::BOOLEAN, vec![0xFF]), b"\x01\x01\xFF"),
+            (
+                Tlv::new(Tag::context_specific(0, true), b"\x02\x01\x02".to_vec()),
+                b"\xA0\x03\x02\x01\x02",
+            ),
+            (
+                Tlv::new(Tag::OCTET_STRING, vec![0xAB; 200]),
+                &[b"\x04\x81\xC8".as_slice(), &[0xAB; 200]].concat(),
+            ),
+        ];
+
+        for (tlv, encoded) in test_cases {
+            test_io(tlv, encoded, false);
+        }
+    }
+
+    #[test]
+    fn decode_value() -> Result<(), Error> {
+        let tlv: Tlv = Tlv::from_value(&Integer::from(65_537i64))?;
+        assert_eq!(tlv.tag(), Tag::INTEGER);
+        assert_eq!(tlv.contents(), b"\x01\x00\x01");
+        assert_eq!(
+            tlv.decode_value::<Integer>(EncodingRules::Der)?.to_i64()?,
+            65_537
+        );
+        assert!(matches!(
+            tlv.decode_value::<OctetString>(EncodingRules::Der),
+            Err(Error::InvalidEncodedData { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn ber() -> Result<(), Error> {
+        let encoded: &[u8] = b"\x04\x81\x02\x01\x02";
+        assert!(Tlv::decode_from_read_prefix(&mut Cursor::new(encoded)).is_err());
+
+        let mut r: Cursor<&[u8]> = Cursor::new(encoded);
+        let first: u8 = read_single_byte(&mut r)?;
+        let tlv: Tlv = Tlv::decode_from_read_prefix_with_rules(&mut r, first, EncodingRules::Ber)?;
+        assert_eq!(tlv, Tlv::new(Tag::OCTET_STRING, vec![1, 2]));
+        Ok(())
+    }
+
+    #[test]
+    fn truncated() {
+        assert!(matches!(
+            Tlv::decode_from_read_prefix(&mut Cursor::new(b"\x04\x03\x01\x02")),
+            Err(Error::Stream(_))
+        ));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::Error;
+use crate::der::{EncodingRules, Tag};
+
+/// An ASN.1 type with a universal tag that can be encoded as a tag-length-value.
+///
+/// Implementations also implement the value traits for the complete tag-length-value encoding.
+pub trait UniversalType: Sized {
+    /// The universal tag.
+    const TAG: Tag;
+
+    /// Gets the length of the encoded contents.
+    fn contents_len(&self) -> Result<usize, Error>;
+
+    /// Encodes the contents to the `target` slice.
+    ///
+    /// Returns the length of the encoded contents. The `target` must have at least `contents_len`
+    /// bytes.
+    fn encode_contents(&self, target: &mut [u8]) -> Result<usize, Error>;
+
+    /// Decodes the value from the `contents` with the `rules`.
+    fn decode_contents(contents: &[u8], rules: EncodingRules) -> Result<Self, Error>;
+}
+
+/// Implements the value traits for the `target_type` using its `UniversalType` implementation.
+macro_rules! impl_universal_type_value {
+    ($target_type:ty) => {
+        impl $crate::EncodedLen for $target_type {
+            fn encoded_len(&self) -> Result<usize, $crate::Error> {
+                use $crate::der::{Length, UniversalType};
+
+                let contents_len: usize = self.contents_len()?;
+                let header_len: usize =
+                    Self::TAG.encoded_len()? + Length::from(contents_len).encoded_len()?;
+                header_len
+                    .checked_add(contents_len)
+                    .ok_or($crate::Error::IntegerOverflow)
+            }
+        }
+
+        impl $crate::EncodeToSlice for $target_type {
+            unsafe fn encode_to_slice_unchecked(
+                &self,
+                target: &mut [u8],
+            ) -> Result<usize, $crate::Error> {
+                use $crate::der::{Length, UniversalType};
+
+                let contents_len: usize = self.contents_len()?;
+                let mut len: usize = unsafe { Self::TAG.encode_to_slice_unchecked(target)? };
+                len += unsafe {
+                    Length::from(contents_len).encode_to_slice_unchecked(&mut target[len..])?
+                };
+                Ok(len + self.encode_contents(&mut target[len..])?)
+            }
+        }
+
+        impl $crate::EncodeToWrite for $target_type {
+            fn encode_to_write<W>(&self, w: &mut W) -> Result<usize, $crate::Error>
+            where
+                W: std::io::Write,
+            {
+                use $crate::EncodeToSlice;
+
+                let encoded: Vec<u8> = self.encode_as_vec()?;
+                w.write_all(&encoded)?;
+                Ok(encoded.len())
+            }
+        }
+
+        impl $crate::DecodeFromReadPrefix for $target_type {
+            fn decode_from_read_prefix_with_first_byte<R>(
+                r: &mut R,
+                first: u8,
+            ) -> Result<Self, $crate::Error>
+            where
+                R: std::io::Read,
+            {
+                use $crate::der::{EncodingRules, Tlv};
+
+                Tlv::decode_from_read_prefix_with_rules(r, first, EncodingRules::Der)?
+                    .decode_value(EncodingRules::Der)
+            }
+        }
+
+        $crate::impl_decode_from_read_by_prefix!($target_type);
+    };
+}
+
+pub(in crate::value::der) use impl_universal_type_value;
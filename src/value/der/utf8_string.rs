@@ -0,0 +1,96 @@
+use crate::Error;
+use crate::Error::InvalidEncodedData;
+use crate::der::{EncodingRules, Tag, UniversalType, impl_universal_type_value};
+use std::fmt::{Display, Formatter};
+
+/// An ASN.1 `UTF8String`.
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Utf8String {
+    value: String,
+}
+
+impl From<String> for Utf8String {
+    fn from(value: String) -> Self {
+        Self { value }
+    }
+}
+
+impl From<&str> for Utf8String {
+    fn from(value: &str) -> Self {
+        Self::from(value.to_string())
+    }
+}
+
+impl Utf8String {
+    //! Properties
+
+    /// Gets the string.
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Converts the value into its string.
+    pub fn into_string(self) -> String {
+        self.value
+    }
+}
+
+impl Display for Utf8String {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl UniversalType for Utf8String {
+    const TAG: Tag = Tag::UTF8_STRING;
+
+    fn contents_len(&self) -> Result<usize, Error> {
+        Ok(self.value.len())
+    }
+
+    fn encode_contents(&self, target: &mut [u8]) -> Result<usize, Error> {
+        target[..self.value.len()].copy_from_slice(self.value.as_bytes());
+        Ok(self.value.len())
+    }
+
+    fn decode_contents(contents: &[u8], _rules: EncodingRules) -> Result<Self, Error> {
+        match std::str::from_utf8(contents) {
+            Ok(value) => Ok(Self::from(value)),
+            Err(error) => Err(InvalidEncodedData {
+                reason: Some(error.into()),
+            }),
+        }
+    }
+}
+
+impl_universal_type_value!(Utf8String);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::der::Utf8String;
+    use crate::test::test_io;
+    use crate::{DecodeFromReadPrefix, Error};
+    use std::io::Cursor;
+
+    #[test]
+    fn utf8_string() {
+        let test_cases: &[(&str, &[u8])] = &[
+            ("", b"\x0C\x00"),
+            ("example.com", b"\x0C\x0Bexample.com"),
+            ("\u{00E9}", b"\x0C\x02\xC3\xA9"),
+        ];
+
+        for (value, encoded) in test_cases {
+            test_io(&Utf8String::from(*value), encoded, false);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            Utf8String::decode_from_read_prefix(&mut Cursor::new(b"\x0C\x01\xFF")),
+            Err(Error::InvalidEncodedData { .. })
+        ));
+    }
+}
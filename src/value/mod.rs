@@ -12,5 +12,17 @@ mod encoded_len;
 
 mod macros;
 
+#[cfg(feature = "cbor")]
+pub mod cbor;
+#[cfg(feature = "der")]
+pub mod der;
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
+#[cfg(feature = "prefix-var-int")]
+pub mod prefix_var_int;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+#[cfg(feature = "stream-vbyte")]
+pub mod stream_vbyte;
 #[cfg(feature = "var-int")]
 pub mod var_int;
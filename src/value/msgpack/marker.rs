@@ -0,0 +1,183 @@
+use crate::Error;
+use crate::Error::IntegerOverflow;
+
+/// The maximum positive fixint.
+pub const POSITIVE_FIXINT_MAX: u8 = 0x7F;
+
+/// The fixmap prefix. (4-bit length)
+pub const FIXMAP: u8 = 0x80;
+
+/// The fixarray prefix. (4-bit length)
+pub const FIXARRAY: u8 = 0x90;
+
+/// The fixstr prefix. (5-bit length)
+pub const FIXSTR: u8 = 0xA0;
+
+/// The nil marker.
+pub const NIL: u8 = 0xC0;
+
+/// The false marker.
+pub const FALSE: u8 = 0xC2;
+
+/// The true marker.
+pub const TRUE: u8 = 0xC3;
+
+/// The bin 8 marker.
+pub const BIN8: u8 = 0xC4;
+
+/// The bin 16 marker.
+pub const BIN16: u8 = 0xC5;
+
+/// The bin 32 marker.
+pub const BIN32: u8 = 0xC6;
+
+/// The ext 8 marker.
+pub const EXT8: u8 = 0xC7;
+
+/// The ext 16 marker.
+pub const EXT16: u8 = 0xC8;
+
+/// The ext 32 marker.
+pub const EXT32: u8 = 0xC9;
+
+/// The float 32 marker.
+pub const FLOAT32: u8 = 0xCA;
+
+/// The float 64 marker.
+pub const FLOAT64: u8 = 0xCB;
+
+/// The uint 8 marker.
+pub const UINT8: u8 = 0xCC;
+
+/// The uint 16 marker.
+pub const UINT16: u8 = 0xCD;
+
+/// The uint 32 marker.
+pub const UINT32: u8 = 0xCE;
+
+/// The uint 64 marker.
+pub const UINT64: u8 = 0xCF;
+
+/// The int 8 marker.
+pub const INT8: u8 = 0xD0;
+
+/// The int 16 marker.
+pub const INT16: u8 = 0xD1;
+
+/// The int 32 marker.
+pub const INT32: u8 = 0xD2;
+
+/// The int 64 marker.
+pub const INT64: u8 = 0xD3;
+
+/// The fixext 1 marker.
+pub const FIXEXT1: u8 = 0xD4;
+
+/// The fixext 2 marker.
+pub const FIXEXT2: u8 = 0xD5;
+
+/// The fixext 4 marker.
+pub const FIXEXT4: u8 = 0xD6;
+
+/// The fixext 8 marker.
+pub const FIXEXT8: u8 = 0xD7;
+
+/// The fixext 16 marker.
+pub const FIXEXT16: u8 = 0xD8;
+
+/// The str 8 marker.
+pub const STR8: u8 = 0xD9;
+
+/// The str 16 marker.
+pub const STR16: u8 = 0xDA;
+
+/// The str 32 marker.
+pub const STR32: u8 = 0xDB;
+
+/// The array 16 marker.
+pub const ARRAY16: u8 = 0xDC;
+
+/// The array 32 marker.
+pub const ARRAY32: u8 = 0xDD;
+
+/// The map 16 marker.
+pub const MAP16: u8 = 0xDE;
+
+/// The map 32 marker.
+pub const MAP32: u8 = 0xDF;
+
+/// The minimum negative fixint.
+pub const NEGATIVE_FIXINT_MIN: u8 = 0xE0;
+
+/// Converts the `len` to a `u32`.
+///
+/// Returns an `IntegerOverflow` error if the `len` cannot be encoded.
+pub fn len_u32(len: usize) -> Result<u32, Error> {
+    u32::try_from(len).map_err(|_| IntegerOverflow)
+}
+
+/// Gets the encoded length of the unsigned integer `value`.
+pub const fn uint_len(value: u64) -> usize {
+    match value {
+        0..=0x7F => 1,
+        0x80..=0xFF => 2,
+        0x100..=0xFFFF => 3,
+        0x1_0000..=0xFFFF_FFFF => 5,
+        _ => 9,
+    }
+}
+
+/// Gets the encoded length of the signed integer `value`.
+pub const fn int_len(value: i64) -> usize {
+    if value >= 0 {
+        uint_len(value as u64)
+    } else if value >= -32 {
+        1
+    } else if value >= i8::MIN as i64 {
+        2
+    } else if value >= i16::MIN as i64 {
+        3
+    } else if value >= i32::MIN as i64 {
+        5
+    } else {
+        9
+    }
+}
+
+/// Gets the header length of a string with the `len`.
+pub const fn str_header_len(len: u32) -> usize {
+    match len {
+        0..32 => 1,
+        32..=0xFF => 2,
+        0x100..=0xFFFF => 3,
+        _ => 5,
+    }
+}
+
+/// Gets the header length of a binary value with the `len`.
+pub const fn bin_header_len(len: u32) -> usize {
+    match len {
+        0..=0xFF => 2,
+        0x100..=0xFFFF => 3,
+        _ => 5,
+    }
+}
+
+/// Gets the header length of an array or map with the `len`.
+pub const fn container_header_len(len: u32) -> usize {
+    match len {
+        0..16 => 1,
+        16..=0xFFFF => 3,
+        _ => 5,
+    }
+}
+
+/// Gets the header length, including the type byte, of an extension value with the `len`.
+pub const fn ext_header_len(len: u32) -> usize {
+    match len {
+        1 | 2 | 4 | 8 | 16 => 2,
+        0..=0xFF => 3,
+        0x100..=0xFFFF => 4,
+        _ => 6,
+    }
+}
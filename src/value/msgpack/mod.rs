@@ -0,0 +1,9 @@
+pub use msgpack_reader::*;
+pub use msgpack_value::*;
+pub use msgpack_writer::*;
+
+mod msgpack_reader;
+mod msgpack_value;
+mod msgpack_writer;
+
+pub(in crate::value::msgpack) mod marker;
@@ -0,0 +1,425 @@
+use crate::Error::{IntegerOverflow, InvalidEncodedData};
+use crate::msgpack::MsgPackValue;
+use crate::msgpack::marker::{
+    ARRAY16, ARRAY32, BIN8, BIN16, BIN32, EXT8, EXT16, EXT32, FALSE, FIXARRAY, FIXEXT1, FIXEXT2,
+    FIXEXT4, FIXEXT8, FIXEXT16, FIXMAP, FIXSTR, FLOAT32, FLOAT64, INT8, INT16, INT32, INT64, MAP16,
+    MAP32, NEGATIVE_FIXINT_MIN, NIL, POSITIVE_FIXINT_MAX, STR8, STR16, STR32, TRUE, UINT8, UINT16,
+    UINT32, UINT64,
+};
+use crate::{Error, read_optional_byte, read_single_byte};
+use std::io;
+use std::io::ErrorKind::UnexpectedEof;
+use std::io::Read;
+
+/// Responsible for reading MessagePack values from a `Read`.
+///
+/// The typed read functions return an `InvalidEncodedData` error when the next value has a
+/// different type. Complete values of any type can be read with `read_value`.
+#[derive(Debug)]
+pub struct MsgPackReader<R> {
+    r: R,
+}
+
+impl<R: Read> MsgPackReader<R> {
+    //! Constants
+
+    /// The maximum nesting depth of arrays and maps when reading values.
+    pub const MAX_DEPTH: usize = 256;
+}
+
+impl<R: Read> MsgPackReader<R> {
+    //! Construction
+
+    /// Creates a new MessagePack reader.
+    pub fn new(r: R) -> Self {
+        Self { r }
+    }
+
+    /// Gets the underlying `Read`.
+    pub fn into_inner(self) -> R {
+        self.r
+    }
+}
+
+impl<R: Read> MsgPackReader<R> {
+    //! Formats
+
+    /// Reads `N` bytes.
+    fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let mut buffer: [u8; N] = [0u8; N];
+        self.r.read_exact(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Reads a payload of `len` bytes.
+    fn read_payload(&mut self, len: usize) -> Result<Vec<u8>, Error> {
+        let mut payload: Vec<u8> = Vec::default();
+        (&mut self.r).take(len as u64).read_to_end(&mut payload)?;
+        if payload.len() != len {
+            Err(io::Error::from(UnexpectedEof).into())
+        } else {
+            Ok(payload)
+        }
+    }
+
+    /// Reads a big-endian 8, 16 or 32-bit length for the `marker` given the `markers`.
+    ///
+    /// Returns `None` if the `marker` is not one of the `markers`.
+    fn read_len(&mut self, marker: u8, markers: [u8; 3]) -> Result<Option<usize>, Error> {
+        if marker == markers[0] {
+            Ok(Some(self.read_bytes::<1>()?[0] as usize))
+        } else {
+            self.read_wide_len(marker, [markers[1], markers[2]])
+        }
+    }
+
+    /// Reads a big-endian 16 or 32-bit length for the `marker` given the `markers`.
+    ///
+    /// Returns `None` if the `marker` is not one of the `markers`.
+    fn read_wide_len(&mut self, marker: u8, markers: [u8; 2]) -> Result<Option<usize>, Error> {
+        Ok(if marker == markers[0] {
+            Some(u16::from_be_bytes(self.read_bytes()?) as usize)
+        } else if marker == markers[1] {
+            Some(u32::from_be_bytes(self.read_bytes()?) as usize)
+        } else {
+            None
+        })
+    }
+
+    /// Reads the integer for the `marker`.
+    ///
+    /// Returns `None` if the `marker` is not an integer marker.
+    fn read_integer(&mut self, marker: u8) -> Result<Option<i128>, Error> {
+        Ok(Some(match marker {
+            0..=POSITIVE_FIXINT_MAX => marker as i128,
+            NEGATIVE_FIXINT_MIN.. => marker as i8 as i128,
+            UINT8 => self.read_bytes::<1>()?[0] as i128,
+            UINT16 => u16::from_be_bytes(self.read_bytes()?) as i128,
+            UINT32 => u32::from_be_bytes(self.read_bytes()?) as i128,
+            UINT64 => u64::from_be_bytes(self.read_bytes()?) as i128,
+            INT8 => i8::from_be_bytes(self.read_bytes()?) as i128,
+            INT16 => i16::from_be_bytes(self.read_bytes()?) as i128,
+            INT32 => i32::from_be_bytes(self.read_bytes()?) as i128,
+            INT64 => i64::from_be_bytes(self.read_bytes()?) as i128,
+            _ => return Ok(None),
+        }))
+    }
+
+    /// Reads the string length for the `marker`.
+    ///
+    /// Returns `None` if the `marker` is not a string marker.
+    fn read_str_len(&mut self, marker: u8) -> Result<Option<usize>, Error> {
+        if marker & 0xE0 == FIXSTR {
+            Ok(Some((marker & 0x1F) as usize))
+        } else {
+            self.read_len(marker, [STR8, STR16, STR32])
+        }
+    }
+
+    /// Reads the array length for the `marker`.
+    ///
+    /// Returns `None` if the `marker` is not an array marker.
+    fn read_array_len_with_marker(&mut self, marker: u8) -> Result<Option<usize>, Error> {
+        if marker & 0xF0 == FIXARRAY {
+            Ok(Some((marker & 0x0F) as usize))
+        } else {
+            self.read_wide_len(marker, [ARRAY16, ARRAY32])
+        }
+    }
+
+    /// Reads the map length for the `marker`.
+    ///
+    /// Returns `None` if the `marker` is not a map marker.
+    fn read_map_len_with_marker(&mut self, marker: u8) -> Result<Option<usize>, Error> {
+        if marker & 0xF0 == FIXMAP {
+            Ok(Some((marker & 0x0F) as usize))
+        } else {
+            self.read_wide_len(marker, [MAP16, MAP32])
+        }
+    }
+
+    /// Reads the extension type & data for the `marker`.
+    ///
+    /// Returns `None` if the `marker` is not an extension marker.
+    fn read_ext_with_marker(&mut self, marker: u8) -> Result<Option<(i8, Vec<u8>)>, Error> {
+        let len: usize = match marker {
+            FIXEXT1 => 1,
+            FIXEXT2 => 2,
+            FIXEXT4 => 4,
+            FIXEXT8 => 8,
+            FIXEXT16 => 16,
+            _ => match self.read_len(marker, [EXT8, EXT16, EXT32])? {
+                Some(len) => len,
+                None => return Ok(None),
+            },
+        };
+        let ext_type: i8 = i8::from_be_bytes(self.read_bytes()?);
+        Ok(Some((ext_type, self.read_payload(len)?)))
+    }
+
+    /// Creates the error for the unexpected `marker` when reading the `expected` type.
+    fn unexpected_marker(marker: u8, expected: &str) -> Error {
+        InvalidEncodedData {
+            reason: Some(format!("expected {expected}, found marker: {marker:#04X}").into()),
+        }
+    }
+}
+
+impl<R: Read> MsgPackReader<R> {
+    //! Typed Reads
+
+    /// Reads the `nil` value.
+    pub fn read_nil(&mut self) -> Result<(), Error> {
+        match read_single_byte(&mut self.r)? {
+            NIL => Ok(()),
+            marker => Err(Self::unexpected_marker(marker, "nil")),
+        }
+    }
+
+    /// Reads a boolean value.
+    pub fn read_bool(&mut self) -> Result<bool, Error> {
+        match read_single_byte(&mut self.r)? {
+            FALSE => Ok(false),
+            TRUE => Ok(true),
+            marker => Err(Self::unexpected_marker(marker, "bool")),
+        }
+    }
+
+    /// Reads an integer of any integer format as a `u64`.
+    ///
+    /// Returns an `IntegerOverflow` error if the integer is negative.
+    pub fn read_uint(&mut self) -> Result<u64, Error> {
+        let marker: u8 = read_single_byte(&mut self.r)?;
+        match self.read_integer(marker)? {
+            Some(value) => u64::try_from(value).map_err(|_| IntegerOverflow),
+            None => Err(Self::unexpected_marker(marker, "int")),
+        }
+    }
+
+    /// Reads an integer of any integer format as an `i64`.
+    ///
+    /// Returns an `IntegerOverflow` error if the integer is greater than `i64::MAX`.
+    pub fn read_int(&mut self) -> Result<i64, Error> {
+        let marker: u8 = read_single_byte(&mut self.r)?;
+        match self.read_integer(marker)? {
+            Some(value) => i64::try_from(value).map_err(|_| IntegerOverflow),
+            None => Err(Self::unexpected_marker(marker, "int")),
+        }
+    }
+
+    /// Reads a single-precision float.
+    pub fn read_f32(&mut self) -> Result<f32, Error> {
+        match read_single_byte(&mut self.r)? {
+            FLOAT32 => Ok(f32::from_be_bytes(self.read_bytes()?)),
+            marker => Err(Self::unexpected_marker(marker, "float 32")),
+        }
+    }
+
+    /// Reads a single or double-precision float as an `f64`.
+    pub fn read_f64(&mut self) -> Result<f64, Error> {
+        match read_single_byte(&mut self.r)? {
+            FLOAT32 => Ok(f32::from_be_bytes(self.read_bytes()?) as f64),
+            FLOAT64 => Ok(f64::from_be_bytes(self.read_bytes()?)),
+            marker => Err(Self::unexpected_marker(marker, "float")),
+        }
+    }
+
+    /// Reads a string.
+    ///
+    /// Returns an `InvalidEncodedData` error if the string is not valid UTF-8.
+    pub fn read_str(&mut self) -> Result<String, Error> {
+        let marker: u8 = read_single_byte(&mut self.r)?;
+        match self.read_str_len(marker)? {
+            Some(len) => self.read_str_payload(len),
+            None => Err(Self::unexpected_marker(marker, "str")),
+        }
+    }
+
+    /// Reads a UTF-8 string payload of `len` bytes.
+    fn read_str_payload(&mut self, len: usize) -> Result<String, Error> {
+        String::from_utf8(self.read_payload(len)?).map_err(|error| InvalidEncodedData {
+            reason: Some(error.into()),
+        })
+    }
+
+    /// Reads a binary value.
+    pub fn read_bin(&mut self) -> Result<Vec<u8>, Error> {
+        let marker: u8 = read_single_byte(&mut self.r)?;
+        match self.read_len(marker, [BIN8, BIN16, BIN32])? {
+            Some(len) => self.read_payload(len),
+            None => Err(Self::unexpected_marker(marker, "bin")),
+        }
+    }
+
+    /// Reads an extension value as its type & data.
+    pub fn read_ext(&mut self) -> Result<(i8, Vec<u8>), Error> {
+        let marker: u8 = read_single_byte(&mut self.r)?;
+        self.read_ext_with_marker(marker)?
+            .ok_or_else(|| Self::unexpected_marker(marker, "ext"))
+    }
+
+    /// Reads the header of an array.
+    ///
+    /// Returns the number of items that follow.
+    pub fn read_array_len(&mut self) -> Result<usize, Error> {
+        let marker: u8 = read_single_byte(&mut self.r)?;
+        self.read_array_len_with_marker(marker)?
+            .ok_or_else(|| Self::unexpected_marker(marker, "array"))
+    }
+
+    /// Reads the header of a map.
+    ///
+    /// Returns the number of key-value pairs that follow.
+    pub fn read_map_len(&mut self) -> Result<usize, Error> {
+        let marker: u8 = read_single_byte(&mut self.r)?;
+        self.read_map_len_with_marker(marker)?
+            .ok_or_else(|| Self::unexpected_marker(marker, "map"))
+    }
+}
+
+impl<R: Read> MsgPackReader<R> {
+    //! Values
+
+    /// Reads the next value.
+    ///
+    /// Returns `None` if the `Read` is empty.
+    pub fn read_value(&mut self) -> Result<Option<MsgPackValue>, Error> {
+        if let Some(marker) = read_optional_byte(&mut self.r)? {
+            Ok(Some(self.read_value_with_first_byte(marker)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads the next value given the `first` byte.
+    pub fn read_value_with_first_byte(&mut self, first: u8) -> Result<MsgPackValue, Error> {
+        self.read_value_with_marker(first, 0)
+    }
+
+    /// Reads the value with the `marker` at the `depth`.
+    fn read_value_with_marker(&mut self, marker: u8, depth: usize) -> Result<MsgPackValue, Error> {
+        if depth > Self::MAX_DEPTH {
+            return Err(InvalidEncodedData {
+                reason: Some(format!("max depth exceeded: {}", Self::MAX_DEPTH).into()),
+            });
+        }
+        match marker {
+            NIL => return Ok(MsgPackValue::Nil),
+            FALSE => return Ok(MsgPackValue::Bool(false)),
+            TRUE => return Ok(MsgPackValue::Bool(true)),
+            FLOAT32 => return Ok(MsgPackValue::F32(f32::from_be_bytes(self.read_bytes()?))),
+            FLOAT64 => return Ok(MsgPackValue::F64(f64::from_be_bytes(self.read_bytes()?))),
+            _ => {}
+        }
+        if let Some(value) = self.read_integer(marker)? {
+            Ok(if value < 0 {
+                MsgPackValue::Int(value as i64)
+            } else {
+                MsgPackValue::UInt(value as u64)
+            })
+        } else if let Some(len) = self.read_str_len(marker)? {
+            Ok(MsgPackValue::Str(self.read_str_payload(len)?))
+        } else if let Some(len) = self.read_len(marker, [BIN8, BIN16, BIN32])? {
+            Ok(MsgPackValue::Bin(self.read_payload(len)?))
+        } else if let Some(len) = self.read_array_len_with_marker(marker)? {
+            let mut items: Vec<MsgPackValue> = Vec::default();
+            for _ in 0..len {
+                items.push(self.read_nested_value(depth + 1)?);
+            }
+            Ok(MsgPackValue::Array(items))
+        } else if let Some(len) = self.read_map_len_with_marker(marker)? {
+            let mut entries: Vec<(MsgPackValue, MsgPackValue)> = Vec::default();
+            for _ in 0..len {
+                let key: MsgPackValue = self.read_nested_value(depth + 1)?;
+                let value: MsgPackValue = self.read_nested_value(depth + 1)?;
+                entries.push((key, value));
+            }
+            Ok(MsgPackValue::Map(entries))
+        } else if let Some((ext_type, data)) = self.read_ext_with_marker(marker)? {
+            Ok(MsgPackValue::Ext(ext_type, data))
+        } else {
+            Err(InvalidEncodedData {
+                reason: Some(format!("invalid marker: {marker:#04X}").into()),
+            })
+        }
+    }
+
+    /// Reads the next required value at the `depth`.
+    fn read_nested_value(&mut self, depth: usize) -> Result<MsgPackValue, Error> {
+        let marker: u8 = read_single_byte(&mut self.r)?;
+        self.read_value_with_marker(marker, depth)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::Error;
+    use crate::msgpack::MsgPackReader;
+
+    #[test]
+    fn read_typed() -> Result<(), Error> {
+        let encoded: &[u8] =
+            b"\xC0\xC3\xD0\x80\xCF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xCA\x3F\xC0\x00\x00\
+            \xA2hi\xC4\x01\xFF\xD4\x01\x02\x92\x81";
+        let mut r: MsgPackReader<&[u8]> = MsgPackReader::new(encoded);
+        r.read_nil()?;
+        assert!(r.read_bool()?);
+        assert_eq!(r.read_int()?, -128);
+        assert_eq!(r.read_uint()?, u64::MAX);
+        assert_eq!(r.read_f64()?, 1.5);
+        assert_eq!(r.read_str()?, "hi");
+        assert_eq!(r.read_bin()?, [0xFF]);
+        assert_eq!(r.read_ext()?, (1, vec![2]));
+        assert_eq!(r.read_array_len()?, 2);
+        assert_eq!(r.read_map_len()?, 1);
+        assert_eq!(r.read_value()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn read_invalid() {
+        let mut r: MsgPackReader<&[u8]> = MsgPackReader::new(b"\xD0\x80");
+        assert!(matches!(r.read_uint(), Err(Error::IntegerOverflow)));
+
+        let mut r: MsgPackReader<&[u8]> =
+            MsgPackReader::new(b"\xCF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF");
+        assert!(matches!(r.read_int(), Err(Error::IntegerOverflow)));
+
+        let mut r: MsgPackReader<&[u8]> = MsgPackReader::new(b"\xA1a");
+        assert!(matches!(
+            r.read_bin(),
+            Err(Error::InvalidEncodedData { .. })
+        ));
+
+        let mut r: MsgPackReader<&[u8]> = MsgPackReader::new(b"\x00\x01");
+        assert!(matches!(
+            r.read_array_len(),
+            Err(Error::InvalidEncodedData { .. })
+        ));
+
+        let test_cases: &[&[u8]] = &[
+            b"\xC1",         // never used
+            b"\xA2\xC3\x28", // invalid UTF-8
+            b"\xA3ab",       // truncated string
+            b"\x92\x01",     // missing array item
+        ];
+        for encoded in test_cases {
+            let mut r: MsgPackReader<&[u8]> = MsgPackReader::new(encoded);
+            match r.read_value() {
+                Err(Error::InvalidEncodedData { .. }) | Err(Error::Stream(_)) => {}
+                result => panic!("encoded={encoded:?} result={result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn max_depth() {
+        let encoded: Vec<u8> = vec![0x91; MsgPackReader::<&[u8]>::MAX_DEPTH + 2];
+        let mut r: MsgPackReader<&[u8]> = MsgPackReader::new(&encoded);
+        assert!(matches!(
+            r.read_value(),
+            Err(Error::InvalidEncodedData { .. })
+        ));
+    }
+}
@@ -0,0 +1,229 @@
+use crate::Error::IntegerOverflow;
+use crate::msgpack::marker::{
+    bin_header_len, container_header_len, ext_header_len, int_len, len_u32, str_header_len,
+    uint_len,
+};
+use crate::msgpack::{MsgPackReader, MsgPackWriter};
+use crate::{Error, impl_decode_from_read_by_prefix};
+use std::io::{Read, Write};
+
+/// A complete MessagePack value.
+///
+/// Integers are encoded with their smallest representation. When decoded, non-negative integers
+/// are always `UInt` values and negative integers are always `Int` values.
+#[derive(Clone, PartialEq, Debug)]
+pub enum MsgPackValue {
+    /// The `nil` value.
+    Nil,
+
+    /// A boolean value.
+    Bool(bool),
+
+    /// An unsigned integer.
+    UInt(u64),
+
+    /// A signed integer.
+    Int(i64),
+
+    /// A single-precision float.
+    F32(f32),
+
+    /// A double-precision float.
+    F64(f64),
+
+    /// A UTF-8 string.
+    Str(String),
+
+    /// A binary value.
+    Bin(Vec<u8>),
+
+    /// An array of values.
+    Array(Vec<MsgPackValue>),
+
+    /// A map of key-value pairs.
+    Map(Vec<(MsgPackValue, MsgPackValue)>),
+
+    /// An extension value with its type & data.
+    Ext(i8, Vec<u8>),
+}
+
+impl From<u64> for MsgPackValue {
+    fn from(value: u64) -> Self {
+        Self::UInt(value)
+    }
+}
+
+impl From<i64> for MsgPackValue {
+    fn from(value: i64) -> Self {
+        if value < 0 {
+            Self::Int(value)
+        } else {
+            Self::UInt(value as u64)
+        }
+    }
+}
+
+impl From<bool> for MsgPackValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<f64> for MsgPackValue {
+    fn from(value: f64) -> Self {
+        Self::F64(value)
+    }
+}
+
+impl From<&str> for MsgPackValue {
+    fn from(value: &str) -> Self {
+        Self::Str(value.to_string())
+    }
+}
+
+impl From<String> for MsgPackValue {
+    fn from(value: String) -> Self {
+        Self::Str(value)
+    }
+}
+
+impl From<Vec<u8>> for MsgPackValue {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bin(value)
+    }
+}
+
+impl crate::EncodedLen for MsgPackValue {
+    fn encoded_len(&self) -> Result<usize, Error> {
+        let add = |a: usize, b: usize| a.checked_add(b).ok_or(IntegerOverflow);
+        match self {
+            Self::Nil | Self::Bool(_) => Ok(1),
+            Self::UInt(value) => Ok(uint_len(*value)),
+            Self::Int(value) => Ok(int_len(*value)),
+            Self::F32(_) => Ok(5),
+            Self::F64(_) => Ok(9),
+            Self::Str(value) => add(str_header_len(len_u32(value.len())?), value.len()),
+            Self::Bin(value) => add(bin_header_len(len_u32(value.len())?), value.len()),
+            Self::Array(items) => items.iter().try_fold(
+                container_header_len(len_u32(items.len())?),
+                |len: usize, item: &MsgPackValue| add(len, item.encoded_len()?),
+            ),
+            Self::Map(entries) => entries.iter().try_fold(
+                container_header_len(len_u32(entries.len())?),
+                |len: usize, (key, value): &(MsgPackValue, MsgPackValue)| {
+                    add(add(len, key.encoded_len()?)?, value.encoded_len()?)
+                },
+            ),
+            Self::Ext(_, data) => add(ext_header_len(len_u32(data.len())?), data.len()),
+        }
+    }
+}
+
+impl crate::EncodeToSlice for MsgPackValue {
+    unsafe fn encode_to_slice_unchecked(&self, target: &mut [u8]) -> Result<usize, Error> {
+        let mut w: &mut [u8] = target;
+        MsgPackWriter::new(&mut w).write_value(self)
+    }
+}
+
+impl crate::EncodeToWrite for MsgPackValue {
+    fn encode_to_write<W>(&self, w: &mut W) -> Result<usize, Error>
+    where
+        W: Write,
+    {
+        MsgPackWriter::new(w).write_value(self)
+    }
+}
+
+impl crate::DecodeFromReadPrefix for MsgPackValue {
+    fn decode_from_read_prefix_with_first_byte<R>(r: &mut R, first: u8) -> Result<Self, Error>
+    where
+        R: Read,
+    {
+        MsgPackReader::new(r).read_value_with_first_byte(first)
+    }
+}
+
+impl_decode_from_read_by_prefix!(MsgPackValue);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::msgpack::MsgPackValue;
+    use crate::test::test_io;
+
+    #[test]
+    fn msgpack_value() {
+        let test_cases: &[(MsgPackValue, &[u8])] = &[
+            (MsgPackValue::Nil, b"\xC0"),
+            (MsgPackValue::from(false), b"\xC2"),
+            (MsgPackValue::from(true), b"\xC3"),
+            (MsgPackValue::from(0u64), b"\x00"),
+            (MsgPackValue::from(127u64), b"\x7F"),
+            (MsgPackValue::from(200u64), b"\xCC\xC8"),
+            (MsgPackValue::from(1_000u64), b"\xCD\x03\xE8"),
+            (MsgPackValue::from(100_000u64), b"\xCE\x00\x01\x86\xA0"),
+            (
+                MsgPackValue::from(u64::MAX),
+                b"\xCF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+            ),
+            (MsgPackValue::from(-1i64), b"\xFF"),
+            (MsgPackValue::from(-100i64), b"\xD0\x9C"),
+            (MsgPackValue::from(-1_000i64), b"\xD1\xFC\x18"),
+            (MsgPackValue::from(-100_000i64), b"\xD2\xFF\xFE\x79\x60"),
+            (
+                MsgPackValue::from(i64::MIN),
+                b"\xD3\x80\x00\x00\x00\x00\x00\x00\x00",
+            ),
+            (MsgPackValue::F32(1.5), b"\xCA\x3F\xC0\x00\x00"),
+            (
+                MsgPackValue::from(1.5),
+                b"\xCB\x3F\xF8\x00\x00\x00\x00\x00\x00",
+            ),
+            (MsgPackValue::from(""), b"\xA0"),
+            (MsgPackValue::from("hello"), b"\xA5hello"),
+            (
+                MsgPackValue::from("a".repeat(32)),
+                &[b"\xD9\x20".as_slice(), "a".repeat(32).as_bytes()].concat(),
+            ),
+            (
+                MsgPackValue::from("a".repeat(256)),
+                &[b"\xDA\x01\x00".as_slice(), "a".repeat(256).as_bytes()].concat(),
+            ),
+            (MsgPackValue::from(Vec::default()), b"\xC4\x00"),
+            (MsgPackValue::from(vec![1, 2, 3]), b"\xC4\x03\x01\x02\x03"),
+            (MsgPackValue::Array(Vec::default()), b"\x90"),
+            (
+                MsgPackValue::Array(vec![
+                    MsgPackValue::from(1u64),
+                    MsgPackValue::Array(vec![MsgPackValue::from(2u64)]),
+                ]),
+                b"\x92\x01\x91\x02",
+            ),
+            (
+                MsgPackValue::Array(vec![MsgPackValue::Nil; 16]),
+                &[b"\xDC\x00\x10".as_slice(), &[0xC0; 16]].concat(),
+            ),
+            (MsgPackValue::Map(Vec::default()), b"\x80"),
+            (
+                MsgPackValue::Map(vec![
+                    (MsgPackValue::from("a"), MsgPackValue::from(1u64)),
+                    (MsgPackValue::from("b"), MsgPackValue::from(-2i64)),
+                ]),
+                b"\x82\xA1a\x01\xA1b\xFE",
+            ),
+            (
+                MsgPackValue::Ext(1, vec![0xAA; 4]),
+                b"\xD6\x01\xAA\xAA\xAA\xAA",
+            ),
+            (
+                MsgPackValue::Ext(-1, vec![0xAA; 3]),
+                b"\xC7\x03\xFF\xAA\xAA\xAA",
+            ),
+        ];
+
+        for (value, encoded) in test_cases {
+            test_io(value, encoded, false);
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use crate::Error;
+use crate::msgpack::MsgPackValue;
+use crate::msgpack::marker::{
+    ARRAY16, ARRAY32, BIN8, BIN16, BIN32, EXT8, EXT16, EXT32, FALSE, FIXARRAY, FIXEXT1, FIXEXT2,
+    FIXEXT4, FIXEXT8, FIXEXT16, FIXMAP, FIXSTR, FLOAT32, FLOAT64, INT8, INT16, INT32, INT64, MAP16,
+    MAP32, NIL, STR8, STR16, STR32, TRUE, UINT8, UINT16, UINT32, UINT64, len_u32,
+};
+use std::io::Write;
+
+/// Responsible for writing MessagePack values to a `Write`.
+///
+/// Each write function returns the number of bytes written. Integers and lengths are always
+/// written with their smallest representation.
+#[derive(Debug)]
+pub struct MsgPackWriter<W> {
+    w: W,
+}
+
+impl<W: Write> MsgPackWriter<W> {
+    //! Construction
+
+    /// Creates a new MessagePack writer.
+    pub fn new(w: W) -> Self {
+        Self { w }
+    }
+
+    /// Gets the underlying `Write`.
+    pub fn into_inner(self) -> W {
+        self.w
+    }
+}
+
+impl<W: Write> MsgPackWriter<W> {
+    //! Headers
+
+    /// Writes the `marker` followed by the big-endian `bytes`.
+    fn write_marker_bytes<const N: usize>(
+        &mut self,
+        marker: u8,
+        bytes: [u8; N],
+    ) -> Result<usize, Error> {
+        self.w.write_all(&[marker])?;
+        self.w.write_all(&bytes)?;
+        Ok(1 + N)
+    }
+
+    /// Writes the `len` with the smallest of the `markers` for 8, 16 and 32-bit lengths.
+    fn write_len(&mut self, len: u32, markers: [u8; 3]) -> Result<usize, Error> {
+        if let Ok(len) = u8::try_from(len) {
+            self.write_marker_bytes(markers[0], [len])
+        } else if let Ok(len) = u16::try_from(len) {
+            self.write_marker_bytes(markers[1], len.to_be_bytes())
+        } else {
+            self.write_marker_bytes(markers[2], len.to_be_bytes())
+        }
+    }
+}
+
+impl<W: Write> MsgPackWriter<W> {
+    //! Scalars
+
+    /// Writes the `nil` value.
+    pub fn write_nil(&mut self) -> Result<usize, Error> {
+        self.w.write_all(&[NIL])?;
+        Ok(1)
+    }
+
+    /// Writes a boolean value.
+    pub fn write_bool(&mut self, value: bool) -> Result<usize, Error> {
+        self.w.write_all(&[if value { TRUE } else { FALSE }])?;
+        Ok(1)
+    }
+
+    /// Writes an unsigned integer.
+    pub fn write_uint(&mut self, value: u64) -> Result<usize, Error> {
+        if value <= 0x7F {
+            self.w.write_all(&[value as u8])?;
+            Ok(1)
+        } else if let Ok(value) = u8::try_from(value) {
+            self.write_marker_bytes(UINT8, [value])
+        } else if let Ok(value) = u16::try_from(value) {
+            self.write_marker_bytes(UINT16, value.to_be_bytes())
+        } else if let Ok(value) = u32::try_from(value) {
+            self.write_marker_bytes(UINT32, value.to_be_bytes())
+        } else {
+            self.write_marker_bytes(UINT64, value.to_be_bytes())
+        }
+    }
+
+    /// Writes a signed integer.
+    ///
+    /// Non-negative values are written as unsigned integers.
+    pub fn write_int(&mut self, value: i64) -> Result<usize, Error> {
+        if value >= 0 {
+            self.write_uint(value as u64)
+        } else if value >= -32 {
+            self.w.write_all(&[value as u8])?;
+            Ok(1)
+        } else if let Ok(value) = i8::try_from(value) {
+            self.write_marker_bytes(INT8, value.to_be_bytes())
+        } else if let Ok(value) = i16::try_from(value) {
+            self.write_marker_bytes(INT16, value.to_be_bytes())
+        } else if let Ok(value) = i32::try_from(value) {
+            self.write_marker_bytes(INT32, value.to_be_bytes())
+        } else {
+            self.write_marker_bytes(INT64, value.to_be_bytes())
+        }
+    }
+
+    /// Writes a single-precision float.
+    pub fn write_f32(&mut self, value: f32) -> Result<usize, Error> {
+        self.write_marker_bytes(FLOAT32, value.to_be_bytes())
+    }
+
+    /// Writes a double-precision float.
+    pub fn write_f64(&mut self, value: f64) -> Result<usize, Error> {
+        self.write_marker_bytes(FLOAT64, value.to_be_bytes())
+    }
+}
+
+impl<W: Write> MsgPackWriter<W> {
+    //! Strings & Binary
+
+    /// Writes a string.
+    pub fn write_str(&mut self, value: &str) -> Result<usize, Error> {
+        let len: u32 = len_u32(value.len())?;
+        let header_len: usize = if len < 32 {
+            self.w.write_all(&[FIXSTR | len as u8])?;
+            1
+        } else {
+            self.write_len(len, [STR8, STR16, STR32])?
+        };
+        self.w.write_all(value.as_bytes())?;
+        Ok(header_len + value.len())
+    }
+
+    /// Writes a binary value.
+    pub fn write_bin(&mut self, value: &[u8]) -> Result<usize, Error> {
+        let len: u32 = len_u32(value.len())?;
+        let header_len: usize = self.write_len(len, [BIN8, BIN16, BIN32])?;
+        self.w.write_all(value)?;
+        Ok(header_len + value.len())
+    }
+
+    /// Writes an extension value with the `ext_type` and `data`.
+    pub fn write_ext(&mut self, ext_type: i8, data: &[u8]) -> Result<usize, Error> {
+        let len: u32 = len_u32(data.len())?;
+        let header_len: usize = match len {
+            1 => self.write_marker_bytes(FIXEXT1, ext_type.to_be_bytes())?,
+            2 => self.write_marker_bytes(FIXEXT2, ext_type.to_be_bytes())?,
+            4 => self.write_marker_bytes(FIXEXT4, ext_type.to_be_bytes())?,
+            8 => self.write_marker_bytes(FIXEXT8, ext_type.to_be_bytes())?,
+            16 => self.write_marker_bytes(FIXEXT16, ext_type.to_be_bytes())?,
+            _ => {
+                let len_len: usize = self.write_len(len, [EXT8, EXT16, EXT32])?;
+                self.w.write_all(&ext_type.to_be_bytes())?;
+                len_len + 1
+            }
+        };
+        self.w.write_all(data)?;
+        Ok(header_len + data.len())
+    }
+}
+
+impl<W: Write> MsgPackWriter<W> {
+    //! Containers
+
+    /// Writes the header of an array with the `len` items.
+    ///
+    /// The header must be followed by the `len` items.
+    pub fn write_array_len(&mut self, len: usize) -> Result<usize, Error> {
+        let len: u32 = len_u32(len)?;
+        if len < 16 {
+            self.w.write_all(&[FIXARRAY | len as u8])?;
+            Ok(1)
+        } else if let Ok(len) = u16::try_from(len) {
+            self.write_marker_bytes(ARRAY16, len.to_be_bytes())
+        } else {
+            self.write_marker_bytes(ARRAY32, len.to_be_bytes())
+        }
+    }
+
+    /// Writes the header of a map with the `len` key-value pairs.
+    ///
+    /// The header must be followed by the `len` keys & values alternating.
+    pub fn write_map_len(&mut self, len: usize) -> Result<usize, Error> {
+        let len: u32 = len_u32(len)?;
+        if len < 16 {
+            self.w.write_all(&[FIXMAP | len as u8])?;
+            Ok(1)
+        } else if let Ok(len) = u16::try_from(len) {
+            self.write_marker_bytes(MAP16, len.to_be_bytes())
+        } else {
+            self.write_marker_bytes(MAP32, len.to_be_bytes())
+        }
+    }
+}
+
+impl<W: Write> MsgPackWriter<W> {
+    //! Values
+
+    /// Writes the `value`.
+    pub fn write_value(&mut self, value: &MsgPackValue) -> Result<usize, Error> {
+        match value {
+            MsgPackValue::Nil => self.write_nil(),
+            MsgPackValue::Bool(value) => self.write_bool(*value),
+            MsgPackValue::UInt(value) => self.write_uint(*value),
+            MsgPackValue::Int(value) => self.write_int(*value),
+            MsgPackValue::F32(value) => self.write_f32(*value),
+            MsgPackValue::F64(value) => self.write_f64(*value),
+            MsgPackValue::Str(value) => self.write_str(value),
+            MsgPackValue::Bin(value) => self.write_bin(value),
+            MsgPackValue::Array(items) => {
+                let mut len: usize = self.write_array_len(items.len())?;
+                for item in items {
+                    len += self.write_value(item)?;
+                }
+                Ok(len)
+            }
+            MsgPackValue::Map(entries) => {
+                let mut len: usize = self.write_map_len(entries.len())?;
+                for (key, value) in entries {
+                    len += self.write_value(key)?;
+                    len += self.write_value(value)?;
+                }
+                Ok(len)
+            }
+            MsgPackValue::Ext(ext_type, data) => self.write_ext(*ext_type, data),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::Error;
+    use crate::msgpack::MsgPackWriter;
+
+    #[test]
+    fn write_ints() -> Result<(), Error> {
+        let test_cases: &[(i64, &[u8])] = &[
+            (0, b"\x00"),
+            (127, b"\x7F"),
+            (128, b"\xCC\x80"),
+            (255, b"\xCC\xFF"),
+            (256, b"\xCD\x01\x00"),
+            (65_536, b"\xCE\x00\x01\x00\x00"),
+            (4_294_967_296, b"\xCF\x00\x00\x00\x01\x00\x00\x00\x00"),
+            (-1, b"\xFF"),
+            (-32, b"\xE0"),
+            (-33, b"\xD0\xDF"),
+            (-128, b"\xD0\x80"),
+            (-129, b"\xD1\xFF\x7F"),
+            (-32_769, b"\xD2\xFF\xFF\x7F\xFF"),
+            (i64::MIN, b"\xD3\x80\x00\x00\x00\x00\x00\x00\x00"),
+        ];
+
+        for (value, expected) in test_cases {
+            let mut w: MsgPackWriter<Vec<u8>> = MsgPackWriter::new(Vec::default());
+            assert_eq!(w.write_int(*value)?, expected.len());
+            assert_eq!(w.into_inner(), *expected, "value={value}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn write_ext() -> Result<(), Error> {
+        let test_cases: &[(usize, &[u8])] = &[
+            (0, b"\xC7\x00\x05"),
+            (1, b"\xD4\x05"),
+            (2, b"\xD5\x05"),
+            (3, b"\xC7\x03\x05"),
+            (4, b"\xD6\x05"),
+            (8, b"\xD7\x05"),
+            (16, b"\xD8\x05"),
+            (17, b"\xC7\x11\x05"),
+            (256, b"\xC8\x01\x00\x05"),
+        ];
+
+        for (len, header) in test_cases {
+            let data: Vec<u8> = vec![0xAB; *len];
+            let mut w: MsgPackWriter<Vec<u8>> = MsgPackWriter::new(Vec::default());
+            assert_eq!(w.write_ext(5, &data)?, header.len() + len);
+            let encoded: Vec<u8> = w.into_inner();
+            assert_eq!(&encoded[..header.len()], *header, "len={len}");
+            assert_eq!(&encoded[header.len()..], data);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt::{Display, Formatter};
+
+macro_rules! impl_prefix_var_int {
+    ($target_type:ident, $unsigned_type:ty) => {
+        /// A prefix var-int encoded `$unsigned_type` value.
+        ///
+        /// # Format
+        /// The count of leading one-bits in the first byte gives the encoded length:
+        /// - `0xxxxxxx`: 7 value bits.
+        /// - `10xxxxxx` + 1 byte: 14 value bits.
+        /// - `110xxxxx` + 2 bytes: 21 value bits.
+        /// - `1110xxxx` + 3 bytes: 28 value bits.
+        /// - `1111nnnn` + `n + 1` bytes: the entire value.
+        ///
+        /// The first byte holds the lowest value bits and the following bytes hold the remaining
+        /// value bits in little-endian order.
+        #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+        pub struct $target_type {
+            pub(in crate::prefix_var_int) value: $unsigned_type,
+        }
+
+        impl From<$unsigned_type> for $target_type {
+            fn from(value: $unsigned_type) -> Self {
+                Self { value }
+            }
+        }
+
+        impl From<&$unsigned_type> for $target_type {
+            fn from(value: &$unsigned_type) -> Self {
+                Self::from(*value)
+            }
+        }
+
+        impl $target_type {
+            //! Constants
+
+            /// The maximum length of a prefix var-int encoded `$unsigned_type` value.
+            pub const MAX_ENCODED_LEN: usize = 1 + size_of::<$unsigned_type>();
+
+            /// The maximum number of value bits with a length prefix of leading one-bits.
+            pub(in crate::value::prefix_var_int) const MAX_SHORT_BITS: u32 = 28;
+
+            /// The first byte marker for values encoded with an explicit byte count.
+            pub(in crate::value::prefix_var_int) const LONG_MARKER: u8 = 0xF0;
+        }
+
+        impl $target_type {
+            //! Properties
+
+            /// Gets the value.
+            pub fn value(self) -> $unsigned_type {
+                self.value
+            }
+        }
+
+        impl Display for $target_type {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.value)
+            }
+        }
+    };
+}
+
+impl_prefix_var_int!(PrefixVarInt32, u32);
+impl_prefix_var_int!(PrefixVarInt64, u64);
+impl_prefix_var_int!(PrefixVarInt128, u128);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::EncodedLen;
+    use crate::prefix_var_int::{PrefixVarInt32, PrefixVarInt64, PrefixVarInt128};
+    use std::error::Error;
+
+    #[test]
+    fn max_encoded_len() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            PrefixVarInt32::MAX_ENCODED_LEN,
+            PrefixVarInt32::from(u32::MAX).encoded_len()?
+        );
+        assert_eq!(
+            PrefixVarInt64::MAX_ENCODED_LEN,
+            PrefixVarInt64::from(u64::MAX).encoded_len()?
+        );
+        assert_eq!(
+            PrefixVarInt128::MAX_ENCODED_LEN,
+            PrefixVarInt128::from(u128::MAX).encoded_len()?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use crate::prefix_var_int::impl_prefix_var_int::{PrefixVarInt32, PrefixVarInt64, PrefixVarInt128};
+
+macro_rules! impl_prefix_var_int_decode {
+    ($target_type:ident, $unsigned_type:ty) => {
+        impl $crate::DecodeFromReadPrefix for $target_type {
+            fn decode_from_read_prefix_with_first_byte<R>(
+                r: &mut R,
+                first: u8,
+            ) -> Result<Self, $crate::Error>
+            where
+                R: std::io::Read,
+            {
+                let mut buffer: [u8; size_of::<$unsigned_type>()] =
+                    [0u8; size_of::<$unsigned_type>()];
+                let following: usize = first.leading_ones() as usize;
+                if following == 0 {
+                    Ok(Self::from(first as $unsigned_type))
+                } else if following < 4 {
+                    r.read_exact(&mut buffer[..following])?;
+                    let low: $unsigned_type = (first & (0x7Fu8 >> following)) as $unsigned_type;
+                    let rest: $unsigned_type = <$unsigned_type>::from_le_bytes(buffer);
+                    Ok(Self::from(low | (rest << (7 - following))))
+                } else {
+                    let following: usize = (first & !Self::LONG_MARKER) as usize + 1;
+                    if following > buffer.len() {
+                        Err($crate::Error::InvalidEncodedData {
+                            reason: Some(format!("invalid length prefix: {first:#04X}").into()),
+                        })
+                    } else {
+                        r.read_exact(&mut buffer[..following])?;
+                        Ok(Self::from(<$unsigned_type>::from_le_bytes(buffer)))
+                    }
+                }
+            }
+        }
+
+        $crate::impl_decode_from_read_by_prefix!($target_type);
+    };
+}
+
+impl_prefix_var_int_decode!(PrefixVarInt32, u32);
+impl_prefix_var_int_decode!(PrefixVarInt64, u64);
+impl_prefix_var_int_decode!(PrefixVarInt128, u128);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::DecodeFromReadPrefix;
+    use crate::prefix_var_int::{PrefixVarInt32, PrefixVarInt64};
+    use std::io::Cursor;
+
+    fn decodes_as_invalid<T: DecodeFromReadPrefix>(encoded: &[u8]) -> bool {
+        matches!(
+            T::decode_from_read_prefix(&mut Cursor::new(encoded)),
+            Err(crate::Error::InvalidEncodedData { .. })
+        )
+    }
+
+    #[test]
+    fn invalid_length_prefix() {
+        assert!(decodes_as_invalid::<PrefixVarInt32>(
+            b"\xF4\x00\x00\x00\x00\x00"
+        ));
+        assert!(decodes_as_invalid::<PrefixVarInt64>(
+            b"\xF8\x00\x00\x00\x00\x00\x00\x00\x00\x00"
+        ));
+    }
+
+    #[test]
+    fn truncated() {
+        assert!(matches!(
+            PrefixVarInt32::decode_from_read_prefix(&mut Cursor::new(b"\xC0\x00")),
+            Err(crate::Error::Stream(_))
+        ));
+    }
+}
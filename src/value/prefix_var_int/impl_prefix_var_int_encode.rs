@@ -0,0 +1,116 @@
+use crate::prefix_var_int::impl_prefix_var_int::{PrefixVarInt32, PrefixVarInt64, PrefixVarInt128};
+use crate::{Error, impl_encode_to_write_stack_buf};
+
+macro_rules! impl_prefix_var_int_encode {
+    ($target_type:ty, $unsigned_type:ty) => {
+        impl $crate::EncodedLen for $target_type {
+            fn encoded_len(&self) -> Result<usize, Error> {
+                let bits: u32 = <$unsigned_type>::BITS - (self.value | 1).leading_zeros();
+                if bits <= Self::MAX_SHORT_BITS {
+                    Ok(bits.div_ceil(7) as usize)
+                } else {
+                    Ok(1 + bits.div_ceil(8) as usize)
+                }
+            }
+        }
+
+        impl $crate::EncodeToSlice for $target_type {
+            unsafe fn encode_to_slice_unchecked(&self, target: &mut [u8]) -> Result<usize, Error> {
+                use $crate::EncodedLen;
+
+                let encoded_len: usize = self.encoded_len()?;
+                let following: usize = encoded_len - 1;
+                let (first, rest): (u8, $unsigned_type) = if encoded_len == 1 {
+                    (self.value as u8, 0)
+                } else if following < 4 {
+                    let value_bits: usize = 7 - following;
+                    let prefix: u8 = !(0xFFu8 >> following);
+                    let low: u8 = self.value as u8 & (0x7Fu8 >> following);
+                    (prefix | low, self.value >> value_bits)
+                } else {
+                    (Self::LONG_MARKER | (following - 1) as u8, self.value)
+                };
+                let rest: [u8; size_of::<$unsigned_type>()] = rest.to_le_bytes();
+                unsafe {
+                    *target.get_unchecked_mut(0) = first;
+                    target
+                        .get_unchecked_mut(1..encoded_len)
+                        .copy_from_slice(rest.get_unchecked(..following));
+                }
+                Ok(encoded_len)
+            }
+        }
+
+        impl_encode_to_write_stack_buf!($target_type, Self::MAX_ENCODED_LEN);
+    };
+}
+
+impl_prefix_var_int_encode!(PrefixVarInt32, u32);
+impl_prefix_var_int_encode!(PrefixVarInt64, u64);
+impl_prefix_var_int_encode!(PrefixVarInt128, u128);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::prefix_var_int::{PrefixVarInt32, PrefixVarInt64, PrefixVarInt128};
+    use crate::test::test_io;
+
+    #[test]
+    fn prefix_var_int_32() {
+        let test_cases: &[(u32, &[u8])] = &[
+            (0x00, b"\x00"),                        // 0 bits
+            (0x7F, b"\x7F"),                        // highest one byte value
+            (0x80, b"\x80\x02"),                    // lowest two byte value
+            (0x3FFF, b"\xBF\xFF"),                  // highest two byte value
+            (0x4000, b"\xC0\x00\x02"),              // lowest three byte value
+            (0x1F_FFFF, b"\xDF\xFF\xFF"),           // highest three byte value
+            (0x20_0000, b"\xE0\x00\x00\x02"),       // lowest four byte value
+            (0x0FFF_FFFF, b"\xEF\xFF\xFF\xFF"),     // highest four byte value
+            (0x1000_0000, b"\xF3\x00\x00\x00\x10"), // lowest five byte value
+            (u32::MAX, b"\xF3\xFF\xFF\xFF\xFF"),
+        ];
+
+        for (value, encoded) in test_cases {
+            let value: PrefixVarInt32 = PrefixVarInt32::from(value);
+            test_io(&value, encoded, false);
+        }
+    }
+
+    #[test]
+    fn prefix_var_int_64() {
+        let test_cases: &[(u64, &[u8])] = &[
+            (0x00, b"\x00"),
+            (0x7F, b"\x7F"),
+            (0x80, b"\x80\x02"),
+            (0x0FFF_FFFF, b"\xEF\xFF\xFF\xFF"),
+            (0x1000_0000, b"\xF3\x00\x00\x00\x10"),
+            (0xFFFF_FFFF, b"\xF3\xFF\xFF\xFF\xFF"),
+            (0x1_0000_0000, b"\xF4\x00\x00\x00\x00\x01"),
+            (u64::MAX, b"\xF7\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"),
+        ];
+
+        for (value, encoded) in test_cases {
+            let value: PrefixVarInt64 = PrefixVarInt64::from(value);
+            test_io(&value, encoded, false);
+        }
+    }
+
+    #[test]
+    fn prefix_var_int_128() {
+        let test_cases: &[(u128, &[u8])] = &[
+            (0x00, b"\x00"),
+            (0x7F, b"\x7F"),
+            (0x80, b"\x80\x02"),
+            (u64::MAX as u128, b"\xF7\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"),
+            (
+                u128::MAX,
+                b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+            ),
+        ];
+
+        for (value, encoded) in test_cases {
+            let value: PrefixVarInt128 = PrefixVarInt128::from(value);
+            test_io(&value, encoded, false);
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub use impl_prefix_var_int::*;
+
+mod impl_prefix_var_int;
+
+mod impl_prefix_var_int_decode;
+mod impl_prefix_var_int_encode;
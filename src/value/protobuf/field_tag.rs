@@ -0,0 +1,166 @@
+use crate::Error::InvalidEncodedData;
+use crate::protobuf::WireType;
+use crate::var_int::VarInt32;
+use crate::{
+    DecodeFromReadPrefix, EncodeToSlice, EncodedLen, Error, impl_decode_from_read_by_prefix,
+    impl_encode_to_write_stack_buf,
+};
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+
+/// A Protocol Buffers field tag. (a field number and a wire type)
+///
+/// The tag is encoded as the var-int: `(field_number << 3) | wire_type`.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct FieldTag {
+    field_number: u32,
+    wire_type: WireType,
+}
+
+impl FieldTag {
+    //! Constants
+
+    /// The minimum field number.
+    pub const MIN_FIELD_NUMBER: u32 = 1;
+
+    /// The maximum field number.
+    pub const MAX_FIELD_NUMBER: u32 = (1 << 29) - 1;
+}
+
+impl FieldTag {
+    //! Construction
+
+    /// Creates a new field tag.
+    ///
+    /// Returns `None` if the `field_number` is not in `[MIN_FIELD_NUMBER, MAX_FIELD_NUMBER]`.
+    pub const fn new(field_number: u32, wire_type: WireType) -> Option<Self> {
+        if field_number >= Self::MIN_FIELD_NUMBER && field_number <= Self::MAX_FIELD_NUMBER {
+            Some(Self {
+                field_number,
+                wire_type,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new field tag.
+    ///
+    /// Returns an `InvalidEncodedData` error if the `field_number` is invalid.
+    pub(in crate::value::protobuf) fn try_new(
+        field_number: u32,
+        wire_type: WireType,
+    ) -> Result<Self, Error> {
+        Self::new(field_number, wire_type).ok_or_else(|| InvalidEncodedData {
+            reason: Some(format!("invalid field number: {field_number}").into()),
+        })
+    }
+}
+
+impl FieldTag {
+    //! Properties
+
+    /// Gets the field number.
+    pub const fn field_number(self) -> u32 {
+        self.field_number
+    }
+
+    /// Gets the wire type.
+    pub const fn wire_type(self) -> WireType {
+        self.wire_type
+    }
+
+    /// Gets the var-int value of the tag.
+    fn var_int(self) -> VarInt32 {
+        VarInt32::from((self.field_number << 3) | self.wire_type.bits() as u32)
+    }
+}
+
+impl Display for FieldTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.field_number, self.wire_type)
+    }
+}
+
+impl EncodedLen for FieldTag {
+    fn encoded_len(&self) -> Result<usize, Error> {
+        self.var_int().encoded_len()
+    }
+}
+
+impl EncodeToSlice for FieldTag {
+    unsafe fn encode_to_slice_unchecked(&self, target: &mut [u8]) -> Result<usize, Error> {
+        unsafe { self.var_int().encode_to_slice_unchecked(target) }
+    }
+}
+
+impl_encode_to_write_stack_buf!(FieldTag, VarInt32::MAX_ENCODED_LEN);
+
+impl DecodeFromReadPrefix for FieldTag {
+    fn decode_from_read_prefix_with_first_byte<R>(r: &mut R, first: u8) -> Result<Self, Error>
+    where
+        R: Read,
+    {
+        let value: u32 = VarInt32::decode_from_read_prefix_with_first_byte(r, first)?.value();
+        let wire_type: WireType =
+            WireType::from_bits((value & 0x07) as u8).ok_or_else(|| InvalidEncodedData {
+                reason: Some(format!("invalid wire type: {}", value & 0x07).into()),
+            })?;
+        Self::try_new(value >> 3, wire_type)
+    }
+}
+
+impl_decode_from_read_by_prefix!(FieldTag);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::DecodeFromReadPrefix;
+    use crate::protobuf::{FieldTag, WireType};
+    use crate::test::test_io;
+    use std::io::Cursor;
+
+    #[test]
+    fn new() {
+        assert!(FieldTag::new(0, WireType::Varint).is_none());
+        assert!(FieldTag::new(1, WireType::Varint).is_some());
+        assert!(FieldTag::new(FieldTag::MAX_FIELD_NUMBER, WireType::Varint).is_some());
+        assert!(FieldTag::new(FieldTag::MAX_FIELD_NUMBER + 1, WireType::Varint).is_none());
+    }
+
+    #[test]
+    fn field_tag() {
+        let test_cases: &[(u32, WireType, &[u8])] = &[
+            (1, WireType::Varint, b"\x08"),
+            (2, WireType::LengthDelimited, b"\x12"),
+            (15, WireType::Fixed32, b"\x7D"),
+            (16, WireType::Fixed64, b"\x81\x01"),
+            (
+                FieldTag::MAX_FIELD_NUMBER,
+                WireType::Varint,
+                b"\xF8\xFF\xFF\xFF\x0F",
+            ),
+        ];
+
+        for (field_number, wire_type, encoded) in test_cases {
+            let tag: FieldTag = FieldTag::new(*field_number, *wire_type).unwrap();
+            test_io(&tag, encoded, false);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let test_cases: &[&[u8]] = &[
+            b"\x00", // field number 0
+            b"\x0E", // wire type 6
+            b"\x0F", // wire type 7
+        ];
+
+        for encoded in test_cases {
+            assert!(matches!(
+                FieldTag::decode_from_read_prefix(&mut Cursor::new(encoded)),
+                Err(crate::Error::InvalidEncodedData { .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub use field_tag::*;
+pub use wire_reader::*;
+pub use wire_type::*;
+pub use wire_writer::*;
+
+mod field_tag;
+mod wire_reader;
+mod wire_type;
+mod wire_writer;
@@ -0,0 +1,302 @@
+use crate::Error::InvalidEncodedData;
+use crate::protobuf::{FieldTag, WireType};
+use crate::var_int::{VarInt32, VarInt64, VarIntSize};
+use crate::{DecodeFromRead, DecodeFromReadPrefix, Error};
+use std::io;
+use std::io::ErrorKind::UnexpectedEof;
+use std::io::Read;
+
+/// Responsible for reading Protocol Buffers wire format fields from a `Read`.
+///
+/// Fields are read by calling `read_tag` followed by the read function matching the wire type of
+/// the tag, or `skip` to skip the value of an unknown field.
+#[derive(Debug)]
+pub struct WireReader<R> {
+    r: R,
+}
+
+impl<R: Read> WireReader<R> {
+    //! Constants
+
+    /// The maximum nesting depth of groups when skipping values.
+    pub const MAX_DEPTH: usize = 256;
+}
+
+impl<R: Read> WireReader<R> {
+    //! Construction
+
+    /// Creates a new wire reader.
+    pub fn new(r: R) -> Self {
+        Self { r }
+    }
+
+    /// Gets the underlying `Read`.
+    pub fn into_inner(self) -> R {
+        self.r
+    }
+}
+
+impl<R: Read> WireReader<R> {
+    //! Tags
+
+    /// Reads the next field tag.
+    ///
+    /// Returns `None` if the `Read` is empty.
+    pub fn read_tag(&mut self) -> Result<Option<FieldTag>, Error> {
+        FieldTag::decode_from_read_prefix_optional(&mut self.r)
+    }
+}
+
+impl<R: Read> WireReader<R> {
+    //! Values
+
+    /// Reads a var-int value.
+    pub fn read_varint(&mut self) -> Result<u64, Error> {
+        Ok(VarInt64::decode_from_read_prefix(&mut self.r)?.value())
+    }
+
+    /// Reads a signed `int32` or `int64` var-int value.
+    pub fn read_int(&mut self) -> Result<i64, Error> {
+        Ok(self.read_varint()? as i64)
+    }
+
+    /// Reads a zigzag encoded `sint32` var-int value.
+    pub fn read_sint32(&mut self) -> Result<i32, Error> {
+        Ok(VarInt32::decode_from_read_prefix(&mut self.r)?.to_zigzag())
+    }
+
+    /// Reads a zigzag encoded `sint64` var-int value.
+    pub fn read_sint64(&mut self) -> Result<i64, Error> {
+        Ok(VarInt64::decode_from_read_prefix(&mut self.r)?.to_zigzag())
+    }
+
+    /// Reads a `bool` var-int value.
+    pub fn read_bool(&mut self) -> Result<bool, Error> {
+        Ok(self.read_varint()? != 0)
+    }
+
+    /// Reads a little-endian 32-bit value.
+    pub fn read_fixed32(&mut self) -> Result<u32, Error> {
+        let mut buffer: [u8; 4] = [0u8; 4];
+        self.r.read_exact(&mut buffer)?;
+        Ok(u32::from_le_bytes(buffer))
+    }
+
+    /// Reads a little-endian 64-bit value.
+    pub fn read_fixed64(&mut self) -> Result<u64, Error> {
+        let mut buffer: [u8; 8] = [0u8; 8];
+        self.r.read_exact(&mut buffer)?;
+        Ok(u64::from_le_bytes(buffer))
+    }
+
+    /// Reads a `float` value.
+    pub fn read_float(&mut self) -> Result<f32, Error> {
+        Ok(f32::from_bits(self.read_fixed32()?))
+    }
+
+    /// Reads a `double` value.
+    pub fn read_double(&mut self) -> Result<f64, Error> {
+        Ok(f64::from_bits(self.read_fixed64()?))
+    }
+
+    /// Reads a length-delimited `bytes` value.
+    pub fn read_bytes(&mut self) -> Result<Vec<u8>, Error> {
+        let len: usize = VarIntSize::decode_from_read_prefix(&mut self.r)?.value();
+        let mut value: Vec<u8> = Vec::default();
+        (&mut self.r).take(len as u64).read_to_end(&mut value)?;
+        if value.len() != len {
+            Err(io::Error::from(UnexpectedEof).into())
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Reads a length-delimited `string` value.
+    pub fn read_string(&mut self) -> Result<String, Error> {
+        String::from_utf8(self.read_bytes()?).map_err(|error| InvalidEncodedData {
+            reason: Some(error.into()),
+        })
+    }
+
+    /// Reads a length-delimited value. (such as an embedded message)
+    pub fn read_message<T>(&mut self) -> Result<T, Error>
+    where
+        T: DecodeFromRead,
+    {
+        T::decode_from_read_length_prefixed(&mut self.r)
+    }
+}
+
+impl<R: Read> WireReader<R> {
+    //! Skip
+
+    /// Skips the value of the field with the `tag`.
+    ///
+    /// Groups are skipped up to and including the matching end group tag. Groups nested deeper than
+    /// `MAX_DEPTH` result in an `InvalidEncodedData` error.
+    pub fn skip(&mut self, tag: FieldTag) -> Result<(), Error> {
+        match tag.wire_type() {
+            WireType::Varint => self.read_varint().map(|_| ()),
+            WireType::Fixed64 => self.skip_bytes(8),
+            WireType::LengthDelimited => {
+                let len: usize = VarIntSize::decode_from_read_prefix(&mut self.r)?.value();
+                self.skip_bytes(len as u64)
+            }
+            WireType::StartGroup => self.skip_group(tag.field_number()),
+            WireType::EndGroup => Err(InvalidEncodedData {
+                reason: Some(format!("unexpected end group: {tag}").into()),
+            }),
+            WireType::Fixed32 => self.skip_bytes(4),
+        }
+    }
+
+    /// Skips `len` bytes.
+    fn skip_bytes(&mut self, len: u64) -> Result<(), Error> {
+        let skipped: u64 = io::copy(&mut (&mut self.r).take(len), &mut io::sink())?;
+        if skipped != len {
+            Err(io::Error::from(UnexpectedEof).into())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Skips the fields of the group with the `field_number` including the end group tag.
+    ///
+    /// Nested groups are tracked with a stack rather than recursion to bound the stack usage.
+    fn skip_group(&mut self, field_number: u32) -> Result<(), Error> {
+        let mut groups: Vec<u32> = vec![field_number];
+        while let Some(field_number) = groups.last().copied() {
+            let tag: FieldTag = self
+                .read_tag()?
+                .ok_or_else(|| Error::from(io::Error::from(UnexpectedEof)))?;
+            match tag.wire_type() {
+                WireType::EndGroup => {
+                    if tag.field_number() != field_number {
+                        return Err(InvalidEncodedData {
+                            reason: Some(format!("mismatched end group: {tag}").into()),
+                        });
+                    }
+                    groups.pop();
+                }
+                WireType::StartGroup => {
+                    if groups.len() >= Self::MAX_DEPTH {
+                        return Err(InvalidEncodedData {
+                            reason: Some(format!("max depth exceeded: {}", Self::MAX_DEPTH).into()),
+                        });
+                    }
+                    groups.push(tag.field_number());
+                }
+                _ => self.skip(tag)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::Error;
+    use crate::protobuf::{FieldTag, WireReader, WireType, WireWriter};
+    use crate::var_int::VarInt32;
+
+    #[test]
+    fn read() -> Result<(), Error> {
+        let mut w: WireWriter<Vec<u8>> = WireWriter::new(Vec::default());
+        w.write_varint(1, 150)?;
+        w.write_string(2, "testing")?;
+        w.write_sint32(3, -1)?;
+        w.write_sint64(4, -2)?;
+        w.write_fixed32(5, 1)?;
+        w.write_double(6, 1.5)?;
+        w.write_message(7, &VarInt32::from(300u32))?;
+        w.write_int(8, -1)?;
+        let encoded: Vec<u8> = w.into_inner();
+
+        let mut r: WireReader<&[u8]> = WireReader::new(encoded.as_slice());
+        let tag = |field_number: u32, wire_type: WireType| FieldTag::new(field_number, wire_type);
+        assert_eq!(r.read_tag()?, tag(1, WireType::Varint));
+        assert_eq!(r.read_varint()?, 150);
+        assert_eq!(r.read_tag()?, tag(2, WireType::LengthDelimited));
+        assert_eq!(r.read_string()?, "testing");
+        assert_eq!(r.read_tag()?, tag(3, WireType::Varint));
+        assert_eq!(r.read_sint32()?, -1);
+        assert_eq!(r.read_tag()?, tag(4, WireType::Varint));
+        assert_eq!(r.read_sint64()?, -2);
+        assert_eq!(r.read_tag()?, tag(5, WireType::Fixed32));
+        assert_eq!(r.read_fixed32()?, 1);
+        assert_eq!(r.read_tag()?, tag(6, WireType::Fixed64));
+        assert_eq!(r.read_double()?, 1.5);
+        assert_eq!(r.read_tag()?, tag(7, WireType::LengthDelimited));
+        assert_eq!(r.read_message::<VarInt32>()?, VarInt32::from(300u32));
+        assert_eq!(r.read_tag()?, tag(8, WireType::Varint));
+        assert_eq!(r.read_int()?, -1);
+        assert_eq!(r.read_tag()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn skip() -> Result<(), Error> {
+        let mut w: WireWriter<Vec<u8>> = WireWriter::new(Vec::default());
+        w.write_varint(1, u64::MAX)?;
+        w.write_fixed64(2, 0)?;
+        w.write_bytes(3, b"unknown")?;
+        w.write_tag(4, WireType::StartGroup)?;
+        w.write_tag(5, WireType::StartGroup)?;
+        w.write_fixed32(6, 0)?;
+        w.write_tag(5, WireType::EndGroup)?;
+        w.write_tag(4, WireType::EndGroup)?;
+        w.write_fixed32(7, 0)?;
+        w.write_bool(8, true)?;
+        let encoded: Vec<u8> = w.into_inner();
+
+        let mut r: WireReader<&[u8]> = WireReader::new(encoded.as_slice());
+        let mut skipped: Vec<u32> = Vec::default();
+        while let Some(tag) = r.read_tag()? {
+            if tag.field_number() == 8 {
+                assert!(r.read_bool()?);
+            } else {
+                r.skip(tag)?;
+                skipped.push(tag.field_number());
+            }
+        }
+        assert_eq!(skipped, [1, 2, 3, 4, 7]);
+        Ok(())
+    }
+
+    #[test]
+    fn skip_invalid() {
+        let test_cases: &[(&[u8], bool)] = &[
+            (b"\x0A\x05abc", false),  // truncated length-delimited value
+            (b"\x0B\x10\x01", false), // unterminated group
+            (b"\x0B\x14", true),      // mismatched end group
+            (b"\x0C", true),          // unexpected end group
+        ];
+
+        for (encoded, invalid) in test_cases {
+            let mut r: WireReader<&[u8]> = WireReader::new(encoded);
+            let tag: FieldTag = r.read_tag().unwrap().unwrap();
+            match r.skip(tag) {
+                Err(Error::InvalidEncodedData { .. }) => assert!(*invalid),
+                Err(Error::Stream(_)) => assert!(!*invalid),
+                result => panic!("{result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn skip_max_depth() {
+        let encoded: Vec<u8> = vec![0x0B; 1_000_000];
+        let mut r: WireReader<&[u8]> = WireReader::new(&encoded);
+        let tag: FieldTag = r.read_tag().unwrap().unwrap();
+        assert!(matches!(r.skip(tag), Err(Error::InvalidEncodedData { .. })));
+
+        let depth: usize = WireReader::<&[u8]>::MAX_DEPTH;
+        let mut encoded: Vec<u8> = vec![0x0B; depth];
+        encoded.extend(vec![0x0C; depth]);
+        let mut r: WireReader<&[u8]> = WireReader::new(&encoded);
+        let tag: FieldTag = r.read_tag().unwrap().unwrap();
+        r.skip(tag).unwrap();
+        assert!(r.read_tag().unwrap().is_none());
+    }
+}
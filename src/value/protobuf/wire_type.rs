@@ -0,0 +1,76 @@
+use std::fmt::{Display, Formatter};
+
+/// A Protocol Buffers wire type.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub enum WireType {
+    /// A var-int encoded integer. (`int32`, `int64`, `uint32`, `uint64`, `sint32`, `sint64`,
+    /// `bool`, `enum`)
+    Varint = 0,
+
+    /// A little-endian 64-bit value. (`fixed64`, `sfixed64`, `double`)
+    Fixed64 = 1,
+
+    /// A length-prefixed value. (`string`, `bytes`, embedded messages, packed repeated fields)
+    LengthDelimited = 2,
+
+    /// The start of a group. (deprecated)
+    StartGroup = 3,
+
+    /// The end of a group. (deprecated)
+    EndGroup = 4,
+
+    /// A little-endian 32-bit value. (`fixed32`, `sfixed32`, `float`)
+    Fixed32 = 5,
+}
+
+impl WireType {
+    //! Conversions
+
+    /// Gets the wire type for the 3-bit `value`.
+    ///
+    /// Returns `None` if the `value` is not a valid wire type.
+    pub const fn from_bits(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Varint),
+            1 => Some(Self::Fixed64),
+            2 => Some(Self::LengthDelimited),
+            3 => Some(Self::StartGroup),
+            4 => Some(Self::EndGroup),
+            5 => Some(Self::Fixed32),
+            _ => None,
+        }
+    }
+
+    /// Gets the 3-bit value of the wire type.
+    pub const fn bits(self) -> u8 {
+        self as u8
+    }
+}
+
+impl Display for WireType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Varint => write!(f, "VARINT"),
+            Self::Fixed64 => write!(f, "I64"),
+            Self::LengthDelimited => write!(f, "LEN"),
+            Self::StartGroup => write!(f, "SGROUP"),
+            Self::EndGroup => write!(f, "EGROUP"),
+            Self::Fixed32 => write!(f, "I32"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::protobuf::WireType;
+
+    #[test]
+    fn from_bits() {
+        for value in 0..8u8 {
+            match WireType::from_bits(value) {
+                Some(wire_type) => assert_eq!(wire_type.bits(), value),
+                None => assert!(value > 5),
+            }
+        }
+    }
+}
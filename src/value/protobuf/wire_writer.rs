@@ -0,0 +1,155 @@
+use crate::protobuf::{FieldTag, WireType};
+use crate::var_int::{VarInt32, VarInt64, VarIntSize};
+use crate::{EncodeToWrite, EncodedLen, Error};
+use std::io::Write;
+
+/// Responsible for writing Protocol Buffers wire format fields to a `Write`.
+///
+/// Each write function encodes the field tag followed by the field value and returns the number of
+/// bytes written. An `InvalidEncodedData` error is returned for invalid field numbers.
+#[derive(Debug)]
+pub struct WireWriter<W> {
+    w: W,
+}
+
+impl<W: Write> WireWriter<W> {
+    //! Construction
+
+    /// Creates a new wire writer.
+    pub fn new(w: W) -> Self {
+        Self { w }
+    }
+
+    /// Gets the underlying `Write`.
+    pub fn into_inner(self) -> W {
+        self.w
+    }
+}
+
+impl<W: Write> WireWriter<W> {
+    //! Write
+
+    /// Writes the field tag.
+    pub fn write_tag(&mut self, field_number: u32, wire_type: WireType) -> Result<usize, Error> {
+        FieldTag::try_new(field_number, wire_type)?.encode_to_write(&mut self.w)
+    }
+
+    /// Writes a var-int field.
+    pub fn write_varint(&mut self, field_number: u32, value: u64) -> Result<usize, Error> {
+        let tag_len: usize = self.write_tag(field_number, WireType::Varint)?;
+        Ok(tag_len + VarInt64::from(value).encode_to_write(&mut self.w)?)
+    }
+
+    /// Writes a signed `int32` or `int64` var-int field. (negative values use 10 bytes)
+    pub fn write_int(&mut self, field_number: u32, value: i64) -> Result<usize, Error> {
+        self.write_varint(field_number, value as u64)
+    }
+
+    /// Writes a zigzag encoded `sint32` var-int field.
+    pub fn write_sint32(&mut self, field_number: u32, value: i32) -> Result<usize, Error> {
+        self.write_varint(field_number, VarInt32::from_zigzag(value).value() as u64)
+    }
+
+    /// Writes a zigzag encoded `sint64` var-int field.
+    pub fn write_sint64(&mut self, field_number: u32, value: i64) -> Result<usize, Error> {
+        self.write_varint(field_number, VarInt64::from_zigzag(value).value())
+    }
+
+    /// Writes a `bool` var-int field.
+    pub fn write_bool(&mut self, field_number: u32, value: bool) -> Result<usize, Error> {
+        self.write_varint(field_number, value as u64)
+    }
+
+    /// Writes a little-endian 32-bit field.
+    pub fn write_fixed32(&mut self, field_number: u32, value: u32) -> Result<usize, Error> {
+        let tag_len: usize = self.write_tag(field_number, WireType::Fixed32)?;
+        self.w.write_all(&value.to_le_bytes())?;
+        Ok(tag_len + 4)
+    }
+
+    /// Writes a little-endian 64-bit field.
+    pub fn write_fixed64(&mut self, field_number: u32, value: u64) -> Result<usize, Error> {
+        let tag_len: usize = self.write_tag(field_number, WireType::Fixed64)?;
+        self.w.write_all(&value.to_le_bytes())?;
+        Ok(tag_len + 8)
+    }
+
+    /// Writes a `float` field.
+    pub fn write_float(&mut self, field_number: u32, value: f32) -> Result<usize, Error> {
+        self.write_fixed32(field_number, value.to_bits())
+    }
+
+    /// Writes a `double` field.
+    pub fn write_double(&mut self, field_number: u32, value: f64) -> Result<usize, Error> {
+        self.write_fixed64(field_number, value.to_bits())
+    }
+
+    /// Writes a length-delimited `bytes` field.
+    pub fn write_bytes(&mut self, field_number: u32, value: &[u8]) -> Result<usize, Error> {
+        let tag_len: usize = self.write_tag(field_number, WireType::LengthDelimited)?;
+        let len_len: usize = VarIntSize::from(value.len()).encode_to_write(&mut self.w)?;
+        self.w.write_all(value)?;
+        Ok(tag_len + len_len + value.len())
+    }
+
+    /// Writes a length-delimited `string` field.
+    pub fn write_string(&mut self, field_number: u32, value: &str) -> Result<usize, Error> {
+        self.write_bytes(field_number, value.as_bytes())
+    }
+
+    /// Writes a length-delimited field with the encoded `value`. (such as an embedded message)
+    pub fn write_message<T>(&mut self, field_number: u32, value: &T) -> Result<usize, Error>
+    where
+        T: EncodedLen + EncodeToWrite,
+    {
+        let tag_len: usize = self.write_tag(field_number, WireType::LengthDelimited)?;
+        let encoded_len: usize = value.encoded_len()?;
+        let len_len: usize = VarIntSize::from(encoded_len).encode_to_write(&mut self.w)?;
+        let also_encoded_len: usize = value.encode_to_write(&mut self.w)?;
+        debug_assert_eq!(encoded_len, also_encoded_len);
+        Ok(tag_len + len_len + encoded_len)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::Error;
+    use crate::protobuf::{WireType, WireWriter};
+    use crate::var_int::VarInt32;
+
+    #[test]
+    fn write() -> Result<(), Error> {
+        let mut w: WireWriter<Vec<u8>> = WireWriter::new(Vec::default());
+        assert_eq!(w.write_varint(1, 150)?, 3);
+        assert_eq!(w.write_string(2, "testing")?, 9);
+        assert_eq!(w.write_sint32(3, -1)?, 2);
+        assert_eq!(w.write_sint64(4, -2)?, 2);
+        assert_eq!(w.write_fixed32(5, 1)?, 5);
+        assert_eq!(w.write_fixed64(6, 1)?, 9);
+        assert_eq!(w.write_message(7, &VarInt32::from(300u32))?, 4);
+        assert_eq!(w.write_int(8, -1)?, 11);
+        assert_eq!(
+            w.into_inner().as_slice(),
+            b"\x08\x96\x01\
+              \x12\x07testing\
+              \x18\x01\
+              \x20\x03\
+              \x2D\x01\x00\x00\x00\
+              \x31\x01\x00\x00\x00\x00\x00\x00\x00\
+              \x3A\x02\xAC\x02\
+              \x40\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x01"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_field_number() {
+        let mut w: WireWriter<Vec<u8>> = WireWriter::new(Vec::default());
+        assert!(matches!(
+            w.write_tag(0, WireType::Varint),
+            Err(Error::InvalidEncodedData { .. })
+        ));
+        assert!(w.into_inner().is_empty());
+    }
+}
@@ -0,0 +1,43 @@
+use crate::stream_vbyte::tables::value_len;
+
+/// Decodes the values described by the `control` stream from the `data` stream into the `target`.
+///
+/// Returns the number of data bytes consumed.
+///
+/// # Safety
+/// The `target` length must be at most `control.len() * 4` and the `data` must contain all the
+/// encoded bytes described by the `control` stream for the `target` length.
+pub unsafe fn decode_scalar(control: &[u8], data: &[u8], target: &mut [u32]) -> usize {
+    debug_assert!(target.len() <= control.len() * 4);
+
+    let mut d: usize = 0;
+    for (i, t) in target.iter_mut().enumerate() {
+        let len: usize = value_len(unsafe { *control.get_unchecked(i / 4) }, i % 4);
+        debug_assert!(d + len <= data.len());
+
+        let mut bytes: [u8; 4] = [0u8; 4];
+        unsafe {
+            bytes
+                .get_unchecked_mut(..len)
+                .copy_from_slice(data.get_unchecked(d..(d + len)));
+        }
+        *t = u32::from_le_bytes(bytes);
+        d += len;
+    }
+    d
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream_vbyte::decode_scalar::decode_scalar;
+
+    #[test]
+    fn fn_decode_scalar() {
+        let control: &[u8] = &[0x1B, 0x04];
+        let data: &[u8] = b"\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0A\x0B\x0C\x0D";
+        let mut target: [u32; 6] = [0u32; 6];
+        let consumed: usize = unsafe { decode_scalar(control, data, &mut target) };
+        assert_eq!(consumed, data.len());
+        assert_eq!(target, [0x04030201, 0x070605, 0x0908, 0x0A, 0x0B, 0x0D0C]);
+    }
+}
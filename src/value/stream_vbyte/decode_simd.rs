@@ -0,0 +1,115 @@
+/// Decodes as many full quads as possible using a SIMD shuffle when the CPU supports it.
+///
+/// Each `control` byte describes a full quad of four values. Decoding stops before the first quad
+/// that does not have 16 readable bytes in the `data` stream. (each quad loads 16 bytes)
+///
+/// Returns `(quads decoded, data bytes consumed)`. Returns `(0, 0)` if SIMD is not supported.
+///
+/// # Safety
+/// The `target` length must be at least `control.len() * 4` and the `data` must contain all the
+/// encoded bytes described by the `control` stream.
+pub unsafe fn decode_simd(control: &[u8], data: &[u8], target: &mut [u32]) -> (usize, usize) {
+    debug_assert!(target.len() >= control.len() * 4);
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if std::arch::is_x86_feature_detected!("ssse3") {
+        return unsafe { decode_ssse3(control, data, target) };
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        return unsafe { decode_neon(control, data, target) };
+    }
+
+    let _ = (control, data, target);
+    (0, 0)
+}
+
+/// Decodes full quads with the SSSE3 `pshufb` instruction.
+///
+/// # Safety
+/// See `decode_simd`. The CPU must support SSSE3.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "ssse3")]
+unsafe fn decode_ssse3(control: &[u8], data: &[u8], target: &mut [u32]) -> (usize, usize) {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::{__m128i, _mm_loadu_si128, _mm_shuffle_epi8, _mm_storeu_si128};
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::{__m128i, _mm_loadu_si128, _mm_shuffle_epi8, _mm_storeu_si128};
+
+    use crate::stream_vbyte::tables::{LENGTH_TABLE, SHUFFLE_TABLE};
+
+    let mut c: usize = 0;
+    let mut d: usize = 0;
+    while c < control.len() && d + 16 <= data.len() {
+        let quad: usize = control[c] as usize;
+        unsafe {
+            let input: __m128i = _mm_loadu_si128(data.as_ptr().add(d) as *const __m128i);
+            let mask: __m128i = _mm_loadu_si128(SHUFFLE_TABLE[quad].as_ptr() as *const __m128i);
+            let output: __m128i = _mm_shuffle_epi8(input, mask);
+            _mm_storeu_si128(target.as_mut_ptr().add(c * 4) as *mut __m128i, output);
+        }
+        d += LENGTH_TABLE[quad] as usize;
+        c += 1;
+    }
+    (c, d)
+}
+
+/// Decodes full quads with the NEON `tbl` instruction.
+///
+/// # Safety
+/// See `decode_simd`. The CPU must support NEON.
+#[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+#[target_feature(enable = "neon")]
+unsafe fn decode_neon(control: &[u8], data: &[u8], target: &mut [u32]) -> (usize, usize) {
+    use crate::stream_vbyte::tables::{LENGTH_TABLE, SHUFFLE_TABLE};
+    use std::arch::aarch64::{uint8x16_t, vld1q_u8, vqtbl1q_u8, vst1q_u8};
+
+    let mut c: usize = 0;
+    let mut d: usize = 0;
+    while c < control.len() && d + 16 <= data.len() {
+        let quad: usize = control[c] as usize;
+        unsafe {
+            let input: uint8x16_t = vld1q_u8(data.as_ptr().add(d));
+            let mask: uint8x16_t = vld1q_u8(SHUFFLE_TABLE[quad].as_ptr());
+            let output: uint8x16_t = vqtbl1q_u8(input, mask);
+            vst1q_u8(target.as_mut_ptr().add(c * 4) as *mut u8, output);
+        }
+        d += LENGTH_TABLE[quad] as usize;
+        c += 1;
+    }
+    (c, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream_vbyte::decode_scalar::decode_scalar;
+    use crate::stream_vbyte::decode_simd::decode_simd;
+    use crate::stream_vbyte::tables::LENGTH_TABLE;
+
+    #[test]
+    fn simd_matches_scalar() {
+        let control: Vec<u8> = (0..=255u8).collect();
+        let data_len: usize = control
+            .iter()
+            .map(|c| LENGTH_TABLE[*c as usize] as usize)
+            .sum();
+        let data: Vec<u8> = (0..data_len).map(|i| (i * 7 + 3) as u8).collect();
+
+        let mut expected: Vec<u32> = vec![0u32; control.len() * 4];
+        let consumed: usize = unsafe { decode_scalar(&control, &data, &mut expected) };
+        assert_eq!(consumed, data.len());
+
+        let mut target: Vec<u32> = vec![0u32; control.len() * 4];
+        let (quads, consumed) = unsafe { decode_simd(&control, &data, &mut target) };
+        let values: usize = quads * 4;
+        assert_eq!(&target[..values], &expected[..values]);
+        assert_eq!(
+            consumed,
+            control[..quads]
+                .iter()
+                .map(|c| LENGTH_TABLE[*c as usize] as usize)
+                .sum::<usize>()
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use crate::Error::{IntegerOverflow, InvalidEncodedData};
+use crate::stream_vbyte::decode_scalar::decode_scalar;
+use crate::stream_vbyte::decode_simd::decode_simd;
+use crate::stream_vbyte::tables::{LENGTH_TABLE, value_len};
+use crate::var_int::VarIntSize;
+use crate::{DecodeFromReadPrefix, EncodeToSlice, Error};
+
+/// Responsible for encoding `u32` values in the Stream VByte format.
+///
+/// # Format
+/// 1. The number of values as a `VarIntSize`.
+/// 2. The control stream: one byte for each quad of four values. Each value uses two bits,
+///    starting with the lowest bits, to store its encoded length minus one.
+/// 3. The data stream: each value in little-endian order using 1 to 4 bytes.
+///
+/// # Decoding
+/// Full quads are decoded with an SSSE3 or NEON byte shuffle when the CPU supports it. The
+/// remaining values are decoded with a scalar implementation.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub struct StreamVByte {}
+
+impl StreamVByte {
+    //! Encoding
+
+    /// Appends the Stream VByte encoded `values` to the `target` vec.
+    ///
+    /// Returns the length of the encoded values.
+    pub fn append_slice_to_vec(values: &[u32], target: &mut Vec<u8>) -> Result<usize, Error> {
+        Self::append_values_to_vec(values.iter().map(|v| Ok(*v)), values.len(), target)
+    }
+
+    /// Appends the Stream VByte encoded differences between consecutive `values` to the `target`
+    /// vec. The first value is encoded as its difference from zero.
+    ///
+    /// Returns the length of the encoded values.
+    ///
+    /// The `values` must be sorted in ascending order, otherwise `IntegerOverflow` is returned.
+    pub fn append_slice_to_vec_delta(values: &[u32], target: &mut Vec<u8>) -> Result<usize, Error> {
+        let mut previous: u32 = 0;
+        let deltas = values.iter().map(|v| {
+            let delta: u32 = v.checked_sub(previous).ok_or(IntegerOverflow)?;
+            previous = *v;
+            Ok(delta)
+        });
+        Self::append_values_to_vec(deltas, values.len(), target)
+    }
+
+    /// Appends the `count` Stream VByte encoded `values` to the `target` vec.
+    ///
+    /// Returns the length of the encoded values.
+    fn append_values_to_vec<I>(
+        values: I,
+        count: usize,
+        target: &mut Vec<u8>,
+    ) -> Result<usize, Error>
+    where
+        I: Iterator<Item = Result<u32, Error>>,
+    {
+        let original_len: usize = target.len();
+        VarIntSize::from(count).append_to_vec(target)?;
+        let control_start: usize = target.len();
+        target.resize(control_start + count.div_ceil(4), 0u8);
+        target.reserve(count);
+        for (i, value) in values.enumerate() {
+            let value: u32 = match value {
+                Ok(value) => value,
+                Err(error) => {
+                    target.truncate(original_len);
+                    return Err(error);
+                }
+            };
+            let len: usize = (u32::BITS - (value | 1).leading_zeros()).div_ceil(8) as usize;
+            target[control_start + i / 4] |= ((len - 1) as u8) << ((i % 4) * 2);
+            target.extend_from_slice(&value.to_le_bytes()[..len]);
+        }
+        Ok(target.len() - original_len)
+    }
+}
+
+impl StreamVByte {
+    //! Decoding
+
+    /// Decodes the Stream VByte encoded `data` and appends the values to the `target` vec.
+    ///
+    /// Returns the number of decoded values.
+    pub fn decode_slice_to_vec(data: &[u8], target: &mut Vec<u32>) -> Result<usize, Error> {
+        Self::decode_values_to_vec(data, target)
+    }
+
+    /// Decodes the Stream VByte encoded differences in the `data` and appends the accumulated
+    /// values to the `target` vec.
+    ///
+    /// Returns the number of decoded values.
+    ///
+    /// This is the inverse of `append_slice_to_vec_delta`.
+    pub fn decode_slice_to_vec_delta(data: &[u8], target: &mut Vec<u32>) -> Result<usize, Error> {
+        let original_len: usize = target.len();
+        let count: usize = Self::decode_values_to_vec(data, target)?;
+        let mut previous: u32 = 0;
+        for value in &mut target[original_len..] {
+            if let Some(accumulated) = previous.checked_add(*value) {
+                *value = accumulated;
+                previous = accumulated;
+            } else {
+                target.truncate(original_len);
+                return Err(IntegerOverflow);
+            }
+        }
+        Ok(count)
+    }
+
+    /// Decodes the Stream VByte encoded `data` and appends the values to the `target` vec.
+    ///
+    /// Returns the number of decoded values.
+    fn decode_values_to_vec(data: &[u8], target: &mut Vec<u32>) -> Result<usize, Error> {
+        let mut r: &[u8] = data;
+        let count: usize = VarIntSize::decode_from_read_prefix(&mut r)?.value();
+        let control_len: usize = count.div_ceil(4);
+        if r.len() < control_len {
+            return Err(InvalidEncodedData {
+                reason: Some(format!("truncated control stream: count={count}").into()),
+            });
+        }
+        let (control, data) = r.split_at(control_len);
+        let data_len: usize = Self::data_len(control, count);
+        if data.len() != data_len {
+            return Err(InvalidEncodedData {
+                reason: Some(
+                    format!(
+                        "invalid data stream length: {} (expected={data_len})",
+                        data.len()
+                    )
+                    .into(),
+                ),
+            });
+        }
+
+        let original_len: usize = target.len();
+        target.resize(original_len + count, 0u32);
+        let target: &mut [u32] = &mut target[original_len..];
+        let (quads, mut d) = unsafe { decode_simd(&control[..(count / 4)], data, target) };
+        d += unsafe { decode_scalar(&control[quads..], &data[d..], &mut target[(quads * 4)..]) };
+        debug_assert_eq!(d, data_len);
+        Ok(count)
+    }
+
+    /// Gets the length of the data stream for the `count` values described by the `control`
+    /// stream.
+    fn data_len(control: &[u8], count: usize) -> usize {
+        let full: usize = control[..(count / 4)]
+            .iter()
+            .map(|c| LENGTH_TABLE[*c as usize] as usize)
+            .sum();
+        let partial: usize = (0..(count % 4))
+            .map(|i| value_len(control[count / 4], i))
+            .sum();
+        full + partial
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::Error;
+    use crate::stream_vbyte::StreamVByte;
+
+    #[test]
+    fn encode() -> Result<(), Error> {
+        let test_cases: &[(&[u32], &[u8])] = &[
+            (&[], b"\x00"),
+            (&[0], b"\x01\x00\x00"),
+            (
+                &[0xFF, 0x100, 0x1_0000, 0x100_0000],
+                b"\x04\xE4\xFF\x00\x01\x00\x00\x01\x00\x00\x00\x01",
+            ),
+            (
+                &[1, 2, 3, 4, 0xFFFF],
+                b"\x05\x00\x01\x01\x02\x03\x04\xFF\xFF",
+            ),
+        ];
+
+        for (values, expected) in test_cases {
+            let mut encoded: Vec<u8> = Vec::default();
+            let encoded_len: usize = StreamVByte::append_slice_to_vec(values, &mut encoded)?;
+            assert_eq!(encoded_len, expected.len());
+            assert_eq!(encoded.as_slice(), *expected);
+
+            let mut decoded: Vec<u32> = Vec::default();
+            assert_eq!(
+                StreamVByte::decode_slice_to_vec(&encoded, &mut decoded)?,
+                values.len()
+            );
+            assert_eq!(decoded.as_slice(), *values);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), Error> {
+        let values: Vec<u32> = (0..1_000u32)
+            .map(|i| i.wrapping_mul(0x9E37_79B9) >> (i % 32))
+            .collect();
+        let mut encoded: Vec<u8> = Vec::default();
+        StreamVByte::append_slice_to_vec(&values, &mut encoded)?;
+
+        let mut decoded: Vec<u32> = vec![7];
+        StreamVByte::decode_slice_to_vec(&encoded, &mut decoded)?;
+        assert_eq!(&decoded[1..], values.as_slice());
+        Ok(())
+    }
+
+    #[test]
+    fn delta() -> Result<(), Error> {
+        let values: Vec<u32> = (0..100u32).map(|i| i * i * 1_000).collect();
+        let mut encoded: Vec<u8> = Vec::default();
+        StreamVByte::append_slice_to_vec_delta(&values, &mut encoded)?;
+
+        let mut decoded: Vec<u32> = Vec::default();
+        StreamVByte::decode_slice_to_vec_delta(&encoded, &mut decoded)?;
+        assert_eq!(decoded, values);
+        Ok(())
+    }
+
+    #[test]
+    fn delta_unsorted() {
+        let mut encoded: Vec<u8> = Vec::default();
+        assert!(matches!(
+            StreamVByte::append_slice_to_vec_delta(&[2, 1], &mut encoded),
+            Err(Error::IntegerOverflow)
+        ));
+        assert!(encoded.is_empty());
+    }
+
+    #[test]
+    fn invalid() {
+        let test_cases: &[&[u8]] = &[
+            b"\x05\x00",                 // truncated control stream
+            b"\x01\x00",                 // missing data stream
+            b"\x01\x00\x00\x00",         // extra data
+            b"\x02\x07\x00\x00\x00\x00", // truncated data stream
+        ];
+
+        for encoded in test_cases {
+            let mut decoded: Vec<u32> = Vec::default();
+            assert!(matches!(
+                StreamVByte::decode_slice_to_vec(encoded, &mut decoded),
+                Err(Error::InvalidEncodedData { .. })
+            ));
+            assert!(decoded.is_empty());
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub use impl_stream_vbyte::*;
+
+mod impl_stream_vbyte;
+
+pub(in crate::value::stream_vbyte) mod decode_scalar;
+pub(in crate::value::stream_vbyte) mod decode_simd;
+pub(in crate::value::stream_vbyte) mod tables;
@@ -0,0 +1,83 @@
+/// Gets the encoded length of the value at `index` in the quad described by the `control` byte.
+#[inline(always)]
+pub const fn value_len(control: u8, index: usize) -> usize {
+    ((control >> (index * 2)) & 0x03) as usize + 1
+}
+
+/// The table of encoded quad lengths indexed by control byte.
+pub const LENGTH_TABLE: [u8; 256] = create_length_table();
+
+/// The table of byte shuffle masks indexed by control byte.
+///
+/// Each mask moves the encoded bytes of a quad into four little-endian `u32` lanes. Mask bytes
+/// with the high bit set zero the corresponding output byte.
+pub static SHUFFLE_TABLE: [[u8; 16]; 256] = create_shuffle_table();
+
+/// Creates the length table.
+const fn create_length_table() -> [u8; 256] {
+    let mut table: [u8; 256] = [0u8; 256];
+    let mut control: usize = 0;
+    while control < 256 {
+        let mut index: usize = 0;
+        while index < 4 {
+            table[control] += value_len(control as u8, index) as u8;
+            index += 1;
+        }
+        control += 1;
+    }
+    table
+}
+
+/// Creates the shuffle table.
+const fn create_shuffle_table() -> [[u8; 16]; 256] {
+    let mut table: [[u8; 16]; 256] = [[0xFF; 16]; 256];
+    let mut control: usize = 0;
+    while control < 256 {
+        let mut offset: u8 = 0;
+        let mut index: usize = 0;
+        while index < 4 {
+            let len: usize = value_len(control as u8, index);
+            let mut b: usize = 0;
+            while b < len {
+                table[control][index * 4 + b] = offset;
+                offset += 1;
+                b += 1;
+            }
+            index += 1;
+        }
+        control += 1;
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream_vbyte::tables::{LENGTH_TABLE, SHUFFLE_TABLE};
+
+    #[test]
+    fn length_table() {
+        assert_eq!(LENGTH_TABLE[0x00], 4);
+        assert_eq!(LENGTH_TABLE[0x1B], 10);
+        assert_eq!(LENGTH_TABLE[0xFF], 16);
+    }
+
+    #[test]
+    fn shuffle_table() {
+        assert_eq!(
+            SHUFFLE_TABLE[0x00],
+            [
+                0, 0xFF, 0xFF, 0xFF, 1, 0xFF, 0xFF, 0xFF, 2, 0xFF, 0xFF, 0xFF, 3, 0xFF, 0xFF, 0xFF
+            ]
+        );
+        assert_eq!(
+            SHUFFLE_TABLE[0x1B], // lengths: 4, 3, 2, 1
+            [
+                0, 1, 2, 3, 4, 5, 6, 0xFF, 7, 8, 0xFF, 0xFF, 9, 0xFF, 0xFF, 0xFF
+            ]
+        );
+        assert_eq!(
+            SHUFFLE_TABLE[0xFF],
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use crate::Error::InvalidEncodedData;
+use crate::{Error, impl_decode_from_read_by_prefix, impl_encode_to_write_stack_buf};
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+
+/// A Bitcoin `CompactSize` encoded `u64` value.
+///
+/// # Format
+/// - `[0x00, 0xFC]`: the value as a single byte.
+/// - `0xFD`: the value as a little-endian `u16`.
+/// - `0xFE`: the value as a little-endian `u32`.
+/// - `0xFF`: the value as a little-endian `u64`.
+///
+/// # Canonical Form
+/// Values must be encoded in the shortest form. Decoding a value that could have been encoded with
+/// fewer bytes will fail.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct CompactSize {
+    value: u64,
+}
+
+impl From<u64> for CompactSize {
+    fn from(value: u64) -> Self {
+        Self { value }
+    }
+}
+
+impl From<&u64> for CompactSize {
+    fn from(value: &u64) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl CompactSize {
+    //! Constants
+
+    /// The maximum length of a compact-size encoded value.
+    pub const MAX_ENCODED_LEN: usize = 9;
+
+    /// The prefix for values encoded as a `u16`.
+    const PREFIX_16: u8 = 0xFD;
+
+    /// The prefix for values encoded as a `u32`.
+    const PREFIX_32: u8 = 0xFE;
+
+    /// The prefix for values encoded as a `u64`.
+    const PREFIX_64: u8 = 0xFF;
+}
+
+impl CompactSize {
+    //! Properties
+
+    /// Gets the value.
+    pub fn value(self) -> u64 {
+        self.value
+    }
+}
+
+impl Display for CompactSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl crate::EncodedLen for CompactSize {
+    fn encoded_len(&self) -> Result<usize, Error> {
+        Ok(match self.value {
+            0..0xFD => 1,
+            0xFD..=0xFFFF => 3,
+            0x1_0000..=0xFFFF_FFFF => 5,
+            _ => 9,
+        })
+    }
+}
+
+impl crate::EncodeToSlice for CompactSize {
+    unsafe fn encode_to_slice_unchecked(&self, target: &mut [u8]) -> Result<usize, Error> {
+        let (prefix, len): (Option<u8>, usize) = match self.value {
+            0..0xFD => (None, 1),
+            0xFD..=0xFFFF => (Some(Self::PREFIX_16), 2),
+            0x1_0000..=0xFFFF_FFFF => (Some(Self::PREFIX_32), 4),
+            _ => (Some(Self::PREFIX_64), 8),
+        };
+        let bytes: [u8; 8] = self.value.to_le_bytes();
+        if let Some(prefix) = prefix {
+            unsafe {
+                *target.get_unchecked_mut(0) = prefix;
+                target
+                    .get_unchecked_mut(1..(len + 1))
+                    .copy_from_slice(bytes.get_unchecked(..len));
+            }
+            Ok(len + 1)
+        } else {
+            unsafe { *target.get_unchecked_mut(0) = bytes[0] };
+            Ok(1)
+        }
+    }
+}
+
+impl_encode_to_write_stack_buf!(CompactSize, Self::MAX_ENCODED_LEN);
+
+impl CompactSize {
+    //! Decoding
+
+    /// Reads a little-endian value of `len` bytes from the `Read`.
+    ///
+    /// Returns an error if the value is less than the `min` canonical value for the `len`.
+    fn read_canonical<R>(r: &mut R, len: usize, min: u64) -> Result<Self, Error>
+    where
+        R: Read,
+    {
+        let mut bytes: [u8; 8] = [0u8; 8];
+        r.read_exact(&mut bytes[..len])?;
+        let value: u64 = u64::from_le_bytes(bytes);
+        if value < min {
+            Err(InvalidEncodedData {
+                reason: Some(format!("non-canonical compact size: {value}").into()),
+            })
+        } else {
+            Ok(Self::from(value))
+        }
+    }
+}
+
+impl crate::DecodeFromReadPrefix for CompactSize {
+    fn decode_from_read_prefix_with_first_byte<R>(r: &mut R, first: u8) -> Result<Self, Error>
+    where
+        R: Read,
+    {
+        match first {
+            Self::PREFIX_16 => Self::read_canonical(r, 2, 0xFD),
+            Self::PREFIX_32 => Self::read_canonical(r, 4, 0x1_0000),
+            Self::PREFIX_64 => Self::read_canonical(r, 8, 0x1_0000_0000),
+            first => Ok(Self::from(first as u64)),
+        }
+    }
+}
+
+impl_decode_from_read_by_prefix!(CompactSize);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::DecodeFromReadPrefix;
+    use crate::test::test_io;
+    use crate::var_int::CompactSize;
+    use std::io::Cursor;
+
+    #[test]
+    fn compact_size() {
+        let test_cases: &[(u64, &[u8])] = &[
+            (0x00, b"\x00"),
+            (0xFC, b"\xFC"),                     // highest one byte value
+            (0xFD, b"\xFD\xFD\x00"),             // lowest three byte value
+            (0xFFFF, b"\xFD\xFF\xFF"),           // highest three byte value
+            (0x1_0000, b"\xFE\x00\x00\x01\x00"), // lowest five byte value
+            (0xFFFF_FFFF, b"\xFE\xFF\xFF\xFF\xFF"),
+            (0x1_0000_0000, b"\xFF\x00\x00\x00\x00\x01\x00\x00\x00"),
+            (u64::MAX, b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"),
+        ];
+
+        for (value, encoded) in test_cases {
+            let value: CompactSize = CompactSize::from(value);
+            test_io(&value, encoded, false);
+        }
+    }
+
+    #[test]
+    fn non_canonical() {
+        let test_cases: &[&[u8]] = &[
+            b"\xFD\x00\x00",
+            b"\xFD\xFC\x00",
+            b"\xFE\xFF\xFF\x00\x00",
+            b"\xFF\xFF\xFF\xFF\xFF\x00\x00\x00\x00",
+        ];
+
+        for encoded in test_cases {
+            assert!(matches!(
+                CompactSize::decode_from_read_prefix(&mut Cursor::new(encoded)),
+                Err(crate::Error::InvalidEncodedData { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn truncated() {
+        assert!(matches!(
+            CompactSize::decode_from_read_prefix(&mut Cursor::new(b"\xFE\x00\x00")),
+            Err(crate::Error::Stream(_))
+        ));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::Error::IntegerOverflow;
+use crate::var_int::impl_var_int::{VarInt16, VarInt32, VarInt64, VarInt128, VarIntSize};
+use crate::{DecodeFromReadPrefix, EncodeToSlice, Error};
+
+macro_rules! impl_var_int_slice {
+    ($target_type:ident, $unsigned_type:ty) => {
+        impl $target_type {
+            //! Slices
+
+            /// Appends the var-int encoded `values` to the `target` vec.
+            ///
+            /// Returns the length of the encoded values.
+            pub fn append_slice_to_vec(
+                values: &[$unsigned_type],
+                target: &mut Vec<u8>,
+            ) -> Result<usize, Error> {
+                Self::append_values_to_vec(values.iter().map(|v| Ok(*v)), values.len(), target)
+            }
+
+            /// Appends the var-int encoded differences between consecutive `values` to the
+            /// `target` vec. The first value is encoded as its difference from zero.
+            ///
+            /// Returns the length of the encoded values.
+            ///
+            /// The `values` must be sorted in ascending order, otherwise `IntegerOverflow` is
+            /// returned.
+            pub fn append_slice_to_vec_delta(
+                values: &[$unsigned_type],
+                target: &mut Vec<u8>,
+            ) -> Result<usize, Error> {
+                let mut previous: $unsigned_type = 0;
+                let deltas = values.iter().map(|v| {
+                    let delta: $unsigned_type = v.checked_sub(previous).ok_or(IntegerOverflow)?;
+                    previous = *v;
+                    Ok(delta)
+                });
+                Self::append_values_to_vec(deltas, values.len(), target)
+            }
+
+            /// Appends the var-int encoded `values` to the `target` vec.
+            ///
+            /// Returns the length of the encoded values.
+            fn append_values_to_vec<I>(
+                values: I,
+                count: usize,
+                target: &mut Vec<u8>,
+            ) -> Result<usize, Error>
+            where
+                I: Iterator<Item = Result<$unsigned_type, Error>>,
+            {
+                let original_len: usize = target.len();
+                target.reserve(count);
+                let mut buffer: [u8; Self::MAX_ENCODED_LEN] = [0u8; Self::MAX_ENCODED_LEN];
+                for value in values {
+                    let encoded_len: usize = match value.and_then(|v| unsafe {
+                        Self::from(v).encode_to_slice_unchecked(&mut buffer)
+                    }) {
+                        Ok(encoded_len) => encoded_len,
+                        Err(error) => {
+                            target.truncate(original_len);
+                            return Err(error);
+                        }
+                    };
+                    target.extend_from_slice(&buffer[..encoded_len]);
+                }
+                Ok(target.len() - original_len)
+            }
+
+            /// Decodes the var-int encoded `data` and appends the values to the `target` vec.
+            ///
+            /// Returns the number of decoded values.
+            pub fn decode_slice_to_vec(
+                data: &[u8],
+                target: &mut Vec<$unsigned_type>,
+            ) -> Result<usize, Error> {
+                Self::decode_values_to_vec(data, target, |value| Ok(value))
+            }
+
+            /// Decodes the var-int encoded differences in the `data` and appends the
+            /// accumulated values to the `target` vec.
+            ///
+            /// Returns the number of decoded values.
+            ///
+            /// This is the inverse of `append_slice_to_vec_delta`.
+            pub fn decode_slice_to_vec_delta(
+                data: &[u8],
+                target: &mut Vec<$unsigned_type>,
+            ) -> Result<usize, Error> {
+                let mut previous: $unsigned_type = 0;
+                Self::decode_values_to_vec(data, target, |delta| {
+                    previous = previous.checked_add(delta).ok_or(IntegerOverflow)?;
+                    Ok(previous)
+                })
+            }
+
+            /// Decodes the var-int encoded `data` and appends the mapped values to the `target`
+            /// vec.
+            ///
+            /// Returns the number of decoded values.
+            fn decode_values_to_vec<F>(
+                data: &[u8],
+                target: &mut Vec<$unsigned_type>,
+                mut map_fn: F,
+            ) -> Result<usize, Error>
+            where
+                F: FnMut($unsigned_type) -> Result<$unsigned_type, Error>,
+            {
+                let original_len: usize = target.len();
+                // each value has exactly one byte without the continuation bit
+                target.reserve(data.iter().filter(|b| **b & 0x80 == 0).count());
+                let mut r: &[u8] = data;
+                while !r.is_empty() {
+                    match Self::decode_from_read_prefix(&mut r).and_then(|v| map_fn(v.value)) {
+                        Ok(value) => target.push(value),
+                        Err(error) => {
+                            target.truncate(original_len);
+                            return Err(error);
+                        }
+                    }
+                }
+                Ok(target.len() - original_len)
+            }
+        }
+    };
+}
+
+impl_var_int_slice!(VarInt16, u16);
+impl_var_int_slice!(VarInt32, u32);
+impl_var_int_slice!(VarInt64, u64);
+impl_var_int_slice!(VarInt128, u128);
+impl_var_int_slice!(VarIntSize, usize);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::var_int::{VarInt32, VarInt64};
+    use crate::{EncodeToSlice, Error};
+
+    #[test]
+    fn slice_wire_compatible() -> Result<(), Error> {
+        let values: &[u32] = &[0, 1, 0x7F, 0x80, 0x3FFF, u32::MAX];
+        let mut expected: Vec<u8> = Vec::default();
+        for value in values {
+            VarInt32::from(value).append_to_vec(&mut expected)?;
+        }
+
+        let mut encoded: Vec<u8> = vec![0xAA];
+        let encoded_len: usize = VarInt32::append_slice_to_vec(values, &mut encoded)?;
+        assert_eq!(encoded_len, expected.len());
+        assert_eq!(&encoded[1..], expected.as_slice());
+
+        let mut decoded: Vec<u32> = vec![7];
+        assert_eq!(
+            VarInt32::decode_slice_to_vec(&encoded[1..], &mut decoded)?,
+            values.len()
+        );
+        assert_eq!(&decoded[1..], values);
+        Ok(())
+    }
+
+    #[test]
+    fn slice_delta() -> Result<(), Error> {
+        let values: &[u64] = &[3, 3, 130, 1_000_000, u64::MAX];
+        let mut encoded: Vec<u8> = Vec::default();
+        VarInt64::append_slice_to_vec_delta(values, &mut encoded)?;
+        assert_eq!(&encoded[..4], b"\x03\x00\x7F\xBE");
+
+        let mut decoded: Vec<u64> = Vec::default();
+        VarInt64::decode_slice_to_vec_delta(&encoded, &mut decoded)?;
+        assert_eq!(decoded.as_slice(), values);
+        Ok(())
+    }
+
+    #[test]
+    fn slice_delta_unsorted() {
+        let mut encoded: Vec<u8> = vec![0xAA];
+        assert!(matches!(
+            VarInt32::append_slice_to_vec_delta(&[1, 2, 1], &mut encoded),
+            Err(Error::IntegerOverflow)
+        ));
+        assert_eq!(encoded, [0xAA]);
+    }
+
+    #[test]
+    fn slice_delta_overflow() {
+        let mut decoded: Vec<u32> = Vec::default();
+        assert!(matches!(
+            VarInt32::decode_slice_to_vec_delta(b"\xFF\xFF\xFF\xFF\x0F\x01", &mut decoded),
+            Err(Error::IntegerOverflow)
+        ));
+        assert!(decoded.is_empty());
+    }
+
+    #[test]
+    fn slice_truncated() {
+        let mut decoded: Vec<u32> = vec![7];
+        assert!(matches!(
+            VarInt32::decode_slice_to_vec(b"\x01\x80", &mut decoded),
+            Err(Error::Stream(_))
+        ));
+        assert_eq!(decoded, [7]);
+    }
+}
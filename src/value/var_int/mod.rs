@@ -1,6 +1,11 @@
+pub use compact_size::*;
 pub use impl_var_int::*;
+pub use sqlite_var_int::*;
 
+mod compact_size;
 mod impl_var_int;
+mod sqlite_var_int;
 
 mod impl_var_int_decode;
 mod impl_var_int_encode;
+mod impl_var_int_slice;
@@ -0,0 +1,141 @@
+use crate::{Error, impl_decode_from_read_by_prefix, impl_encode_to_write_stack_buf};
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+
+/// A SQLite-style variable-length encoded `u64` value.
+///
+/// # Format
+/// The value is encoded big-endian in 1 to 9 bytes. The first 8 bytes contribute their lower 7
+/// bits and use their high bit to signal that another byte follows. The 9th byte, if present,
+/// contributes all 8 bits.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct SqliteVarInt {
+    value: u64,
+}
+
+impl From<u64> for SqliteVarInt {
+    fn from(value: u64) -> Self {
+        Self { value }
+    }
+}
+
+impl From<&u64> for SqliteVarInt {
+    fn from(value: &u64) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl SqliteVarInt {
+    //! Constants
+
+    /// The maximum length of a SQLite var-int encoded value.
+    pub const MAX_ENCODED_LEN: usize = 9;
+
+    /// The maximum number of value bits that can be encoded without the 9th byte.
+    const SHORT_BITS: u32 = 56;
+}
+
+impl SqliteVarInt {
+    //! Properties
+
+    /// Gets the value.
+    pub fn value(self) -> u64 {
+        self.value
+    }
+}
+
+impl Display for SqliteVarInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl crate::EncodedLen for SqliteVarInt {
+    fn encoded_len(&self) -> Result<usize, Error> {
+        let bits: u32 = u64::BITS - (self.value | 1).leading_zeros();
+        if bits > Self::SHORT_BITS {
+            Ok(Self::MAX_ENCODED_LEN)
+        } else {
+            Ok(bits.div_ceil(7) as usize)
+        }
+    }
+}
+
+impl crate::EncodeToSlice for SqliteVarInt {
+    unsafe fn encode_to_slice_unchecked(&self, target: &mut [u8]) -> Result<usize, Error> {
+        use crate::EncodedLen;
+
+        let encoded_len: usize = self.encoded_len()?;
+        let mut v: u64 = self.value;
+        let mut t: usize = encoded_len;
+        t -= 1;
+        if encoded_len == Self::MAX_ENCODED_LEN {
+            unsafe { *target.get_unchecked_mut(t) = v as u8 };
+            v >>= 8;
+        } else {
+            unsafe { *target.get_unchecked_mut(t) = v as u8 & 0x7F };
+            v >>= 7;
+        }
+        while t > 0 {
+            t -= 1;
+            unsafe { *target.get_unchecked_mut(t) = (v as u8 & 0x7F) | 0x80 };
+            v >>= 7;
+        }
+        Ok(encoded_len)
+    }
+}
+
+impl_encode_to_write_stack_buf!(SqliteVarInt, Self::MAX_ENCODED_LEN);
+
+impl crate::DecodeFromReadPrefix for SqliteVarInt {
+    fn decode_from_read_prefix_with_first_byte<R>(r: &mut R, first: u8) -> Result<Self, Error>
+    where
+        R: Read,
+    {
+        let mut result: u64 = (first & 0x7F) as u64;
+        if first & 0x80 == 0 {
+            return Ok(Self::from(result));
+        }
+        for _ in 0..(Self::MAX_ENCODED_LEN - 2) {
+            let b: u8 = crate::read_single_byte(r)?;
+            result = (result << 7) | (b & 0x7F) as u64;
+            if b & 0x80 == 0 {
+                return Ok(Self::from(result));
+            }
+        }
+        let b: u8 = crate::read_single_byte(r)?;
+        Ok(Self::from((result << 8) | b as u64))
+    }
+}
+
+impl_decode_from_read_by_prefix!(SqliteVarInt);
+
+#[cfg(test)]
+#[cfg(feature = "dev")]
+mod tests {
+    use crate::test::test_io;
+    use crate::var_int::SqliteVarInt;
+
+    #[test]
+    fn sqlite_var_int() {
+        let test_cases: &[(u64, &[u8])] = &[
+            (0x00, b"\x00"),
+            (0x7F, b"\x7F"),           // highest one byte value
+            (0x80, b"\x81\x00"),       // lowest two byte value
+            (0x3FFF, b"\xFF\x7F"),     // highest two byte value
+            (0x4000, b"\x81\x80\x00"), // lowest three byte value
+            (0x1234_5678, b"\x81\x91\xD1\xAC\x78"),
+            (0x00FF_FFFF_FFFF_FFFF, b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x7F"), // highest eight byte value
+            (
+                0x0100_0000_0000_0000, // lowest nine byte value
+                b"\x80\xC0\x80\x80\x80\x80\x80\x80\x00",
+            ),
+            (u64::MAX, b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"),
+        ];
+
+        for (value, encoded) in test_cases {
+            let value: SqliteVarInt = SqliteVarInt::from(value);
+            test_io(&value, encoded, false);
+        }
+    }
+}